@@ -0,0 +1,396 @@
+//! Heading anchor generation compatible with popular markdown renderers.
+
+use std::collections::HashMap;
+
+use crate::suffix::fill_counter;
+use crate::{Preset, Slugifier};
+
+/// Returns `true` for the characters GitHub keeps in heading anchors: letters,
+/// numbers, combining marks, `_`, `-`, and spaces.
+fn is_github_anchor_char(c: char) -> bool {
+    c.is_alphanumeric()
+        || matches!(c, '_' | '-' | ' ')
+        || unicode_normalization::char::is_combining_mark(c)
+}
+
+/// Converts a heading to an anchor using GitHub's algorithm (as implemented by
+/// `github-slugger`): lowercase the text, remove punctuation and symbols, and
+/// turn every space into a hyphen.
+pub(crate) fn github_anchor(heading: &str) -> String {
+    heading
+        .to_lowercase()
+        .chars()
+        .filter(|&c| is_github_anchor_char(c))
+        .map(|c| if c == ' ' { '-' } else { c })
+        .collect()
+}
+
+/// Converts a heading to an anchor using GitLab's algorithm: strip and lowercase
+/// the text, remove punctuation, turn spaces into hyphens, and squeeze runs of
+/// hyphens. Digit-only anchors are prefixed with `anchor-`, since they would
+/// conflict with issue references.
+pub(crate) fn gitlab_anchor(heading: &str) -> String {
+    let mut anchor = String::with_capacity(heading.len());
+
+    for c in heading.trim().to_lowercase().chars() {
+        let c = if c == ' ' { '-' } else { c };
+        if is_github_anchor_char(c) && !(c == '-' && anchor.ends_with('-')) {
+            anchor.push(c);
+        }
+    }
+
+    if !anchor.is_empty() && anchor.bytes().all(|b| b.is_ascii_digit()) {
+        anchor.insert_str(0, "anchor-");
+    }
+
+    anchor
+}
+
+/// Converts a heading to an identifier using Pandoc's `auto_identifiers`
+/// algorithm: keep alphanumerics, `_`, `-`, and `.`, lowercase the text, join
+/// the words with hyphens, and drop everything before the first letter.
+/// Headings without any letter become `section`.
+pub(crate) fn pandoc_anchor(heading: &str) -> String {
+    let filtered: String = heading
+        .to_lowercase()
+        .chars()
+        .filter(|&c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.') || c.is_whitespace())
+        .collect();
+    let joined = filtered.split_whitespace().collect::<Vec<_>>().join("-");
+
+    match joined.find(char::is_alphabetic) {
+        Some(start) => joined[start..].to_string(),
+        None => "section".to_string(),
+    }
+}
+
+/// Converts a heading to an anchor using mdBook's `normalize_id`: keep
+/// alphanumerics, `_`, and `-`, turn whitespace into hyphens, and lowercase
+/// ASCII letters only.
+pub(crate) fn mdbook_anchor(heading: &str) -> String {
+    heading
+        .chars()
+        .filter_map(|c| {
+            if c.is_alphanumeric() || c == '_' || c == '-' {
+                Some(c.to_ascii_lowercase())
+            } else if c.is_whitespace() {
+                Some('-')
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// A stateful generator producing unique anchors for the headings of a document.
+///
+/// Duplicate anchors get a numeric suffix the same way GitHub does it: the
+/// second "Usage" heading becomes `usage-1`, the third `usage-2`, and so on.
+///
+/// # Example
+///
+/// ```
+/// use rslug::AnchorGenerator;
+///
+/// let mut anchors = AnchorGenerator::new();
+/// assert_eq!(anchors.anchor("Usage"), "usage");
+/// assert_eq!(anchors.anchor("Usage"), "usage-1");
+/// assert_eq!(anchors.anchor("What's new?"), "whats-new");
+/// ```
+#[derive(Debug, Clone)]
+pub struct AnchorGenerator {
+    slugifier: Slugifier,
+    suffix: String,
+    start: usize,
+    hash_suffixes: bool,
+    occurrences: HashMap<String, usize>,
+}
+
+impl Default for AnchorGenerator {
+    /// Creates a generator using [`Preset::GitHubAnchor`].
+    fn default() -> Self {
+        Self::with_slugifier(Slugifier::new().preset(Preset::GitHubAnchor))
+    }
+}
+
+impl AnchorGenerator {
+    /// Creates a generator producing GitHub-compatible anchors.
+    ///
+    /// This is an alias for `AnchorGenerator::default()`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a generator that uses `slugifier` to turn headings into anchors.
+    pub fn with_slugifier(slugifier: Slugifier) -> Self {
+        Self {
+            slugifier,
+            suffix: "-{n}".to_string(),
+            start: 1,
+            hash_suffixes: false,
+            occurrences: HashMap::new(),
+        }
+    }
+
+    /// Sets the suffix appended to duplicate anchors.
+    ///
+    /// `{n}` is replaced with the duplicate's number, which is appended if the
+    /// suffix has no `{n}`. Defaults to `"-{n}"`.
+    ///
+    /// # Arguments
+    ///
+    /// * `suffix` - The suffix template, containing `{n}`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::AnchorGenerator;
+    /// let mut anchors = AnchorGenerator::new().suffix("_{n}").start_number(2);
+    /// assert_eq!(anchors.anchor("Usage"), "usage");
+    /// assert_eq!(anchors.anchor("Usage"), "usage_2");
+    /// ```
+    pub fn suffix(mut self, suffix: &str) -> Self {
+        self.suffix = suffix.to_string();
+        self
+    }
+
+    /// Sets the number of the first duplicate anchor. Defaults to 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The number used in the suffix of the first duplicate.
+    pub fn start_number(mut self, start: usize) -> Self {
+        self.start = start;
+        self
+    }
+
+    /// Sets whether [`anchor_for_section`](AnchorGenerator::anchor_for_section)
+    /// suffixes duplicate anchors with a hash of their section's content
+    /// rather than their number.
+    ///
+    /// Numbered suffixes depend on the order of the headings, so inserting a
+    /// section shifts the anchors of the duplicates after it and breaks links
+    /// to them. A hash of the content stays the same as long as the section
+    /// does. `{n}` in the suffix is replaced with eight hex digits of the hash,
+    /// and sections with the same content are numbered after the hash. The
+    /// first occurrence of a heading still gets the bare anchor.
+    ///
+    /// # Arguments
+    ///
+    /// * `hash_suffixes` - A boolean indicating if duplicates are suffixed by
+    ///   content hash.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::AnchorGenerator;
+    ///
+    /// let mut anchors = AnchorGenerator::new().hash_suffixes(true);
+    /// assert_eq!(anchors.anchor_for_section("Usage", "Run it."), "usage");
+    /// let usage = anchors.anchor_for_section("Usage", "Call it.");
+    ///
+    /// // Another duplicate before it doesn't change its anchor.
+    /// let mut anchors = AnchorGenerator::new().hash_suffixes(true);
+    /// anchors.anchor_for_section("Usage", "Run it.");
+    /// anchors.anchor_for_section("Usage", "Import it.");
+    /// assert_eq!(anchors.anchor_for_section("Usage", "Call it."), usage);
+    /// ```
+    pub fn hash_suffixes(mut self, hash_suffixes: bool) -> Self {
+        self.hash_suffixes = hash_suffixes;
+        self
+    }
+
+    /// Returns a unique anchor for `heading`.
+    pub fn anchor(&mut self, heading: &str) -> String {
+        let original = self.slugifier.slugify(heading);
+        self.unique(original)
+    }
+
+    /// Returns a unique anchor for a section with the given heading and
+    /// content.
+    ///
+    /// With [`hash_suffixes`](AnchorGenerator::hash_suffixes), the suffix of a
+    /// duplicate anchor is derived from `content`. Otherwise, this is the same
+    /// as [`anchor`](AnchorGenerator::anchor).
+    ///
+    /// # Arguments
+    ///
+    /// * `heading` - The heading of the section.
+    /// * `content` - The text of the section below its heading.
+    pub fn anchor_for_section(&mut self, heading: &str, content: &str) -> String {
+        let original = self.slugifier.slugify(heading);
+        if !self.hash_suffixes || !self.occurrences.contains_key(&original) {
+            return self.unique(original);
+        }
+        let hash = format!("{:08x}", fnv1a(content.as_bytes()) >> 32);
+        let hashed = format!("{original}{}", fill_counter(&self.suffix, &hash));
+        self.unique(hashed)
+    }
+
+    /// Returns `original`, or `original` with a numbered suffix if it was
+    /// generated already, and records the result.
+    fn unique(&mut self, original: String) -> String {
+        let mut anchor = original.clone();
+
+        while self.occurrences.contains_key(&anchor) {
+            let next = self.occurrences.entry(original.clone()).or_default();
+            let n = *next;
+            *next += 1;
+            anchor = format!("{original}{}", fill_counter(&self.suffix, &n.to_string()));
+        }
+
+        self.occurrences.insert(anchor.clone(), self.start);
+        anchor
+    }
+
+    /// Returns a unique anchor for each heading, paired with the heading, in
+    /// the order of the headings.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::AnchorGenerator;
+    /// let toc = AnchorGenerator::new().anchors(["Install", "Usage", "Usage"]);
+    /// assert_eq!(
+    ///     toc,
+    ///     [
+    ///         ("Install".to_string(), "install".to_string()),
+    ///         ("Usage".to_string(), "usage".to_string()),
+    ///         ("Usage".to_string(), "usage-1".to_string()),
+    ///     ]
+    /// );
+    /// ```
+    pub fn anchors<I>(&mut self, headings: I) -> Vec<(String, String)>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        headings
+            .into_iter()
+            .map(|heading| {
+                let heading = heading.as_ref();
+                (heading.to_string(), self.anchor(heading))
+            })
+            .collect()
+    }
+
+    /// Forgets all anchors generated so far, e.g. to start a new document.
+    pub fn reset(&mut self) {
+        self.occurrences.clear();
+    }
+}
+
+/// Hashes `bytes` with 64-bit FNV-1a, which, unlike the standard library's
+/// hashers, is the same in every release, so anchors derived from it are too.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_github_anchor() {
+        assert_eq!(github_anchor("Hello World"), "hello-world");
+        assert_eq!(github_anchor("API & SDK: v2.0"), "api--sdk-v20");
+        assert_eq!(github_anchor("snake_case_name"), "snake_case_name");
+        assert_eq!(github_anchor("Rust 🦀 Tips"), "rust--tips");
+        assert_eq!(github_anchor("Übersicht"), "übersicht");
+        assert_eq!(github_anchor("你好 世界"), "你好-世界");
+    }
+
+    #[test]
+    fn test_gitlab_anchor() {
+        assert_eq!(gitlab_anchor("  Hello World  "), "hello-world");
+        assert_eq!(gitlab_anchor("API & SDK: v2.0"), "api-sdk-v20");
+        assert_eq!(gitlab_anchor("a -- b"), "a-b");
+        assert_eq!(gitlab_anchor("2024"), "anchor-2024");
+    }
+
+    #[test]
+    fn test_mdbook_anchor() {
+        assert_eq!(mdbook_anchor("Hello, World!"), "hello-world");
+        assert_eq!(mdbook_anchor("a\tb  c"), "a-b--c");
+        assert_eq!(mdbook_anchor("Über_Uns"), "Über_uns");
+    }
+
+    #[test]
+    fn test_pandoc_anchor() {
+        assert_eq!(
+            pandoc_anchor("Heading identifiers in HTML"),
+            "heading-identifiers-in-html"
+        );
+        assert_eq!(pandoc_anchor("Maître d'hôtel"), "maître-dhôtel");
+        assert_eq!(
+            pandoc_anchor("*Dogs*?--in *my* house?"),
+            "dogs--in-my-house"
+        );
+        assert_eq!(pandoc_anchor("1.2 Setup"), "setup");
+        assert_eq!(pandoc_anchor("[HTML], [S5], or [RTF]?"), "html-s5-or-rtf");
+        assert_eq!(pandoc_anchor("3. Applications"), "applications");
+        assert_eq!(pandoc_anchor("33"), "section");
+    }
+
+    #[test]
+    fn test_anchor_deduplication() {
+        let mut anchors = AnchorGenerator::new();
+        assert_eq!(anchors.anchor("Intro"), "intro");
+        assert_eq!(anchors.anchor("Intro"), "intro-1");
+        assert_eq!(anchors.anchor("Intro 1"), "intro-1-1");
+        assert_eq!(anchors.anchor("Intro"), "intro-2");
+
+        anchors.reset();
+        assert_eq!(anchors.anchor("Intro"), "intro");
+    }
+
+    #[test]
+    fn test_anchor_suffix_and_start_number() {
+        let mut anchors = AnchorGenerator::new().suffix("~{n}").start_number(0);
+        assert_eq!(anchors.anchor("FAQ"), "faq");
+        assert_eq!(anchors.anchor("FAQ"), "faq~0");
+        assert_eq!(anchors.anchor("FAQ"), "faq~1");
+    }
+
+    #[test]
+    fn test_anchors_from_iterator() {
+        let headings = vec![String::from("A"), String::from("B"), String::from("A")];
+        let anchors: Vec<_> = AnchorGenerator::new()
+            .anchors(&headings)
+            .into_iter()
+            .map(|(_, anchor)| anchor)
+            .collect();
+        assert_eq!(anchors, ["a", "b", "a-1"]);
+    }
+
+    #[test]
+    fn test_hash_suffixes() {
+        let mut anchors = AnchorGenerator::new().hash_suffixes(true);
+        assert_eq!(anchors.anchor_for_section("Usage", "a"), "usage");
+        let hashed = format!("usage-{:08x}", fnv1a(b"b") >> 32);
+        assert_eq!(anchors.anchor_for_section("Usage", "b"), hashed);
+        assert_eq!(anchors.anchor_for_section("Usage", "b"), hashed + "-1");
+        assert_eq!(anchors.anchor("Usage"), "usage-1");
+        assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+
+        let mut anchors = AnchorGenerator::new();
+        assert_eq!(anchors.anchor_for_section("Usage", "a"), "usage");
+        assert_eq!(anchors.anchor_for_section("Usage", "b"), "usage-1");
+    }
+
+    #[test]
+    fn test_suffix_without_counter() {
+        let mut anchors = AnchorGenerator::new().suffix("-dup");
+        assert_eq!(anchors.anchor("Intro"), "intro");
+        assert_eq!(anchors.anchor("Intro"), "intro-dup1");
+        assert_eq!(anchors.anchor("Intro"), "intro-dup2");
+
+        let mut anchors = AnchorGenerator::new().suffix("-dup").hash_suffixes(true);
+        anchors.anchor_for_section("Intro", "a");
+        let hashed = anchors.anchor_for_section("Intro", "b");
+        assert!(hashed.starts_with("intro-dup") && hashed.len() == "intro-dup".len() + 8);
+        assert_eq!(anchors.anchor_for_section("Intro", "b"), hashed + "-dup1");
+    }
+}
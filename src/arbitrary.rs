@@ -0,0 +1,151 @@
+//! Seeded generators of slugifier configurations and slugs, for property
+//! tests of code that consumes slugs.
+//!
+//! The generators take a `u64` seed instead of implementing a property testing
+//! crate's trait, so they work with any of them and the crate doesn't depend
+//! on one: map the crate's `u64` strategy or generator through them.
+
+use crate::{Preset, Slugifier};
+
+/// Words the generated slugs are made of, including ones that need
+/// transliteration and ones that slugify to nothing.
+const WORDS: [&str; 24] = [
+    "hello", "World", "rust", "2024", "v1.2", "Café", "naïve", "Straße", "a", "I", "THE", "of",
+    "&", "C++", "50%", "--", "über", "Ærø", "x", "slug", "API", "", "!!", "déjà",
+];
+
+impl Slugifier {
+    /// Returns a slugifier configuration chosen by `seed`, for property tests.
+    ///
+    /// The same seed always gives the same configuration. Configurations
+    /// vary the separator, case, truncation, and preset, and every slug they
+    /// generate passes their [`validate`](Slugifier::validate).
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed that chooses the configuration.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    ///
+    /// for seed in 0..100 {
+    ///     let slugifier = Slugifier::arbitrary(seed);
+    ///     let slug = slugifier.arbitrary_slug(seed).unwrap();
+    ///     assert_eq!(slugifier.validate(&slug), Ok(()));
+    /// }
+    /// ```
+    pub fn arbitrary(seed: u64) -> Self {
+        let mut rng = Rng(seed);
+        let mut slugifier = Slugifier::new()
+            .separator(["-", "_"][rng.below(2)])
+            .to_lowercase(rng.below(4) != 0);
+        if rng.below(3) == 0 {
+            slugifier = slugifier.truncate(8 + rng.below(40));
+        }
+        if rng.below(4) == 0 {
+            let presets = [
+                Preset::DnsLabel,
+                Preset::CratesIo,
+                Preset::EnvVar,
+                Preset::HtmlId,
+            ];
+            slugifier = slugifier.preset(presets[rng.below(presets.len())]);
+        }
+        slugifier
+    }
+
+    /// Returns a slug chosen by `seed` that passes this slugifier's
+    /// [`validate`](Slugifier::validate), for property tests, or `None` if
+    /// none is found within a thousand attempts, like for a configuration
+    /// whose stop words remove every word.
+    ///
+    /// The slug is generated by slugifying text of random words, so it looks
+    /// like slugs in the wild.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed that chooses the slug.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    ///
+    /// let slugifier = Slugifier::new().separator("_");
+    /// let slug = slugifier.arbitrary_slug(7).unwrap();
+    /// assert_eq!(Some(&slug), slugifier.arbitrary_slug(7).as_ref());
+    /// assert!(slugifier.validate(&slug).is_ok());
+    /// ```
+    pub fn arbitrary_slug(&self, seed: u64) -> Option<String> {
+        let mut rng = Rng(seed);
+        for _ in 0..1000 {
+            let mut text = String::new();
+            for _ in 0..=rng.below(6) {
+                text.push_str(WORDS[rng.below(WORDS.len())]);
+                text.push(' ');
+            }
+            let slug = self.slugify(&text);
+            if self.validate(&slug).is_ok() {
+                return Some(slug);
+            }
+        }
+        None
+    }
+}
+
+/// The SplitMix64 generator, which is small and good enough to pick words.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number below `n`.
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arbitrary_slugs_are_valid() {
+        for seed in 0..500 {
+            let slugifier = Slugifier::arbitrary(seed);
+            let slug = slugifier.arbitrary_slug(seed).unwrap();
+            assert_eq!(slugifier.validate(&slug), Ok(()), "{seed}: {slug:?}");
+        }
+    }
+
+    #[test]
+    fn test_arbitrary_is_deterministic() {
+        for seed in [0, 1, u64::MAX] {
+            let slugifier = Slugifier::arbitrary(seed);
+            assert_eq!(
+                slugifier.arbitrary_slug(seed),
+                Slugifier::arbitrary(seed).arbitrary_slug(seed)
+            );
+        }
+        let slugs: std::collections::HashSet<_> = (0..50)
+            .map(|seed| Slugifier::new().arbitrary_slug(seed))
+            .collect();
+        assert!(slugs.len() > 25);
+    }
+
+    #[test]
+    fn test_no_arbitrary_slug_without_valid_slugs() {
+        let words = Slugifier::new().slugify(&WORDS.join(" "));
+        let words: Vec<&str> = words.split('-').collect();
+        let slugifier = Slugifier::new().stop_words(&words);
+        assert_eq!(slugifier.arbitrary_slug(3), None);
+    }
+}
@@ -0,0 +1,127 @@
+//! Checked and lossy slugification of byte strings that should be ASCII.
+
+use crate::{AsciiError, Slugifier};
+
+/// What `slugify_ascii_lossy` does with bytes outside of ASCII.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum NonAsciiPolicy {
+    /// Treat each non-ASCII byte as a word boundary, like `slugify_ascii`.
+    #[default]
+    Separate,
+    /// Drop non-ASCII bytes, joining the text around them.
+    Remove,
+    /// Decode the input as UTF-8, replacing invalid sequences, and
+    /// transliterate it like `slugify`.
+    Transliterate,
+}
+
+impl Slugifier {
+    /// Generates a slug like `slugify_ascii`, failing if `text` contains
+    /// bytes outside of ASCII instead of silently treating them as word
+    /// boundaries.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AsciiError` listing every non-ASCII byte and its offset.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    ///
+    /// let slugifier = Slugifier::new();
+    /// assert_eq!(slugifier.try_slugify_ascii(b"Hello, World!").unwrap(), "hello-world");
+    ///
+    /// let err = slugifier.try_slugify_ascii("Café".as_bytes()).unwrap_err();
+    /// assert_eq!(err.invalid_bytes, [(3, 0xc3), (4, 0xa9)]);
+    /// assert_eq!(err.valid_up_to(), 3);
+    /// ```
+    pub fn try_slugify_ascii(&self, text: &[u8]) -> Result<String, AsciiError> {
+        let invalid_bytes: Vec<(usize, u8)> = text
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| !c.is_ascii())
+            .map(|(i, &c)| (i, c))
+            .collect();
+        if !invalid_bytes.is_empty() {
+            return Err(AsciiError { invalid_bytes });
+        }
+
+        Ok(self.slugify_ascii(text))
+    }
+
+    /// Generates a slug from text that should be ASCII, handling the bytes
+    /// that aren't as `policy` says.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to slugify.
+    /// * `policy` - What to do with bytes outside of ASCII.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::{NonAsciiPolicy, Slugifier};
+    ///
+    /// let slugifier = Slugifier::new();
+    /// let text = "Café au lait".as_bytes();
+    /// assert_eq!(slugifier.slugify_ascii_lossy(text, NonAsciiPolicy::Separate), "caf-au-lait");
+    /// assert_eq!(slugifier.slugify_ascii_lossy(text, NonAsciiPolicy::Remove), "caf-au-lait");
+    /// assert_eq!(
+    ///     slugifier.slugify_ascii_lossy(text, NonAsciiPolicy::Transliterate),
+    ///     "cafe-au-lait"
+    /// );
+    /// ```
+    pub fn slugify_ascii_lossy(&self, text: &[u8], policy: NonAsciiPolicy) -> String {
+        match policy {
+            NonAsciiPolicy::Separate => self.slugify_ascii(text),
+            NonAsciiPolicy::Remove if text.is_ascii() => self.slugify_ascii(text),
+            NonAsciiPolicy::Remove => {
+                let ascii: Vec<u8> = text.iter().copied().filter(u8::is_ascii).collect();
+                self.slugify_ascii(&ascii)
+            }
+            NonAsciiPolicy::Transliterate => self.slugify(&String::from_utf8_lossy(text)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_slugify_ascii() {
+        let slugifier = Slugifier::new();
+        assert_eq!(slugifier.try_slugify_ascii(b"").unwrap(), "");
+        assert_eq!(
+            slugifier.try_slugify_ascii(b"Plain ASCII").unwrap(),
+            slugifier.slugify_ascii(b"Plain ASCII")
+        );
+
+        let err = slugifier.try_slugify_ascii(b"a\xffb\x80").unwrap_err();
+        assert_eq!(err.invalid_bytes, [(1, 0xff), (3, 0x80)]);
+        assert_eq!(
+            err.to_string(),
+            "input contains 2 non-ASCII byte(s), the first is 0xff at offset 1"
+        );
+    }
+
+    #[test]
+    fn test_lossy_policies() {
+        let slugifier = Slugifier::new();
+        let text = b"na\xefve \xff caf\xc3\xa9";
+        assert_eq!(
+            slugifier.slugify_ascii_lossy(text, NonAsciiPolicy::Separate),
+            "na-ve-caf"
+        );
+        assert_eq!(
+            slugifier.slugify_ascii_lossy(text, NonAsciiPolicy::Remove),
+            "nave-caf"
+        );
+        assert_eq!(
+            slugifier.slugify_ascii_lossy(text, NonAsciiPolicy::Transliterate),
+            "na-ve-cafe"
+        );
+    }
+}
@@ -0,0 +1,109 @@
+//! Slugification of many texts at once.
+//!
+//! With the `rayon` feature, large batches are slugified in parallel.
+
+use crate::Slugifier;
+
+/// Batches smaller than this are slugified sequentially, since spreading them
+/// over threads costs more than it saves.
+#[cfg(feature = "rayon")]
+const PARALLEL_THRESHOLD: usize = 1024;
+
+/// The number of texts `slugify_stream` slugifies at a time.
+const STREAM_CHUNK_SIZE: usize = 4096;
+
+impl Slugifier {
+    /// Slugifies every text of `texts`, returning the slugs in the same order.
+    ///
+    /// With the `rayon` feature, large batches are slugified in parallel.
+    ///
+    /// # Arguments
+    ///
+    /// * `texts` - The texts to slugify.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// let slugs = Slugifier::new().slugify_batch(&["Hello World", "Foo & Bar"]);
+    /// assert_eq!(slugs, ["hello-world", "foo-bar"]);
+    /// ```
+    pub fn slugify_batch<S: AsRef<str> + Sync>(&self, texts: &[S]) -> Vec<String> {
+        #[cfg(feature = "rayon")]
+        if texts.len() >= PARALLEL_THRESHOLD {
+            use rayon::prelude::*;
+            return texts
+                .par_iter()
+                .map(|text| self.slugify(text.as_ref()))
+                .collect();
+        }
+
+        texts
+            .iter()
+            .map(|text| self.slugify(text.as_ref()))
+            .collect()
+    }
+
+    /// Lazily slugifies the texts of an iterator, yielding the slugs in the
+    /// same order.
+    ///
+    /// Texts are read and slugified in chunks, so memory use stays bounded
+    /// for arbitrarily long inputs. With the `rayon` feature, each chunk is
+    /// slugified in parallel.
+    ///
+    /// # Arguments
+    ///
+    /// * `texts` - The texts to slugify.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// let titles = ["First Post", "Second Post"].into_iter().map(String::from);
+    /// let slugs: Vec<String> = Slugifier::new().slugify_stream(titles).collect();
+    /// assert_eq!(slugs, ["first-post", "second-post"]);
+    /// ```
+    pub fn slugify_stream<'a, I>(&'a self, texts: I) -> impl Iterator<Item = String> + 'a
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str> + Sync + 'a,
+        I::IntoIter: 'a,
+    {
+        let mut texts = texts.into_iter();
+        let mut chunk = Vec::new();
+        std::iter::from_fn(move || {
+            chunk.clear();
+            chunk.extend(texts.by_ref().take(STREAM_CHUNK_SIZE));
+            (!chunk.is_empty()).then(|| self.slugify_batch(&chunk))
+        })
+        .flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slugify_batch_preserves_order() {
+        let texts: Vec<String> = (0..3000).map(|i| format!("Title #{i}")).collect();
+        let slugs = Slugifier::new().slugify_batch(&texts);
+        assert_eq!(slugs.len(), 3000);
+        assert_eq!(slugs[0], "title-0");
+        assert_eq!(slugs[2999], "title-2999");
+    }
+
+    #[test]
+    fn test_slugify_stream_spans_chunks() {
+        let slugifier = Slugifier::new();
+        let texts = (0..STREAM_CHUNK_SIZE + 10).map(|i| format!("Item {i}"));
+        let slugs: Vec<String> = slugifier.slugify_stream(texts).collect();
+        assert_eq!(slugs.len(), STREAM_CHUNK_SIZE + 10);
+        assert!(
+            slugs
+                .iter()
+                .enumerate()
+                .all(|(i, slug)| *slug == format!("item-{i}"))
+        );
+    }
+}
@@ -0,0 +1,258 @@
+//! The `rslug` command-line tool.
+//!
+//! Slugifies its arguments, or each line read from standard input:
+//!
+//! ```text
+//! $ rslug Hello World
+//! hello-world
+//! $ cat titles.txt | rslug --stdin
+//! $ rslug --csv --column title < posts.csv
+//! $ rslug rename --recursive --dry-run ~/Downloads
+//! ```
+
+mod rename;
+#[cfg(test)]
+#[path = "../../scratch.rs"]
+mod scratch;
+mod structured;
+
+use std::io::{self, BufRead, BufWriter, Write};
+use std::process::ExitCode;
+
+use rslug::Slugifier;
+
+const USAGE: &str = "\
+Usage: rslug [OPTIONS] [TEXT]...
+       rslug [OPTIONS] --stdin
+       rslug [OPTIONS] (--json | --csv) [--column <NAME>]
+       rslug rename [OPTIONS] <DIR>
+
+Slugifies TEXT, or each line read from standard input.
+See `rslug rename --help` for renaming files.
+
+Options:
+  -s, --separator <SEP>   Separate words with SEP [default: -]
+  -k, --keep-case         Don't lowercase the slug
+      --stdin             Read one text per line from standard input
+  -0, --null-delimited    With --stdin, read and write NUL-terminated records
+      --json              Read JSON objects, one per line, from standard input
+                          and add a \"slug\" field to each
+      --csv               Read CSV with a header row from standard input and
+                          add a \"slug\" column
+  -c, --column <NAME>     With --json or --csv, the field to slugify [default: title]
+  -h, --help              Print this help
+";
+
+/// A structured input format.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Format {
+    Json,
+    Csv,
+}
+
+/// The command line, parsed.
+#[derive(Debug, Default, PartialEq)]
+struct Options {
+    separator: Option<String>,
+    keep_case: bool,
+    stdin: bool,
+    null_delimited: bool,
+    format: Option<Format>,
+    column: Option<String>,
+    help: bool,
+    texts: Vec<String>,
+}
+
+impl Options {
+    /// Parses the arguments, without the program name.
+    fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut options = Options::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-s" | "--separator" => {
+                    let separator = args
+                        .next()
+                        .ok_or_else(|| format!("{arg} requires a value"))?;
+                    options.separator = Some(separator);
+                }
+                "-k" | "--keep-case" => options.keep_case = true,
+                "--stdin" => options.stdin = true,
+                "-0" | "--null-delimited" => options.null_delimited = true,
+                "--json" => options.format = Some(Format::Json),
+                "--csv" => options.format = Some(Format::Csv),
+                "-c" | "--column" => {
+                    let column = args
+                        .next()
+                        .ok_or_else(|| format!("{arg} requires a value"))?;
+                    options.column = Some(column);
+                }
+                "-h" | "--help" => options.help = true,
+                "--" => options.texts.extend(args.by_ref()),
+                _ if arg.starts_with('-') && arg.len() > 1 => {
+                    return Err(format!("unknown option {arg}"));
+                }
+                _ => options.texts.push(arg),
+            }
+        }
+
+        if options.stdin && !options.texts.is_empty() {
+            return Err("--stdin can't be combined with TEXT arguments".to_string());
+        }
+        if options.null_delimited && !options.stdin {
+            return Err("--null-delimited requires --stdin".to_string());
+        }
+        if options.format.is_some() && (options.stdin || !options.texts.is_empty()) {
+            return Err("--json and --csv read standard input on their own".to_string());
+        }
+        if options.column.is_some() && options.format.is_none() {
+            return Err("--column requires --json or --csv".to_string());
+        }
+        if let Err(err) = options.slugifier().try_build() {
+            return Err(err.to_string());
+        }
+
+        Ok(options)
+    }
+
+    fn slugifier(&self) -> Slugifier {
+        let mut slugifier = Slugifier::new().to_lowercase(!self.keep_case);
+        if let Some(separator) = &self.separator {
+            slugifier = slugifier.separator(separator);
+        }
+        slugifier
+    }
+}
+
+/// Slugifies each record of `input`, writing one slug per record to `output`.
+fn slugify_records<R: BufRead, W: Write>(
+    slugifier: &Slugifier,
+    input: R,
+    mut output: W,
+    null_delimited: bool,
+) -> io::Result<()> {
+    if null_delimited {
+        for record in input.split(b'\0') {
+            let slug = slugifier.slugify_bytes(&record?);
+            output.write_all(slug.as_bytes())?;
+            output.write_all(b"\0")?;
+        }
+    } else {
+        for slug in slugifier.slugify_lines(input) {
+            writeln!(output, "{}", slug?)?;
+        }
+    }
+    output.flush()
+}
+
+fn run(options: &Options) -> io::Result<()> {
+    let slugifier = options.slugifier();
+    let mut stdout = BufWriter::new(io::stdout().lock());
+    let column = options.column.as_deref().unwrap_or("title");
+    match options.format {
+        Some(Format::Json) => {
+            return structured::slugify_json_lines(&slugifier, io::stdin().lock(), stdout, column);
+        }
+        Some(Format::Csv) => {
+            return structured::slugify_csv(&slugifier, io::stdin().lock(), stdout, column);
+        }
+        None => {}
+    }
+    if options.stdin {
+        let stdin = io::stdin().lock();
+        return slugify_records(&slugifier, stdin, stdout, options.null_delimited);
+    }
+
+    writeln!(stdout, "{}", slugifier.slugify(&options.texts.join(" ")))?;
+    stdout.flush()
+}
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1).peekable();
+    if args.peek().is_some_and(|arg| arg == "rename") {
+        args.next();
+        return match rename::Options::parse(args) {
+            Ok(options) if options.help => {
+                print!("{}", rename::USAGE);
+                ExitCode::SUCCESS
+            }
+            Ok(options) => exit_code(rename::run(&options)),
+            Err(err) => {
+                eprintln!("rslug rename: {err}\n\n{}", rename::USAGE);
+                ExitCode::from(2)
+            }
+        };
+    }
+
+    let options = match Options::parse(args) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("rslug: {err}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    let reads_stdin = options.stdin || options.format.is_some();
+    if options.help || (!reads_stdin && options.texts.is_empty()) {
+        print!("{USAGE}");
+        return ExitCode::SUCCESS;
+    }
+
+    exit_code(run(&options))
+}
+
+fn exit_code(result: io::Result<()>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        // The reader of the output went away, e.g. `rslug --stdin | head`.
+        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("rslug: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Options, String> {
+        Options::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_parse_options() {
+        let options = parse(&["-s", "_", "--keep-case", "Hello", "World"]).unwrap();
+        assert_eq!(options.slugifier().slugify("Hello World"), "Hello_World");
+        assert_eq!(options.texts, ["Hello", "World"]);
+
+        assert!(parse(&["--stdin", "text"]).is_err());
+        assert!(parse(&["--null-delimited"]).is_err());
+        assert!(parse(&["--separator"]).is_err());
+        assert!(parse(&["--separator", "x"]).is_err());
+        assert!(parse(&["--bogus"]).is_err());
+        assert_eq!(parse(&["--", "-x"]).unwrap().texts, ["-x"]);
+
+        assert_eq!(parse(&["--csv"]).unwrap().format, Some(Format::Csv));
+        assert!(parse(&["--json", "--stdin"]).is_err());
+        assert!(parse(&["--column", "title"]).is_err());
+    }
+
+    #[test]
+    fn test_slugify_records() {
+        let slugifier = Slugifier::new();
+        let mut output = Vec::new();
+        slugify_records(
+            &slugifier,
+            "First Post\r\nSecond\n".as_bytes(),
+            &mut output,
+            false,
+        )
+        .unwrap();
+        assert_eq!(output, b"first-post\nsecond\n");
+
+        let mut output = Vec::new();
+        slugify_records(&slugifier, "a\nb\0c d\0".as_bytes(), &mut output, true).unwrap();
+        assert_eq!(output, b"a-b\0c-d\0");
+    }
+}
@@ -0,0 +1,344 @@
+//! The `rslug rename` subcommand, which renames files to sanitized or
+//! slugified names.
+
+use std::collections::HashSet;
+use std::fs;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use rslug::Slugifier;
+
+pub(crate) const USAGE: &str = "\
+Usage: rslug rename [OPTIONS] <DIR>
+       rslug rename --undo <MANIFEST>
+
+Renames the files in DIR to sanitized names, or to slugs with --slug.
+Hidden files are left alone, and names that are taken get a numbered suffix.
+
+Options:
+  -r, --recursive         Rename files in subdirectories as well
+  -n, --dry-run           Print the renames without renaming anything
+      --slug              Slugify names, keeping their extension
+  -m, --manifest <FILE>   Record the renames in FILE, to undo them later
+      --undo <MANIFEST>   Undo the renames recorded in MANIFEST
+  -h, --help              Print this help
+";
+
+/// How new names are made.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+enum Mode {
+    /// Only remove what is illegal in filenames.
+    #[default]
+    Sanitize,
+    /// Slugify the name, keeping the extension.
+    Slug,
+}
+
+/// The `rename` command line, parsed.
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct Options {
+    dir: Option<PathBuf>,
+    recursive: bool,
+    dry_run: bool,
+    mode: Mode,
+    manifest: Option<PathBuf>,
+    undo: Option<PathBuf>,
+    pub(crate) help: bool,
+}
+
+impl Options {
+    /// Parses the arguments after `rename`.
+    pub(crate) fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut options = Options::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .map(PathBuf::from)
+                    .ok_or_else(|| format!("{arg} requires a value"))
+            };
+            match arg.as_str() {
+                "-r" | "--recursive" => options.recursive = true,
+                "-n" | "--dry-run" => options.dry_run = true,
+                "--slug" => options.mode = Mode::Slug,
+                "-m" | "--manifest" => options.manifest = Some(value()?),
+                "--undo" => options.undo = Some(value()?),
+                "-h" | "--help" => options.help = true,
+                _ if arg.starts_with('-') => return Err(format!("unknown option {arg}")),
+                _ if options.dir.is_none() => options.dir = Some(PathBuf::from(arg)),
+                _ => return Err(format!("unexpected argument {arg}")),
+            }
+        }
+
+        if !options.help && options.dir.is_none() == options.undo.is_none() {
+            return Err("expected either a DIR or --undo".to_string());
+        }
+
+        Ok(options)
+    }
+}
+
+/// Runs the subcommand, printing each rename as it is made.
+pub(crate) fn run(options: &Options) -> io::Result<()> {
+    let mut stdout = BufWriter::new(io::stdout().lock());
+
+    let renames = match (&options.dir, &options.undo) {
+        (_, Some(manifest)) => read_manifest(manifest)?
+            .into_iter()
+            .rev()
+            .map(|(from, to)| (to, from))
+            .collect(),
+        (Some(dir), None) => plan(&Slugifier::new(), dir, options.recursive, options.mode)?,
+        (None, None) => Vec::new(),
+    };
+
+    // The manifest is written as renames are made, so that it covers them
+    // even if a later rename fails.
+    let mut manifest = match &options.manifest {
+        Some(path) if !options.dry_run => Some(BufWriter::new(fs::File::create(path)?)),
+        _ => None,
+    };
+    for (from, to) in &renames {
+        writeln!(stdout, "{} -> {}", from.display(), to.display())?;
+        if options.dry_run {
+            continue;
+        }
+
+        // On case-insensitive filesystems, a rename that only changes case
+        // finds the file itself at the new path.
+        let is_case_change =
+            from.to_string_lossy().to_lowercase() == to.to_string_lossy().to_lowercase();
+        if to.exists() && !is_case_change {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} already exists", to.display()),
+            ));
+        }
+        fs::rename(from, to)?;
+        if let Some(manifest) = &mut manifest {
+            write_manifest_line(manifest, from, to)?;
+        }
+    }
+
+    if let Some(manifest) = &mut manifest {
+        manifest.flush()?;
+    }
+    stdout.flush()
+}
+
+/// Plans the renames of the entries of `dir`, in the order they must be made:
+/// the entries of a subdirectory come before the subdirectory itself.
+fn plan(
+    slugifier: &Slugifier,
+    dir: &Path,
+    recursive: bool,
+    mode: Mode,
+) -> io::Result<Vec<(PathBuf, PathBuf)>> {
+    let mut entries = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        entries.push((entry.file_name(), entry.file_type()?.is_dir()));
+    }
+    entries.sort();
+
+    let mut renames = Vec::new();
+    // Names that differ only in case are treated as taken, since they collide
+    // on case-insensitive filesystems.
+    let mut taken: HashSet<String> = entries
+        .iter()
+        .map(|(name, _)| name.to_string_lossy().to_lowercase())
+        .collect();
+    for (file_name, is_dir) in &entries {
+        let name = &*file_name.to_string_lossy();
+        if name.starts_with('.') {
+            continue;
+        }
+        if *is_dir && recursive {
+            renames.extend(plan(slugifier, &dir.join(file_name), recursive, mode)?);
+        }
+
+        let new_name = match mode {
+            Mode::Sanitize => slugifier.sanitize_filename(name),
+            Mode::Slug if *is_dir => slugifier.slugify(name),
+            Mode::Slug => slugify_keeping_extension(slugifier, name),
+        };
+        if new_name.is_empty() || new_name == name {
+            continue;
+        }
+
+        let suffix = match mode {
+            Mode::Sanitize => " ({n})",
+            Mode::Slug => "-{n}",
+        };
+        let is_free = |candidate: &str| {
+            let folded = candidate.to_lowercase();
+            folded == name.to_lowercase() || !taken.contains(&folded)
+        };
+        let new_name = if is_free(&new_name) {
+            new_name
+        } else {
+            (2..)
+                .map(|n| with_suffix(&new_name, &suffix.replace("{n}", &n.to_string())))
+                .find(|candidate| is_free(candidate))
+                .expect("an unbounded counter always finds a free name")
+        };
+
+        taken.insert(new_name.to_lowercase());
+        renames.push((dir.join(file_name), dir.join(new_name)));
+    }
+
+    Ok(renames)
+}
+
+/// Slugifies the stem of `name`, keeping a short alphanumeric extension.
+fn slugify_keeping_extension(slugifier: &Slugifier, name: &str) -> String {
+    match name.rsplit_once('.') {
+        Some((stem, extension))
+            if !stem.is_empty()
+                && (1..=8).contains(&extension.len())
+                && extension.bytes().all(|c| c.is_ascii_alphanumeric()) =>
+        {
+            let stem = slugifier.slugify(stem);
+            if stem.is_empty() {
+                return String::new();
+            }
+            format!("{stem}.{}", extension.to_ascii_lowercase())
+        }
+        _ => slugifier.slugify(name),
+    }
+}
+
+/// Inserts `suffix` before the extension of `name`.
+fn with_suffix(name: &str, suffix: &str) -> String {
+    match name.rsplit_once('.') {
+        Some((stem, extension)) if !stem.is_empty() => format!("{stem}{suffix}.{extension}"),
+        _ => format!("{name}{suffix}"),
+    }
+}
+
+/// Records a rename in a manifest, as the old and new path separated by a
+/// tab, with backslashes, tabs, and newlines escaped.
+fn write_manifest_line<W: Write>(manifest: &mut W, from: &Path, to: &Path) -> io::Result<()> {
+    let from = escape(&from.to_string_lossy());
+    let to = escape(&to.to_string_lossy());
+    writeln!(manifest, "{from}\t{to}")
+}
+
+/// Reads the renames recorded with `write_manifest_line`.
+fn read_manifest(path: &Path) -> io::Result<Vec<(PathBuf, PathBuf)>> {
+    let file = BufReader::new(fs::File::open(path)?);
+    let mut renames = Vec::new();
+    for line in file.lines() {
+        let line = line?;
+        let Some((from, to)) = line.split_once('\t') else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("malformed manifest line {line:?}"),
+            ));
+        };
+        renames.push((PathBuf::from(unescape(from)), PathBuf::from(unescape(to))));
+    }
+    Ok(renames)
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+}
+
+fn unescape(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => unescaped.push('\t'),
+            Some('n') => unescaped.push('\n'),
+            Some(other) => unescaped.push(other),
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scratch::scratch_dir;
+
+    fn file_names(dir: &Path) -> Vec<String> {
+        let mut names: Vec<String> = fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn test_plan_handles_collisions() {
+        let dir = scratch_dir("collisions");
+        for name in [
+            "My Report.PDF",
+            "my-report.pdf",
+            "Notes.txt",
+            "notes .txt",
+            "Draft  v2.txt",
+            ".hidden file",
+        ] {
+            fs::write(dir.join(name), "").unwrap();
+        }
+
+        let slugs = plan(&Slugifier::new(), &dir, false, Mode::Slug).unwrap();
+        assert_eq!(
+            slugs,
+            [
+                (dir.join("Draft  v2.txt"), dir.join("draft-v2.txt")),
+                (dir.join("My Report.PDF"), dir.join("my-report-2.pdf")),
+                (dir.join("Notes.txt"), dir.join("notes.txt")),
+                (dir.join("notes .txt"), dir.join("notes-2.txt"))
+            ]
+        );
+
+        let sanitized = plan(&Slugifier::new(), &dir, false, Mode::Sanitize).unwrap();
+        assert_eq!(
+            sanitized,
+            [(dir.join("Draft  v2.txt"), dir.join("Draft v2.txt"))]
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_rename_and_undo() {
+        let dir = scratch_dir("undo");
+        fs::create_dir(dir.join("Sub Dir")).unwrap();
+        fs::write(dir.join("Sub Dir").join("Read Me.md"), "").unwrap();
+        let manifest = std::env::temp_dir().join(format!("rslug-manifest-{}", std::process::id()));
+
+        let args = ["-r", "--slug", "-m"].map(String::from);
+        let args = args
+            .into_iter()
+            .chain([manifest.display().to_string(), dir.display().to_string()]);
+        run(&Options::parse(args).unwrap()).unwrap();
+        assert_eq!(file_names(&dir), ["sub-dir"]);
+        assert_eq!(file_names(&dir.join("sub-dir")), ["read-me.md"]);
+
+        let args = ["--undo".to_string(), manifest.display().to_string()];
+        run(&Options::parse(args).unwrap()).unwrap();
+        assert_eq!(file_names(&dir), ["Sub Dir"]);
+        assert_eq!(file_names(&dir.join("Sub Dir")), ["Read Me.md"]);
+
+        fs::remove_dir_all(dir).unwrap();
+        fs::remove_file(manifest).unwrap();
+    }
+
+    #[test]
+    fn test_manifest_escaping() {
+        let text = "tab\there\nnewline\\backslash";
+        assert_eq!(unescape(&escape(text)), text);
+    }
+}
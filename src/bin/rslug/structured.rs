@@ -0,0 +1,372 @@
+//! Slugifying a field of JSON Lines or CSV records, adding the slug to each
+//! record.
+
+use std::io::{self, BufRead, Write};
+
+use rslug::Slugifier;
+
+/// The name of the field or column the slug is added as.
+const SLUG_FIELD: &str = "slug";
+
+/// Reads JSON objects, one per line, and writes each with a `slug` field
+/// holding the slug of its `field`, or `null` if it isn't a string.
+pub(crate) fn slugify_json_lines<R: BufRead, W: Write>(
+    slugifier: &Slugifier,
+    input: R,
+    mut output: W,
+    field: &str,
+) -> io::Result<()> {
+    for (i, line) in input.lines().enumerate() {
+        let line = line?;
+        let record = line.trim();
+        if record.is_empty() {
+            continue;
+        }
+
+        let value = find_json_field(record, field).map_err(|err| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("line {}: {err}", i + 1))
+        })?;
+        let slug = match value {
+            Some(value) => json_string(&slugifier.slugify(&value)),
+            None => "null".to_string(),
+        };
+
+        // The record ends with the `}` of its object, which was checked above.
+        let body = record[..record.len() - 1].trim_end();
+        let comma = if body == "{" { "" } else { "," };
+        writeln!(output, "{body}{comma}\"{SLUG_FIELD}\":{slug}}}")?;
+    }
+    output.flush()
+}
+
+/// Returns the value of the top-level `field` of the JSON object `record`, if
+/// it is a string.
+fn find_json_field(record: &str, field: &str) -> Result<Option<String>, String> {
+    let mut scanner = Scanner {
+        bytes: record.as_bytes(),
+        pos: 0,
+    };
+    scanner.expect(b'{')?;
+    scanner.skip_whitespace();
+
+    let mut found = None;
+    if scanner.peek() == Some(b'}') {
+        scanner.pos += 1;
+    } else {
+        loop {
+            scanner.skip_whitespace();
+            let key = scanner.string()?;
+            scanner.skip_whitespace();
+            scanner.expect(b':')?;
+            scanner.skip_whitespace();
+            if key == field && found.is_none() && scanner.peek() == Some(b'"') {
+                found = Some(scanner.string()?);
+            } else {
+                scanner.skip_value()?;
+            }
+            scanner.skip_whitespace();
+            match scanner.next() {
+                Some(b',') => continue,
+                Some(b'}') => break,
+                _ => return Err("expected `,` or `}`".to_string()),
+            }
+        }
+    }
+
+    if scanner.pos != record.len() {
+        return Err("unexpected data after the object".to_string());
+    }
+    Ok(found)
+}
+
+/// A minimal JSON scanner, which only decodes strings and skips other values.
+struct Scanner<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Scanner<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<u8> {
+        let c = self.peek()?;
+        self.pos += 1;
+        Some(c)
+    }
+
+    fn expect(&mut self, expected: u8) -> Result<(), String> {
+        match self.next() {
+            Some(c) if c == expected => Ok(()),
+            _ => Err(format!("expected `{}`", expected as char)),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self
+            .peek()
+            .is_some_and(|c| matches!(c, b' ' | b'\t' | b'\r' | b'\n'))
+        {
+            self.pos += 1;
+        }
+    }
+
+    /// Decodes a string, including its escapes.
+    fn string(&mut self) -> Result<String, String> {
+        self.expect(b'"')?;
+        let mut bytes = Vec::new();
+        loop {
+            match self.next().ok_or("unterminated string")? {
+                b'"' => break,
+                b'\\' => {
+                    let escaped = match self.next().ok_or("unterminated string")? {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{C}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => self.unicode_escape()?,
+                        _ => return Err("invalid escape".to_string()),
+                    };
+                    let mut buffer = [0; 4];
+                    bytes.extend_from_slice(escaped.encode_utf8(&mut buffer).as_bytes());
+                }
+                c => bytes.push(c),
+            }
+        }
+        String::from_utf8(bytes).map_err(|_| "invalid UTF-8 in string".to_string())
+    }
+
+    /// Decodes the code point of a `\u` escape, which may be a surrogate pair.
+    fn unicode_escape(&mut self) -> Result<char, String> {
+        let high = self.hex4()?;
+        let code_point = if (0xD800..0xDC00).contains(&high) {
+            if self.next() != Some(b'\\') || self.next() != Some(b'u') {
+                return Err("unpaired surrogate".to_string());
+            }
+            let low = self.hex4()?;
+            if !(0xDC00..0xE000).contains(&low) {
+                return Err("unpaired surrogate".to_string());
+            }
+            0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+        } else {
+            high
+        };
+        char::from_u32(code_point).ok_or_else(|| "invalid code point".to_string())
+    }
+
+    fn hex4(&mut self) -> Result<u32, String> {
+        let digits = self
+            .bytes
+            .get(self.pos..self.pos + 4)
+            .and_then(|digits| std::str::from_utf8(digits).ok())
+            .and_then(|digits| u32::from_str_radix(digits, 16).ok())
+            .ok_or("invalid `\\u` escape")?;
+        self.pos += 4;
+        Ok(digits)
+    }
+
+    /// Skips a value of any type.
+    fn skip_value(&mut self) -> Result<(), String> {
+        match self.peek().ok_or("expected a value")? {
+            b'"' => self.string().map(|_| ()),
+            b'{' | b'[' => {
+                // Strings are skipped whole, so brackets inside them aren't counted.
+                let mut depth = 0;
+                loop {
+                    match self.peek().ok_or("unterminated value")? {
+                        b'"' => {
+                            self.string()?;
+                            continue;
+                        }
+                        b'{' | b'[' => depth += 1,
+                        b'}' | b']' => depth -= 1,
+                        _ => {}
+                    }
+                    self.pos += 1;
+                    if depth == 0 {
+                        return Ok(());
+                    }
+                }
+            }
+            _ => {
+                let start = self.pos;
+                while self
+                    .peek()
+                    .is_some_and(|c| c.is_ascii_alphanumeric() || matches!(c, b'-' | b'+' | b'.'))
+                {
+                    self.pos += 1;
+                }
+                if self.pos == start {
+                    return Err("expected a value".to_string());
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Encodes `text` as a JSON string.
+fn json_string(text: &str) -> String {
+    let mut encoded = String::with_capacity(text.len() + 2);
+    encoded.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => encoded.push_str("\\\""),
+            '\\' => encoded.push_str("\\\\"),
+            c if c.is_control() => encoded.push_str(&format!("\\u{:04x}", c as u32)),
+            c => encoded.push(c),
+        }
+    }
+    encoded.push('"');
+    encoded
+}
+
+/// Reads CSV records with a header row, and writes them with a `slug` column
+/// holding the slug of `column`.
+pub(crate) fn slugify_csv<R: BufRead, W: Write>(
+    slugifier: &Slugifier,
+    mut input: R,
+    mut output: W,
+    column: &str,
+) -> io::Result<()> {
+    let Some(mut header) = read_csv_record(&mut input)? else {
+        return output.flush();
+    };
+    let index = header
+        .iter()
+        .position(|name| name == column)
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("no column is named {column:?}"),
+            )
+        })?;
+    header.push(SLUG_FIELD.to_string());
+    write_csv_record(&mut output, &header)?;
+
+    while let Some(mut record) = read_csv_record(&mut input)? {
+        let slug = record
+            .get(index)
+            .map(|value| slugifier.slugify(value))
+            .unwrap_or_default();
+        record.push(slug);
+        write_csv_record(&mut output, &record)?;
+    }
+    output.flush()
+}
+
+/// Reads one CSV record, whose quoted fields may span lines.
+fn read_csv_record<R: BufRead>(input: &mut R) -> io::Result<Option<Vec<String>>> {
+    let mut line = String::new();
+    if input.read_line(&mut line)? == 0 {
+        return Ok(None);
+    }
+
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    loop {
+        let mut chars = line.chars().peekable();
+        while let Some(c) = chars.next() {
+            match (c, in_quotes) {
+                ('"', true) if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                ('"', true) => in_quotes = false,
+                ('"', false) if field.is_empty() => in_quotes = true,
+                (',', false) => fields.push(std::mem::take(&mut field)),
+                ('\r' | '\n', false) => {}
+                (c, _) => field.push(c),
+            }
+        }
+
+        if !in_quotes {
+            break;
+        }
+        line.clear();
+        if input.read_line(&mut line)? == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "unterminated quoted field",
+            ));
+        }
+    }
+    fields.push(field);
+
+    Ok(Some(fields))
+}
+
+/// Writes one CSV record, quoting the fields that need it.
+fn write_csv_record<W: Write>(output: &mut W, fields: &[String]) -> io::Result<()> {
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            output.write_all(b",")?;
+        }
+        if field.contains([',', '"', '\r', '\n']) {
+            write!(output, "\"{}\"", field.replace('"', "\"\""))?;
+        } else {
+            output.write_all(field.as_bytes())?;
+        }
+    }
+    output.write_all(b"\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn json_lines(input: &str, field: &str) -> io::Result<String> {
+        let mut output = Vec::new();
+        slugify_json_lines(&Slugifier::new(), input.as_bytes(), &mut output, field)?;
+        Ok(String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn test_json_lines() {
+        let input = concat!(
+            r#"{"id": 1, "tags": ["a}"], "title": "Hello \"World\" über"}"#,
+            "\n\n",
+            r#"{"id": 2}"#,
+            "\n{}\n",
+        );
+        assert_eq!(
+            json_lines(input, "title").unwrap(),
+            concat!(
+                r#"{"id": 1, "tags": ["a}"], "title": "Hello \"World\" über","slug":"hello-world-uber"}"#,
+                "\n",
+                r#"{"id": 2,"slug":null}"#,
+                "\n",
+                r#"{"slug":null}"#,
+                "\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_json_lines_rejects_malformed_records() {
+        let err = json_lines("{\"title\": \"a\"}\n{\"title\": }\n", "title").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().starts_with("line 2"));
+        assert!(json_lines("[1, 2]\n", "title").is_err());
+    }
+
+    #[test]
+    fn test_csv() {
+        let input = "id,title\n1,\"Hello, World\"\n2,\"Multi\nLine \"\"Quote\"\"\"\n3\n";
+        let mut output = Vec::new();
+        slugify_csv(&Slugifier::new(), input.as_bytes(), &mut output, "title").unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "id,title,slug\n1,\"Hello, World\",hello-world\n2,\"Multi\nLine \"\"Quote\"\"\",multi-line-quote\n3,\n"
+        );
+
+        let err = slugify_csv(&Slugifier::new(), input.as_bytes(), Vec::new(), "name");
+        assert!(err.is_err());
+    }
+}
@@ -0,0 +1,685 @@
+//! The incremental core of the slug pipeline.
+
+use std::fmt;
+
+use crate::{
+    NumberMode, OrdinalMode, RomanNumerals, Slugifier, chars, numbers, roman, thai, trace, translit,
+};
+
+/// Stands in for the separator while words are built separately, since it
+/// can't appear in a slug.
+pub(crate) const WORD_BREAK: &str = "\0";
+
+/// What the slug pipeline does with a byte of ASCII text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ByteAction {
+    /// Separates words, like spaces and punctuation.
+    Separate,
+    /// Is dropped without separating words, set with `remove_chars`.
+    Remove,
+    /// Is kept if it sits between two alphanumerics, set with `allowed_chars`.
+    Allow,
+    /// Is kept as part of a word.
+    Keep,
+    /// Is kept as part of a word, lowercased unless the word keeps its case.
+    KeepLower,
+}
+
+/// The action for each byte, built from the slugifier's case and character
+/// options when they are set, so slugifying only looks bytes up.
+#[derive(Debug, Clone)]
+pub(crate) struct ByteClasses {
+    actions: [ByteAction; 256],
+}
+
+impl ByteClasses {
+    pub(crate) fn new(to_lowercase: bool, allowed_chars: &str, remove_chars: &str) -> Self {
+        let mut actions = [ByteAction::Separate; 256];
+        for c in allowed_chars.bytes().filter(u8::is_ascii) {
+            actions[c as usize] = ByteAction::Allow;
+        }
+        for c in (b'0'..=b'9').chain(b'a'..=b'z') {
+            actions[c as usize] = ByteAction::Keep;
+        }
+        for c in b'A'..=b'Z' {
+            actions[c as usize] = if to_lowercase {
+                ByteAction::KeepLower
+            } else {
+                ByteAction::Keep
+            };
+        }
+        for c in remove_chars.bytes().filter(u8::is_ascii) {
+            actions[c as usize] = ByteAction::Remove;
+        }
+        Self { actions }
+    }
+
+    pub(crate) fn action(&self, c: u8) -> ByteAction {
+        self.actions[c as usize]
+    }
+
+    fn is_allowed(&self, c: u8) -> bool {
+        self.action(c) == ByteAction::Allow
+    }
+}
+
+/// Builds a slug from ASCII input that arrives in pieces.
+///
+/// The state carried between pieces is what lets a word, a separator run, or
+/// an allowed character span two pieces, so feeding the input at once or in
+/// any split produces the same slug. The slug is written to `out` as it is
+/// built, which is a `String` unless it is streamed into a caller's sink.
+pub(crate) struct SlugBuilder<'a, W = String> {
+    slugifier: &'a Slugifier,
+    /// The separator between words, which is the slugifier's unless overridden.
+    separator: &'a str,
+    classes: &'a ByteClasses,
+    out: W,
+    /// Whether anything was written to `out`.
+    written: bool,
+    /// The first error returned by `out`.
+    result: fmt::Result,
+    /// The number of separators due before the next word, which is at most
+    /// one when separators are collapsed.
+    separators: usize,
+    /// Whether the last character kept was alphanumeric.
+    after_alphanumeric: bool,
+    /// Whether the last character kept was a digit.
+    after_digit: bool,
+    /// Whether the last byte pushed was alphanumeric, kept or not.
+    after_word_byte: bool,
+    /// Whether the run being written keeps its case, like a roman numeral.
+    keep_case: bool,
+    /// An allowed character that is only kept if an alphanumeric follows.
+    pending: Option<u8>,
+    /// Whether `pending` is punctuation inside a word, which is dropped if a
+    /// letter follows.
+    joining: bool,
+}
+
+impl<'a> SlugBuilder<'a> {
+    /// Creates a builder that writes into `buffer`, which is cleared first.
+    pub(crate) fn with_buffer(slugifier: &'a Slugifier, mut buffer: String) -> Self {
+        buffer.clear();
+        Self::with_sink(slugifier, buffer)
+    }
+
+    /// Creates a builder with an empty slug.
+    pub(crate) fn new(slugifier: &'a Slugifier) -> Self {
+        Self::with_buffer(slugifier, String::new())
+    }
+
+    /// Returns the length of the slug built so far.
+    pub(crate) fn written_len(&self) -> usize {
+        self.out.len()
+    }
+
+    /// Finishes the slug, applying stop words, truncation, and the preset's rules.
+    pub(crate) fn finish(mut self) -> String {
+        self.end();
+        finish_slug(self.slugifier, self.out)
+    }
+}
+
+/// Applies the rules that need the whole slug: stop words, truncation, and
+/// the preset's rules.
+pub(crate) fn finish_slug(slugifier: &Slugifier, slug: String) -> String {
+    finish_slug_with(slugifier, slug, |word| is_stop_word(slugifier, word))
+}
+
+/// Applies the rules that need the whole slug like `finish_slug`, telling
+/// stop words apart with `is_stop_word`, like a lookup in a prebuilt set.
+pub(crate) fn finish_slug_with(
+    slugifier: &Slugifier,
+    slug: String,
+    is_stop_word: impl Fn(&str) -> bool,
+) -> String {
+    let len = slug.len();
+    let (mut slug, _) = remove_stop_words_with(slugifier, slug, is_stop_word);
+    trace::stage!("stop_words", len, &slug);
+
+    if slugifier.max_segments.is_some() {
+        let len = slug.len();
+        limit_segments(slugifier, &mut slug);
+        trace::stage!("max_segments", len, &slug);
+    }
+
+    let len = slug.len();
+    slugifier.apply_truncation(&mut slug);
+    trace::stage!("truncation", len, &slug);
+
+    if let Some(preset) = slugifier.preset {
+        let len = slug.len();
+        preset.finish(slugifier, &mut slug);
+        trace::stage!("preset", len, &slug);
+    }
+
+    slug
+}
+
+/// Returns the ASCII digit that `c` is written as, if it is a digit other
+/// than an ASCII one.
+fn fold_digit(c: char) -> Option<char> {
+    if c.is_ascii() || !c.is_numeric() {
+        return None;
+    }
+    match translit::transliterate_char(c).as_bytes() {
+        &[digit] if digit.is_ascii_digit() => Some(digit as char),
+        _ => None,
+    }
+}
+
+/// Returns the end of the number in `text` whose first run of digits ends at
+/// `end`, including the runs joined by decimal or grouping punctuation, like
+/// the ".5" of "3.5".
+fn number_end(text: &[u8], mut end: usize) -> usize {
+    while let [b'.' | b',', rest @ ..] = &text[end..] {
+        let len = rest
+            .iter()
+            .take_while(|c| c.is_ascii_alphanumeric())
+            .count();
+        if len == 0 || !rest[..len].iter().all(u8::is_ascii_digit) {
+            break;
+        }
+        end += 1 + len;
+    }
+    end
+}
+
+/// Removes the segments past `max_segments` from `slug`, returning the number
+/// of segments removed.
+pub(crate) fn limit_segments(slugifier: &Slugifier, slug: &mut String) -> usize {
+    let Some(max) = slugifier.max_segments else {
+        return 0;
+    };
+    if slugifier.separator.is_empty() || slug.is_empty() {
+        return 0;
+    }
+
+    let mut segments = slug.split(slugifier.separator.as_str());
+    let kept_len: usize = segments.by_ref().take(max).map(str::len).sum::<usize>()
+        + max.saturating_sub(1) * slugifier.separator.len();
+    let removed = segments.count();
+    if removed > 0 {
+        slug.truncate(kept_len);
+    }
+    removed
+}
+
+/// Removes the configured stop words from `slug`, returning the slug and the
+/// number of words removed.
+pub(crate) fn remove_stop_words(slugifier: &Slugifier, slug: String) -> (String, usize) {
+    remove_stop_words_with(slugifier, slug, |word| is_stop_word(slugifier, word))
+}
+
+/// Returns `true` if `word` is one of the configured stop words.
+fn is_stop_word(slugifier: &Slugifier, word: &str) -> bool {
+    slugifier
+        .stop_words
+        .iter()
+        .any(|s| s.eq_ignore_ascii_case(word))
+}
+
+/// Removes the words of `slug` that `is_stop_word` if any stop words are
+/// configured, returning the slug and the number of words removed.
+fn remove_stop_words_with(
+    slugifier: &Slugifier,
+    slug: String,
+    is_stop_word: impl Fn(&str) -> bool,
+) -> (String, usize) {
+    if slugifier.stop_words.is_empty() || slugifier.separator.is_empty() {
+        return (slug, 0);
+    }
+
+    let mut removed = 0;
+    let slug = slug
+        .split(slugifier.separator.as_str())
+        .filter(|word| {
+            let is_stop_word = is_stop_word(word);
+            removed += usize::from(is_stop_word);
+            !is_stop_word
+        })
+        .collect::<Vec<_>>()
+        .join(&slugifier.separator);
+    (slug, removed)
+}
+
+impl Slugifier {
+    /// Returns `true` if roman numerals, ordinals, or numbers are converted,
+    /// which needs to know where words start and end.
+    pub(crate) fn converts_words(&self) -> bool {
+        self.roman_numerals != RomanNumerals::Ignore
+            || self.numbers != NumberMode::Keep
+            || self.ordinals != OrdinalMode::Keep
+    }
+}
+
+impl<'a, W: fmt::Write> SlugBuilder<'a, W> {
+    /// Creates a builder that writes the slug into `out` as it is built.
+    pub(crate) fn with_sink(slugifier: &'a Slugifier, out: W) -> Self {
+        Self {
+            slugifier,
+            separator: &slugifier.separator,
+            classes: &slugifier.classes,
+            out,
+            written: false,
+            result: Ok(()),
+            separators: 0,
+            after_alphanumeric: false,
+            after_digit: false,
+            after_word_byte: false,
+            keep_case: false,
+            pending: None,
+            joining: false,
+        }
+    }
+
+    /// Overrides the separator written between words.
+    pub(crate) fn with_separator(mut self, separator: &'a str) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Appends ASCII text. Bytes outside of ASCII separate words.
+    ///
+    /// Runs of alphanumerics are found first and written in bulk, so most of
+    /// a typical title is copied a word at a time rather than a byte at a time.
+    pub(crate) fn push_bytes(&mut self, text: &[u8]) {
+        self.push_ascii(text, false);
+    }
+
+    /// Appends ASCII text, which is followed by more of the same word if
+    /// `followed_by_alphanumeric`, handling roman numerals, ordinals, and
+    /// numbers if configured.
+    fn push_ascii(&mut self, text: &[u8], followed_by_alphanumeric: bool) {
+        let mode = self.slugifier.roman_numerals;
+        let numbers = self.slugifier.numbers;
+        let ordinals = self.slugifier.ordinals;
+        if !self.slugifier.converts_words() || text.is_empty() {
+            self.push_words(text);
+            return;
+        }
+
+        let mut start = 0;
+        let mut i = 0;
+        while i < text.len() {
+            if !text[i].is_ascii_alphanumeric() {
+                i += 1;
+                continue;
+            }
+            let end = text[i..]
+                .iter()
+                .position(|c| !c.is_ascii_alphanumeric())
+                .map_or(text.len(), |len| i + len);
+
+            let is_standalone =
+                (i > 0 || !self.after_word_byte) && (end < text.len() || !followed_by_alphanumeric);
+            let word = &text[i..end];
+            if is_standalone
+                && mode != RomanNumerals::Ignore
+                && let Some(value) = roman::parse(word)
+            {
+                self.push_words(&text[start..i]);
+                if mode == RomanNumerals::Arabic {
+                    self.push_number(value.to_string().as_bytes());
+                } else {
+                    self.keep_case = true;
+                    self.push_words(word);
+                    self.keep_case = false;
+                }
+                start = end;
+            } else if is_standalone
+                && ordinals != OrdinalMode::Keep
+                && let Some(digits) = numbers::parse_ordinal(word)
+            {
+                self.push_words(&text[start..i]);
+                let words = match ordinals {
+                    OrdinalMode::Words => numbers::to_ordinal_words(&word[..digits]),
+                    _ => None,
+                };
+                match words {
+                    Some(words) => self.push_str(&words),
+                    None if ordinals == OrdinalMode::Digits => self.push_words(&word[..digits]),
+                    None => self.push_words(word),
+                }
+                start = end;
+            } else if is_standalone
+                && numbers != NumberMode::Keep
+                && word.iter().all(u8::is_ascii_digit)
+                && !matches!(text[..i], [.., b'0'..=b'9', b'.' | b','])
+            {
+                // Numbers with decimal or grouping punctuation are only
+                // removed, since they can't be spelled out.
+                let number_end = number_end(text, end);
+                let is_whole = (number_end == end
+                    || (numbers == NumberMode::Remove
+                        && (number_end < text.len() || !followed_by_alphanumeric)))
+                    && !matches!(text[number_end..], [b'.' | b',', c, ..] if c.is_ascii_alphanumeric());
+                if is_whole {
+                    self.push_words(&text[start..i]);
+                    self.push_number(&text[i..number_end]);
+                    start = number_end;
+                    i = number_end;
+                    continue;
+                }
+            }
+            i = end;
+        }
+        self.push_words(&text[start..]);
+        self.after_word_byte = text[text.len() - 1].is_ascii_alphanumeric();
+    }
+
+    /// Appends the transliteration of a symbol as words of its own, so that
+    /// numbers next to it stand alone and aren't joined to it once converted.
+    fn push_symbol(&mut self, ascii: &str, followed_by_alphanumeric: bool) {
+        let ascii = ascii.as_bytes();
+        if ascii.first().is_some_and(u8::is_ascii_alphanumeric) {
+            self.break_word();
+        }
+        self.after_word_byte = false;
+        self.push_bytes(ascii);
+        self.after_word_byte = false;
+        if followed_by_alphanumeric && ascii.last().is_some_and(u8::is_ascii_alphanumeric) {
+            self.break_word();
+        }
+    }
+
+    /// Ends the word being written, as if a separator followed it.
+    fn break_word(&mut self) {
+        if self.after_alphanumeric && self.pending.is_none() {
+            self.add_separator();
+            self.after_alphanumeric = false;
+            self.after_digit = false;
+        }
+    }
+
+    /// Appends a standalone number as the configured `NumberMode` says.
+    fn push_number(&mut self, digits: &[u8]) {
+        let code = self.slugifier.language.map(|language| language.code);
+        match self.slugifier.numbers {
+            NumberMode::Words => match numbers::to_words(digits, code) {
+                Some(words) => self.push_str(&words),
+                None => self.push_words(digits),
+            },
+            NumberMode::Remove => {}
+            _ => self.push_words(digits),
+        }
+    }
+
+    /// Appends ASCII text without looking for roman numerals.
+    fn push_words(&mut self, text: &[u8]) {
+        let mut i = 0;
+        while let Some(&c) = text.get(i) {
+            let action = self.classes.action(c);
+            if action == ByteAction::Remove {
+                i += 1;
+                continue;
+            }
+
+            if matches!(action, ByteAction::Keep | ByteAction::KeepLower) {
+                let mut needs_lowercase = false;
+                let run_len = text[i..]
+                    .iter()
+                    .position(|&c| match self.classes.action(c) {
+                        ByteAction::Keep => false,
+                        ByteAction::KeepLower => {
+                            needs_lowercase = true;
+                            false
+                        }
+                        _ => true,
+                    })
+                    .unwrap_or(text.len() - i);
+
+                let joining = std::mem::take(&mut self.joining);
+                match self.pending.take() {
+                    // Punctuation between two letters is part of the word.
+                    Some(_) if joining && c.is_ascii_alphabetic() => {}
+                    // A dot kept for a version number needs a digit after it too.
+                    Some(kept)
+                        if !joining && (self.classes.is_allowed(kept) || c.is_ascii_digit()) =>
+                    {
+                        self.write_char(kept as char);
+                    }
+                    Some(_) => {
+                        self.add_separator();
+                        self.write_separators();
+                    }
+                    None if self.written || !self.slugifier.trim_separators => {
+                        // If separators were found before, add them before the word.
+                        self.write_separators();
+                    }
+                    None => {}
+                }
+                self.write_run(&text[i..i + run_len], needs_lowercase);
+
+                self.separators = 0;
+                self.after_alphanumeric = true;
+                self.after_digit = text[i + run_len - 1].is_ascii_digit();
+                i += run_len;
+            } else {
+                // Allowed characters are kept only between two alphanumerics,
+                // and dots of version numbers only between two digits.
+                let is_version_dot =
+                    c == b'.' && self.after_digit && self.slugifier.preserve_versions;
+                let is_mid_word = self.slugifier.unicode_words
+                    && self.after_alphanumeric
+                    && !self.after_digit
+                    && self.pending.is_none()
+                    && chars::is_mid_word(c as char);
+                if (action == ByteAction::Allow && self.after_alphanumeric) || is_version_dot {
+                    self.pending = Some(c);
+                } else if is_mid_word {
+                    self.pending = Some(c);
+                    self.joining = true;
+                } else {
+                    self.joining = false;
+                    if self.pending.take().is_some() {
+                        self.add_separator();
+                    }
+                    self.add_separator();
+                }
+                self.after_alphanumeric = false;
+                self.after_digit = false;
+                i += 1;
+            }
+        }
+    }
+
+    /// Appends Unicode text, transliterating it to ASCII a character at a time
+    /// and skipping invisible formatting characters.
+    ///
+    /// When words are converted, other digits are read as ASCII digits, and
+    /// symbols are words of their own, so numbers are found the same way
+    /// whether they are written in ASCII or not.
+    pub(crate) fn push_str(&mut self, text: &str) {
+        let converts_words = self.slugifier.converts_words();
+        let folded;
+        let mut rest = text;
+        if converts_words && text.chars().any(|c| fold_digit(c).is_some()) {
+            folded = text
+                .chars()
+                .map(|c| fold_digit(c).unwrap_or(c))
+                .collect::<String>();
+            rest = &folded;
+        }
+        while !rest.is_empty() {
+            let ascii_len = rest.find(|c: char| !c.is_ascii()).unwrap_or(rest.len());
+            let (ascii, tail) = rest.split_at(ascii_len);
+            let followed_by_alphanumeric = tail.chars().next().is_some_and(char::is_alphabetic);
+            self.push_ascii(ascii.as_bytes(), followed_by_alphanumeric);
+            rest = tail;
+
+            let run = thai::run_len(rest);
+            if run > 0 {
+                self.push_bytes(thai::romanize(&rest[..run]).as_bytes());
+                rest = &rest[run..];
+                continue;
+            }
+
+            if let Some(c) = rest.chars().next() {
+                rest = &rest[c.len_utf8()..];
+                if self.slugifier.unicode_words && chars::is_mid_word(c) {
+                    // Stands in for the punctuation, which has no single
+                    // transliteration.
+                    self.push_bytes(b"'");
+                } else if converts_words && !c.is_alphabetic() && !chars::is_invisible(c) {
+                    let followed_by_alphanumeric =
+                        rest.chars().next().is_some_and(char::is_alphanumeric);
+                    self.push_symbol(translit::transliterate_char(c), followed_by_alphanumeric);
+                } else if !chars::is_invisible(c) {
+                    self.push_bytes(translit::transliterate_char(c).as_bytes());
+                }
+            }
+        }
+    }
+
+    /// Writes a run of ASCII alphanumerics, lowercasing it if it
+    /// `needs_lowercase` and doesn't keep its case.
+    fn write_run(&mut self, run: &[u8], needs_lowercase: bool) {
+        if !needs_lowercase || self.keep_case {
+            // Alphanumerics are ASCII, so this never fails.
+            if let Ok(run) = std::str::from_utf8(run) {
+                self.write_str(run);
+            }
+            return;
+        }
+
+        let mut lowercase = [0; 64];
+        for chunk in run.chunks(lowercase.len()) {
+            let lowercase = &mut lowercase[..chunk.len()];
+            lowercase.copy_from_slice(chunk);
+            lowercase.make_ascii_lowercase();
+            if let Ok(chunk) = std::str::from_utf8(lowercase) {
+                self.write_str(chunk);
+            }
+        }
+    }
+
+    /// Counts a character that separates words.
+    fn add_separator(&mut self) {
+        self.separators = if self.slugifier.collapse_separators {
+            1
+        } else {
+            self.separators + 1
+        };
+    }
+
+    /// Writes the separators due before the next word.
+    fn write_separators(&mut self) {
+        for _ in 0..std::mem::take(&mut self.separators) {
+            self.write_str(self.separator);
+        }
+    }
+
+    /// Ends the input, writing the trailing separators if they are kept.
+    fn end(&mut self) {
+        self.joining = false;
+        if self.pending.take().is_some() {
+            self.add_separator();
+        }
+        if !self.slugifier.trim_separators {
+            self.write_separators();
+        }
+    }
+
+    /// Returns the sink, or the first error it returned.
+    ///
+    /// Unlike `finish`, this applies none of the rules that need the whole slug.
+    pub(crate) fn into_sink(mut self) -> Result<W, fmt::Error> {
+        self.end();
+        self.result.map(|()| self.out)
+    }
+
+    fn write_char(&mut self, c: char) {
+        if self.result.is_ok() {
+            self.result = self.out.write_char(c);
+        }
+        self.written = true;
+    }
+
+    fn write_str(&mut self, s: &str) {
+        if self.result.is_ok() {
+            self.result = self.out.write_str(s);
+        }
+        self.written = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pieces_match_whole_input() {
+        let slugifier = Slugifier::new().allowed_chars(".");
+        let text = b"  Version 1.5 -- released!  ";
+        let whole = slugifier.slugify_ascii(text);
+
+        for split in 0..text.len() {
+            let mut builder = SlugBuilder::new(&slugifier);
+            builder.push_bytes(&text[..split]);
+            builder.push_bytes(&text[split..]);
+            assert_eq!(builder.finish(), whole, "split at {split}");
+        }
+        assert_eq!(whole, "version-1.5-released");
+    }
+
+    #[test]
+    fn test_kept_separators_span_pieces() {
+        let slugifier = Slugifier::new()
+            .allowed_chars(".")
+            .collapse_separators(false)
+            .trim_separators(false);
+        let text = b" v1. -- x.";
+        for split in 0..text.len() {
+            let mut builder = SlugBuilder::new(&slugifier);
+            builder.push_bytes(&text[..split]);
+            builder.push_bytes(&text[split..]);
+            assert_eq!(builder.finish(), "-v1-----x-", "split at {split}");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "transliteration")]
+    fn test_push_str_transliterates() {
+        let slugifier = Slugifier::new();
+        let mut builder = SlugBuilder::new(&slugifier);
+        builder.push_str("Ünïcödé\u{200B}Wörld ");
+        builder.push_str("你好");
+        assert_eq!(builder.finish(), "unicodeworld-nihao");
+    }
+
+    #[test]
+    fn test_long_runs_are_lowercased() {
+        let word = "AbCdEfGhIj".repeat(20);
+        let slug = Slugifier::new().slugify_ascii(format!("{word} {word}").as_bytes());
+        let lowercase = word.to_ascii_lowercase();
+        assert_eq!(slug, format!("{lowercase}-{lowercase}"));
+    }
+
+    #[test]
+    fn test_removed_chars_inside_runs() {
+        let slugifier = Slugifier::new().remove_chars("aeiou");
+        assert_eq!(
+            slugifier.slugify_ascii(b"Quick Brown aaa Fox"),
+            "qck-brwn-fx"
+        );
+    }
+
+    #[test]
+    fn test_byte_actions() {
+        let classes = ByteClasses::new(true, "._a", "'_");
+        assert_eq!(classes.action(b'a'), ByteAction::Keep);
+        assert_eq!(classes.action(b'Q'), ByteAction::KeepLower);
+        assert_eq!(classes.action(b'.'), ByteAction::Allow);
+        assert_eq!(classes.action(b'_'), ByteAction::Remove);
+        assert_eq!(classes.action(b' '), ByteAction::Separate);
+        assert_eq!(classes.action(0xE9), ByteAction::Separate);
+
+        // The table follows the options whichever order they are set in.
+        let slugifier = Slugifier::new().remove_chars("'").to_lowercase(false);
+        assert_eq!(slugifier.slugify_ascii(b"Don't Stop"), "Dont-Stop");
+        let slugifier = Slugifier::new().to_lowercase(false).remove_chars("'");
+        assert_eq!(slugifier.slugify_ascii(b"Don't Stop"), "Dont-Stop");
+    }
+}
@@ -0,0 +1,69 @@
+//! Slugifying byte strings that may not be valid UTF-8.
+
+use std::borrow::Cow;
+
+use crate::Slugifier;
+
+impl Slugifier {
+    /// Generates a slug from bytes that may not be valid UTF-8, like
+    /// filenames and legacy data read from disk.
+    ///
+    /// Valid UTF-8 is slugified like `slugify`. Each byte that isn't part of
+    /// valid UTF-8 is read as Latin-1, the encoding most legacy data that
+    /// isn't UTF-8 is close to, and transliterated along with the rest.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    ///
+    /// let slugifier = Slugifier::new();
+    /// assert_eq!(slugifier.slugify_bytes("Déjà vu".as_bytes()), "deja-vu");
+    /// // "Café" in Latin-1.
+    /// assert_eq!(slugifier.slugify_bytes(b"Caf\xe9 Menu"), "cafe-menu");
+    /// ```
+    pub fn slugify_bytes(&self, text: &[u8]) -> String {
+        self.slugify(&decode_bytes(text))
+    }
+}
+
+/// Decodes `text` as UTF-8, reading the bytes that aren't valid UTF-8 as
+/// Latin-1.
+fn decode_bytes(text: &[u8]) -> Cow<'_, str> {
+    if let Ok(text) = std::str::from_utf8(text) {
+        return Cow::Borrowed(text);
+    }
+
+    let mut decoded = String::with_capacity(text.len() + text.len() / 2);
+    for chunk in text.utf8_chunks() {
+        decoded.push_str(chunk.valid());
+        decoded.extend(chunk.invalid().iter().map(|&c| char::from(c)));
+    }
+    Cow::Owned(decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_bytes() {
+        assert!(matches!(decode_bytes(b"plain"), Cow::Borrowed("plain")));
+        assert_eq!(decode_bytes("ü".as_bytes()), "ü");
+        assert_eq!(decode_bytes(b"\xfc ber \xe2\x82"), "ü ber â\u{82}");
+    }
+
+    #[test]
+    fn test_slugify_bytes() {
+        let slugifier = Slugifier::new();
+        assert_eq!(slugifier.slugify_bytes(b""), "");
+        assert_eq!(
+            slugifier.slugify_bytes("Ünïcode ✓".as_bytes()),
+            slugifier.slugify("Ünïcode ✓")
+        );
+        assert_eq!(
+            slugifier.slugify_bytes(b"Gr\xfc\xdfe aus M\xfcnchen"),
+            "grusse-aus-munchen"
+        );
+    }
+}
@@ -0,0 +1,204 @@
+//! Character classification helpers shared by the slugifier and the filename sanitizer.
+
+/// Returns `true` for invisible formatting characters that should never reach
+/// the output: zero-width characters, soft hyphens, and bidi controls.
+pub(crate) fn is_invisible(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00AD}'              // SOFT HYPHEN
+        | '\u{034F}'            // COMBINING GRAPHEME JOINER
+        | '\u{061C}'            // ARABIC LETTER MARK
+        | '\u{180E}'            // MONGOLIAN VOWEL SEPARATOR
+        | '\u{200B}'..='\u{200F}' // ZWSP, ZWNJ, ZWJ, LRM, RLM
+        | '\u{202A}'..='\u{202E}' // LRE, RLE, PDF, LRO, RLO
+        | '\u{2060}'..='\u{2064}' // WORD JOINER and invisible operators
+        | '\u{2066}'..='\u{2069}' // LRI, RLI, FSI, PDI
+        | '\u{FEFF}' // ZERO WIDTH NO-BREAK SPACE (BOM)
+    )
+}
+
+/// Returns `true` for the no-break spaces copy-pasted web content is full of:
+/// the no-break space of `&nbsp;`, the narrow no-break space French puts
+/// before punctuation, and the figure space.
+pub(crate) fn is_no_break_space(c: char) -> bool {
+    matches!(c, '\u{00A0}' | '\u{2007}' | '\u{202F}')
+}
+
+/// Returns `true` for punctuation that Unicode word segmentation (UAX #29)
+/// keeps inside a word when it is between two letters, like the apostrophe of
+/// "can't" or the middle dot of Catalan "col·lecció".
+///
+/// These are the characters of the `MidLetter`, `MidNumLet`, and
+/// `Single_Quote` word break properties.
+pub(crate) fn is_mid_word(c: char) -> bool {
+    matches!(
+        c,
+        '\'' | '.' | ':'
+        | '\u{00B7}'             // MIDDLE DOT
+        | '\u{0387}'             // GREEK ANO TELEIA
+        | '\u{055F}'             // ARMENIAN ABBREVIATION MARK
+        | '\u{05F4}'             // HEBREW PUNCTUATION GERSHAYIM
+        | '\u{2018}' | '\u{2019}' // SINGLE QUOTATION MARKS
+        | '\u{2024}'             // ONE DOT LEADER
+        | '\u{2027}'             // HYPHENATION POINT
+        | '\u{FE13}'             // PRESENTATION FORM FOR VERTICAL COLON
+        | '\u{FE52}'             // SMALL FULL STOP
+        | '\u{FE55}'             // SMALL COLON
+        | '\u{FF07}'             // FULLWIDTH APOSTROPHE
+        | '\u{FF0E}'             // FULLWIDTH FULL STOP
+        | '\u{FF1A}' // FULLWIDTH COLON
+    )
+}
+
+/// Returns the largest char boundary in `s` that is not greater than `index`.
+pub(crate) fn floor_char_boundary(s: &str, index: usize) -> usize {
+    (0..=index.min(s.len()))
+        .rev()
+        .find(|&i| s.is_char_boundary(i))
+        .unwrap_or(0)
+}
+
+/// Returns the largest boundary in `s` that is not greater than `index` and
+/// doesn't split a visible character.
+///
+/// With the `grapheme-clusters` feature this is a grapheme cluster boundary,
+/// so a flag, an emoji sequence, or a letter with combining marks is kept or
+/// dropped whole. Otherwise it is a char boundary.
+pub(crate) fn floor_grapheme_boundary(s: &str, index: usize) -> usize {
+    let index = floor_char_boundary(s, index);
+    #[cfg(feature = "grapheme-clusters")]
+    let index = floor_cluster_boundary(s, index);
+    index
+}
+
+/// Returns the largest boundary between extended grapheme clusters in `s` that
+/// is not greater than `index`, a char boundary.
+///
+/// This follows the rules of UAX #29 for line breaks, combining marks and
+/// other extending characters, emoji ZWJ sequences, flags, and Hangul
+/// syllables, but not the rare prepended characters, and it tells extending
+/// characters apart with the combining marks of `unicode-normalization` and a
+/// few ranges rather than the full grapheme break tables.
+#[cfg(feature = "grapheme-clusters")]
+fn floor_cluster_boundary(s: &str, mut index: usize) -> usize {
+    let is_regional_indicator = |c: char| matches!(c, '\u{1F1E6}'..='\u{1F1FF}');
+    loop {
+        let (before, after) = s.split_at(index);
+        let (Some(prev), Some(next)) = (before.chars().next_back(), after.chars().next()) else {
+            return index;
+        };
+
+        let is_boundary = match (prev, next) {
+            ('\r', '\n') => false,
+            (_, next) if is_extend(next) => false,
+            // The joiner of an emoji ZWJ sequence.
+            ('\u{200D}', _) => false,
+            // Flags are pairs of regional indicators, so a run of them is
+            // counted once and split after its last whole pair.
+            (prev, next) if is_regional_indicator(prev) && is_regional_indicator(next) => {
+                let count = before
+                    .chars()
+                    .rev()
+                    .take_while(|&c| is_regional_indicator(c))
+                    .count();
+                return if count.is_multiple_of(2) {
+                    index
+                } else {
+                    index - prev.len_utf8()
+                };
+            }
+            (prev, next) => !joins_hangul(prev, next),
+        };
+        if is_boundary {
+            return index;
+        }
+        index -= prev.len_utf8();
+    }
+}
+
+/// Returns `true` for characters that extend the grapheme cluster before
+/// them: combining marks, joiners, variation selectors, emoji modifiers, and
+/// tag characters.
+#[cfg(feature = "grapheme-clusters")]
+fn is_extend(c: char) -> bool {
+    unicode_normalization::char::is_combining_mark(c)
+        || matches!(
+            c,
+            '\u{200C}' | '\u{200D}'
+            | '\u{FE00}'..='\u{FE0F}'
+            | '\u{1F3FB}'..='\u{1F3FF}'
+            | '\u{E0020}'..='\u{E007F}'
+            | '\u{E0100}'..='\u{E01EF}'
+        )
+}
+
+/// Returns whether the Hangul jamo or syllables `prev` and `next` form one
+/// syllable.
+#[cfg(feature = "grapheme-clusters")]
+fn joins_hangul(prev: char, next: char) -> bool {
+    #[derive(PartialEq)]
+    enum Jamo {
+        Leading,
+        Vowel,
+        Trailing,
+        Lv,
+        Lvt,
+    }
+
+    let jamo = |c: char| match c {
+        '\u{1100}'..='\u{115F}' | '\u{A960}'..='\u{A97C}' => Some(Jamo::Leading),
+        '\u{1160}'..='\u{11A7}' | '\u{D7B0}'..='\u{D7C6}' => Some(Jamo::Vowel),
+        '\u{11A8}'..='\u{11FF}' | '\u{D7CB}'..='\u{D7FB}' => Some(Jamo::Trailing),
+        '\u{AC00}'..='\u{D7A3}' if (c as u32 - 0xAC00).is_multiple_of(28) => Some(Jamo::Lv),
+        '\u{AC00}'..='\u{D7A3}' => Some(Jamo::Lvt),
+        _ => None,
+    };
+
+    match (jamo(prev), jamo(next)) {
+        (Some(Jamo::Leading), Some(next)) => next != Jamo::Trailing,
+        (Some(Jamo::Vowel | Jamo::Lv), Some(next)) => matches!(next, Jamo::Vowel | Jamo::Trailing),
+        (Some(Jamo::Trailing | Jamo::Lvt), Some(next)) => next == Jamo::Trailing,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_floor_char_boundary() {
+        assert_eq!(floor_char_boundary("héllo", 2), 1);
+        assert_eq!(floor_char_boundary("héllo", 3), 3);
+        assert_eq!(floor_char_boundary("abc", 10), 3);
+    }
+
+    #[test]
+    #[cfg(feature = "grapheme-clusters")]
+    fn test_floor_grapheme_boundary() {
+        // "e" and a combining acute accent.
+        assert_eq!(floor_grapheme_boundary("ae\u{301}b", 3), 1);
+        // Two flags, each a pair of regional indicators.
+        let flags = "\u{1F1EB}\u{1F1F7}\u{1F1E9}\u{1F1EA}";
+        assert_eq!(floor_grapheme_boundary(flags, 12), 8);
+        assert_eq!(floor_grapheme_boundary(flags, 7), 0);
+        // A family emoji joined with ZWJs, and a thumbs up with a skin tone.
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}!";
+        assert_eq!(floor_grapheme_boundary(family, 17), 0);
+        assert_eq!(floor_grapheme_boundary(family, 18), 18);
+        assert_eq!(floor_grapheme_boundary("\u{1F44D}\u{1F3FD}", 4), 0);
+        // A syllable spelled with conjoining jamo.
+        assert_eq!(floor_grapheme_boundary("\u{1100}\u{1161}\u{11A8}x", 6), 0);
+        assert_eq!(floor_grapheme_boundary("\r\nx", 1), 0);
+        // A long run of flags is split after its last whole flag.
+        let flags = flags.repeat(50_000);
+        assert_eq!(
+            floor_grapheme_boundary(&flags, flags.len() - 1),
+            flags.len() - 8
+        );
+        assert_eq!(
+            floor_grapheme_boundary(&flags, flags.len() - 5),
+            flags.len() - 8
+        );
+    }
+}
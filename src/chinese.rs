@@ -0,0 +1,1402 @@
+//! Conversion between Traditional and Simplified Chinese characters, so that
+//! titles from sources using either script give the same slug.
+//!
+//! The tables convert character by character, like the character tables of
+//! OpenCC without its phrase tables, and cover the characters common in
+//! titles rather than every character.
+
+/// The Chinese script that Chinese characters are converted to with
+/// [`Slugifier::chinese_script`](crate::Slugifier::chinese_script).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChineseScript {
+    /// Simplified Chinese, as used in mainland China and Singapore.
+    Simplified,
+    /// Traditional Chinese, as used in Taiwan, Hong Kong, and Macau.
+    Traditional,
+}
+
+/// Returns `c` in `script`, or `c` itself if it has no other form.
+pub(crate) fn convert(c: char, script: Option<ChineseScript>) -> char {
+    let table = match script {
+        Some(ChineseScript::Simplified) => TO_SIMPLIFIED,
+        Some(ChineseScript::Traditional) => TO_TRADITIONAL,
+        None => return c,
+    };
+    match table.binary_search_by_key(&c, |&(from, _)| from) {
+        Ok(i) => table[i].1,
+        Err(_) => c,
+    }
+}
+
+/// Traditional characters and their simplified forms, sorted.
+const TO_SIMPLIFIED: &[(char, char)] = &[
+    ('乾', '干'),
+    ('來', '来'),
+    ('個', '个'),
+    ('們', '们'),
+    ('備', '备'),
+    ('傳', '传'),
+    ('價', '价'),
+    ('優', '优'),
+    ('動', '动'),
+    ('區', '区'),
+    ('員', '员'),
+    ('問', '问'),
+    ('單', '单'),
+    ('國', '国'),
+    ('園', '园'),
+    ('圓', '圆'),
+    ('圖', '图'),
+    ('團', '团'),
+    ('報', '报'),
+    ('場', '场'),
+    ('塊', '块'),
+    ('壇', '坛'),
+    ('壓', '压'),
+    ('壞', '坏'),
+    ('夢', '梦'),
+    ('學', '学'),
+    ('實', '实'),
+    ('寧', '宁'),
+    ('寫', '写'),
+    ('寶', '宝'),
+    ('將', '将'),
+    ('專', '专'),
+    ('尋', '寻'),
+    ('對', '对'),
+    ('導', '导'),
+    ('層', '层'),
+    ('屬', '属'),
+    ('島', '岛'),
+    ('師', '师'),
+    ('帶', '带'),
+    ('幣', '币'),
+    ('幫', '帮'),
+    ('幹', '干'),
+    ('幾', '几'),
+    ('庫', '库'),
+    ('廠', '厂'),
+    ('廢', '废'),
+    ('廣', '广'),
+    ('廳', '厅'),
+    ('張', '张'),
+    ('強', '强'),
+    ('彈', '弹'),
+    ('後', '后'),
+    ('從', '从'),
+    ('復', '复'),
+    ('徵', '征'),
+    ('愛', '爱'),
+    ('態', '态'),
+    ('憶', '忆'),
+    ('應', '应'),
+    ('懷', '怀'),
+    ('戰', '战'),
+    ('戲', '戏'),
+    ('戶', '户'),
+    ('捨', '舍'),
+    ('換', '换'),
+    ('損', '损'),
+    ('搖', '摇'),
+    ('撥', '拨'),
+    ('擁', '拥'),
+    ('擇', '择'),
+    ('擊', '击'),
+    ('擔', '担'),
+    ('據', '据'),
+    ('擬', '拟'),
+    ('擴', '扩'),
+    ('斷', '断'),
+    ('於', '于'),
+    ('時', '时'),
+    ('晉', '晋'),
+    ('曆', '历'),
+    ('書', '书'),
+    ('會', '会'),
+    ('東', '东'),
+    ('條', '条'),
+    ('業', '业'),
+    ('極', '极'),
+    ('構', '构'),
+    ('樂', '乐'),
+    ('樓', '楼'),
+    ('標', '标'),
+    ('樣', '样'),
+    ('樸', '朴'),
+    ('樹', '树'),
+    ('橋', '桥'),
+    ('機', '机'),
+    ('檔', '档'),
+    ('檢', '检'),
+    ('櫃', '柜'),
+    ('欄', '栏'),
+    ('權', '权'),
+    ('歐', '欧'),
+    ('歡', '欢'),
+    ('歲', '岁'),
+    ('歷', '历'),
+    ('歸', '归'),
+    ('殘', '残'),
+    ('殺', '杀'),
+    ('毀', '毁'),
+    ('氣', '气'),
+    ('氫', '氢'),
+    ('決', '决'),
+    ('沒', '没'),
+    ('況', '况'),
+    ('測', '测'),
+    ('湯', '汤'),
+    ('準', '准'),
+    ('溝', '沟'),
+    ('溫', '温'),
+    ('滅', '灭'),
+    ('滬', '沪'),
+    ('滾', '滚'),
+    ('滿', '满'),
+    ('漁', '渔'),
+    ('漢', '汉'),
+    ('漲', '涨'),
+    ('漿', '浆'),
+    ('潔', '洁'),
+    ('潛', '潜'),
+    ('澤', '泽'),
+    ('濕', '湿'),
+    ('濟', '济'),
+    ('瀏', '浏'),
+    ('灑', '洒'),
+    ('灣', '湾'),
+    ('災', '灾'),
+    ('為', '为'),
+    ('烏', '乌'),
+    ('無', '无'),
+    ('煙', '烟'),
+    ('熱', '热'),
+    ('燈', '灯'),
+    ('燒', '烧'),
+    ('營', '营'),
+    ('燦', '灿'),
+    ('爐', '炉'),
+    ('爛', '烂'),
+    ('爭', '争'),
+    ('爺', '爷'),
+    ('爾', '尔'),
+    ('牆', '墙'),
+    ('牠', '它'),
+    ('牽', '牵'),
+    ('犧', '牺'),
+    ('狀', '状'),
+    ('猶', '犹'),
+    ('獅', '狮'),
+    ('獎', '奖'),
+    ('獨', '独'),
+    ('獲', '获'),
+    ('獻', '献'),
+    ('玀', '猡'),
+    ('現', '现'),
+    ('瑪', '玛'),
+    ('環', '环'),
+    ('瓊', '琼'),
+    ('產', '产'),
+    ('畝', '亩'),
+    ('畢', '毕'),
+    ('畫', '画'),
+    ('異', '异'),
+    ('當', '当'),
+    ('瘋', '疯'),
+    ('療', '疗'),
+    ('癡', '痴'),
+    ('發', '发'),
+    ('皺', '皱'),
+    ('盜', '盗'),
+    ('盞', '盏'),
+    ('盡', '尽'),
+    ('監', '监'),
+    ('盤', '盘'),
+    ('眾', '众'),
+    ('睏', '困'),
+    ('矯', '矫'),
+    ('碩', '硕'),
+    ('確', '确'),
+    ('碼', '码'),
+    ('礎', '础'),
+    ('礦', '矿'),
+    ('祕', '秘'),
+    ('禍', '祸'),
+    ('禪', '禅'),
+    ('禮', '礼'),
+    ('禱', '祷'),
+    ('稅', '税'),
+    ('種', '种'),
+    ('稱', '称'),
+    ('穀', '谷'),
+    ('穌', '稣'),
+    ('積', '积'),
+    ('穩', '稳'),
+    ('窩', '窝'),
+    ('窮', '穷'),
+    ('窺', '窥'),
+    ('竄', '窜'),
+    ('竊', '窃'),
+    ('競', '竞'),
+    ('筆', '笔'),
+    ('筍', '笋'),
+    ('箏', '筝'),
+    ('節', '节'),
+    ('範', '范'),
+    ('築', '筑'),
+    ('簡', '简'),
+    ('籃', '篮'),
+    ('籌', '筹'),
+    ('籤', '签'),
+    ('糧', '粮'),
+    ('糾', '纠'),
+    ('紀', '纪'),
+    ('約', '约'),
+    ('紅', '红'),
+    ('紋', '纹'),
+    ('納', '纳'),
+    ('純', '纯'),
+    ('紗', '纱'),
+    ('紙', '纸'),
+    ('級', '级'),
+    ('紛', '纷'),
+    ('紡', '纺'),
+    ('細', '细'),
+    ('終', '终'),
+    ('組', '组'),
+    ('結', '结'),
+    ('絕', '绝'),
+    ('絞', '绞'),
+    ('絡', '络'),
+    ('給', '给'),
+    ('絨', '绒'),
+    ('統', '统'),
+    ('絲', '丝'),
+    ('綁', '绑'),
+    ('經', '经'),
+    ('綠', '绿'),
+    ('維', '维'),
+    ('綱', '纲'),
+    ('網', '网'),
+    ('綿', '绵'),
+    ('緊', '紧'),
+    ('線', '线'),
+    ('緣', '缘'),
+    ('編', '编'),
+    ('緩', '缓'),
+    ('練', '练'),
+    ('縣', '县'),
+    ('縫', '缝'),
+    ('縮', '缩'),
+    ('總', '总'),
+    ('織', '织'),
+    ('繩', '绳'),
+    ('繪', '绘'),
+    ('繼', '继'),
+    ('續', '续'),
+    ('纖', '纤'),
+    ('缽', '钵'),
+    ('罈', '坛'),
+    ('罰', '罚'),
+    ('罷', '罢'),
+    ('羅', '罗'),
+    ('義', '义'),
+    ('習', '习'),
+    ('聖', '圣'),
+    ('聞', '闻'),
+    ('聯', '联'),
+    ('聰', '聪'),
+    ('聲', '声'),
+    ('聳', '耸'),
+    ('職', '职'),
+    ('聽', '听'),
+    ('肅', '肃'),
+    ('脅', '胁'),
+    ('脫', '脱'),
+    ('脹', '胀'),
+    ('腦', '脑'),
+    ('腳', '脚'),
+    ('膚', '肤'),
+    ('膽', '胆'),
+    ('臉', '脸'),
+    ('臟', '脏'),
+    ('臨', '临'),
+    ('臺', '台'),
+    ('與', '与'),
+    ('興', '兴'),
+    ('舉', '举'),
+    ('舊', '旧'),
+    ('艙', '舱'),
+    ('艱', '艰'),
+    ('莊', '庄'),
+    ('華', '华'),
+    ('萬', '万'),
+    ('葉', '叶'),
+    ('蒼', '苍'),
+    ('蓋', '盖'),
+    ('蓮', '莲'),
+    ('蔣', '蒋'),
+    ('蔥', '葱'),
+    ('蕭', '萧'),
+    ('薦', '荐'),
+    ('薩', '萨'),
+    ('藍', '蓝'),
+    ('藝', '艺'),
+    ('藥', '药'),
+    ('蘆', '芦'),
+    ('蘇', '苏'),
+    ('蘋', '苹'),
+    ('蘭', '兰'),
+    ('蘿', '萝'),
+    ('處', '处'),
+    ('虛', '虚'),
+    ('號', '号'),
+    ('蝦', '虾'),
+    ('螢', '萤'),
+    ('蟲', '虫'),
+    ('蠟', '蜡'),
+    ('蠶', '蚕'),
+    ('術', '术'),
+    ('衛', '卫'),
+    ('衝', '冲'),
+    ('裏', '里'),
+    ('補', '补'),
+    ('裝', '装'),
+    ('裡', '里'),
+    ('製', '制'),
+    ('複', '复'),
+    ('褲', '裤'),
+    ('襪', '袜'),
+    ('見', '见'),
+    ('規', '规'),
+    ('覓', '觅'),
+    ('視', '视'),
+    ('親', '亲'),
+    ('覺', '觉'),
+    ('覽', '览'),
+    ('觀', '观'),
+    ('觸', '触'),
+    ('訂', '订'),
+    ('計', '计'),
+    ('訊', '讯'),
+    ('訓', '训'),
+    ('託', '托'),
+    ('記', '记'),
+    ('訪', '访'),
+    ('設', '设'),
+    ('許', '许'),
+    ('訴', '诉'),
+    ('診', '诊'),
+    ('詐', '诈'),
+    ('評', '评'),
+    ('詞', '词'),
+    ('詠', '咏'),
+    ('詢', '询'),
+    ('試', '试'),
+    ('詩', '诗'),
+    ('話', '话'),
+    ('該', '该'),
+    ('詳', '详'),
+    ('誇', '夸'),
+    ('誌', '志'),
+    ('認', '认'),
+    ('誕', '诞'),
+    ('誘', '诱'),
+    ('語', '语'),
+    ('誠', '诚'),
+    ('誤', '误'),
+    ('說', '说'),
+    ('誰', '谁'),
+    ('課', '课'),
+    ('誼', '谊'),
+    ('調', '调'),
+    ('談', '谈'),
+    ('請', '请'),
+    ('論', '论'),
+    ('諧', '谐'),
+    ('諸', '诸'),
+    ('諾', '诺'),
+    ('謀', '谋'),
+    ('謂', '谓'),
+    ('謎', '谜'),
+    ('謙', '谦'),
+    ('講', '讲'),
+    ('謝', '谢'),
+    ('謠', '谣'),
+    ('證', '证'),
+    ('識', '识'),
+    ('譜', '谱'),
+    ('譯', '译'),
+    ('議', '议'),
+    ('護', '护'),
+    ('譽', '誉'),
+    ('讀', '读'),
+    ('變', '变'),
+    ('讓', '让'),
+    ('讚', '赞'),
+    ('豎', '竖'),
+    ('豐', '丰'),
+    ('豬', '猪'),
+    ('貓', '猫'),
+    ('貝', '贝'),
+    ('貞', '贞'),
+    ('負', '负'),
+    ('財', '财'),
+    ('貢', '贡'),
+    ('貧', '贫'),
+    ('貨', '货'),
+    ('販', '贩'),
+    ('貫', '贯'),
+    ('貴', '贵'),
+    ('買', '买'),
+    ('費', '费'),
+    ('貼', '贴'),
+    ('貿', '贸'),
+    ('賀', '贺'),
+    ('資', '资'),
+    ('賊', '贼'),
+    ('賓', '宾'),
+    ('賜', '赐'),
+    ('賞', '赏'),
+    ('賠', '赔'),
+    ('賢', '贤'),
+    ('賣', '卖'),
+    ('賦', '赋'),
+    ('質', '质'),
+    ('賬', '账'),
+    ('賭', '赌'),
+    ('賴', '赖'),
+    ('賺', '赚'),
+    ('購', '购'),
+    ('賽', '赛'),
+    ('贈', '赠'),
+    ('贊', '赞'),
+    ('贏', '赢'),
+    ('趕', '赶'),
+    ('趙', '赵'),
+    ('趨', '趋'),
+    ('跡', '迹'),
+    ('踐', '践'),
+    ('蹤', '踪'),
+    ('躍', '跃'),
+    ('車', '车'),
+    ('軌', '轨'),
+    ('軍', '军'),
+    ('軒', '轩'),
+    ('軟', '软'),
+    ('軸', '轴'),
+    ('較', '较'),
+    ('載', '载'),
+    ('輔', '辅'),
+    ('輕', '轻'),
+    ('輛', '辆'),
+    ('輝', '辉'),
+    ('輩', '辈'),
+    ('輪', '轮'),
+    ('輯', '辑'),
+    ('輸', '输'),
+    ('轉', '转'),
+    ('轎', '轿'),
+    ('辦', '办'),
+    ('辭', '辞'),
+    ('辯', '辩'),
+    ('農', '农'),
+    ('迴', '回'),
+    ('這', '这'),
+    ('連', '连'),
+    ('週', '周'),
+    ('進', '进'),
+    ('遊', '游'),
+    ('運', '运'),
+    ('過', '过'),
+    ('達', '达'),
+    ('遙', '遥'),
+    ('遜', '逊'),
+    ('遞', '递'),
+    ('遠', '远'),
+    ('適', '适'),
+    ('遲', '迟'),
+    ('遷', '迁'),
+    ('選', '选'),
+    ('遺', '遗'),
+    ('邁', '迈'),
+    ('還', '还'),
+    ('邊', '边'),
+    ('邏', '逻'),
+    ('郵', '邮'),
+    ('鄉', '乡'),
+    ('鄭', '郑'),
+    ('鄰', '邻'),
+    ('醜', '丑'),
+    ('醞', '酝'),
+    ('醫', '医'),
+    ('醬', '酱'),
+    ('釀', '酿'),
+    ('釋', '释'),
+    ('針', '针'),
+    ('釣', '钓'),
+    ('鈔', '钞'),
+    ('鈴', '铃'),
+    ('鉛', '铅'),
+    ('銀', '银'),
+    ('銅', '铜'),
+    ('銘', '铭'),
+    ('銳', '锐'),
+    ('銷', '销'),
+    ('鋒', '锋'),
+    ('鋪', '铺'),
+    ('鋼', '钢'),
+    ('錄', '录'),
+    ('錢', '钱'),
+    ('錦', '锦'),
+    ('錯', '错'),
+    ('鍋', '锅'),
+    ('鍛', '锻'),
+    ('鍵', '键'),
+    ('鍾', '钟'),
+    ('鎖', '锁'),
+    ('鎮', '镇'),
+    ('鏈', '链'),
+    ('鏡', '镜'),
+    ('鐘', '钟'),
+    ('鐵', '铁'),
+    ('鑄', '铸'),
+    ('鑰', '钥'),
+    ('長', '长'),
+    ('門', '门'),
+    ('閃', '闪'),
+    ('閉', '闭'),
+    ('開', '开'),
+    ('閒', '闲'),
+    ('間', '间'),
+    ('閘', '闸'),
+    ('閣', '阁'),
+    ('閩', '闽'),
+    ('閱', '阅'),
+    ('闆', '板'),
+    ('闊', '阔'),
+    ('闖', '闯'),
+    ('關', '关'),
+    ('闡', '阐'),
+    ('陝', '陕'),
+    ('陣', '阵'),
+    ('陰', '阴'),
+    ('陳', '陈'),
+    ('陸', '陆'),
+    ('陽', '阳'),
+    ('隊', '队'),
+    ('階', '阶'),
+    ('隕', '陨'),
+    ('際', '际'),
+    ('隨', '随'),
+    ('險', '险'),
+    ('隱', '隐'),
+    ('隸', '隶'),
+    ('隻', '只'),
+    ('雖', '虽'),
+    ('雙', '双'),
+    ('雛', '雏'),
+    ('雜', '杂'),
+    ('雞', '鸡'),
+    ('離', '离'),
+    ('難', '难'),
+    ('雲', '云'),
+    ('電', '电'),
+    ('霧', '雾'),
+    ('靂', '雳'),
+    ('靈', '灵'),
+    ('靜', '静'),
+    ('韋', '韦'),
+    ('韓', '韩'),
+    ('韻', '韵'),
+    ('響', '响'),
+    ('頁', '页'),
+    ('頂', '顶'),
+    ('項', '项'),
+    ('順', '顺'),
+    ('須', '须'),
+    ('頌', '颂'),
+    ('預', '预'),
+    ('頒', '颁'),
+    ('頓', '顿'),
+    ('頗', '颇'),
+    ('領', '领'),
+    ('頭', '头'),
+    ('頸', '颈'),
+    ('頻', '频'),
+    ('顆', '颗'),
+    ('題', '题'),
+    ('額', '额'),
+    ('顏', '颜'),
+    ('願', '愿'),
+    ('顛', '颠'),
+    ('類', '类'),
+    ('顧', '顾'),
+    ('顯', '显'),
+    ('風', '风'),
+    ('颱', '台'),
+    ('颶', '飓'),
+    ('飄', '飘'),
+    ('飛', '飞'),
+    ('飯', '饭'),
+    ('飲', '饮'),
+    ('飼', '饲'),
+    ('飽', '饱'),
+    ('餃', '饺'),
+    ('餅', '饼'),
+    ('養', '养'),
+    ('餓', '饿'),
+    ('餘', '余'),
+    ('館', '馆'),
+    ('饑', '饥'),
+    ('饒', '饶'),
+    ('馬', '马'),
+    ('馮', '冯'),
+    ('馳', '驰'),
+    ('駐', '驻'),
+    ('駛', '驶'),
+    ('騎', '骑'),
+    ('驅', '驱'),
+    ('驕', '骄'),
+    ('驗', '验'),
+    ('驚', '惊'),
+    ('驟', '骤'),
+    ('驢', '驴'),
+    ('骯', '肮'),
+    ('體', '体'),
+    ('髮', '发'),
+    ('鬆', '松'),
+    ('鬍', '胡'),
+    ('鬚', '须'),
+    ('鬥', '斗'),
+    ('鬧', '闹'),
+    ('鬱', '郁'),
+    ('魚', '鱼'),
+    ('魯', '鲁'),
+    ('鮮', '鲜'),
+    ('鯉', '鲤'),
+    ('鯨', '鲸'),
+    ('鰻', '鳗'),
+    ('鳥', '鸟'),
+    ('鳳', '凤'),
+    ('鳴', '鸣'),
+    ('鴨', '鸭'),
+    ('鴻', '鸿'),
+    ('鵝', '鹅'),
+    ('鵬', '鹏'),
+    ('鶴', '鹤'),
+    ('鷗', '鸥'),
+    ('鷹', '鹰'),
+    ('鹽', '盐'),
+    ('麗', '丽'),
+    ('麥', '麦'),
+    ('麵', '面'),
+    ('麼', '么'),
+    ('黃', '黄'),
+    ('點', '点'),
+    ('黨', '党'),
+    ('黴', '霉'),
+    ('鼴', '鼹'),
+    ('齊', '齐'),
+    ('齋', '斋'),
+    ('齒', '齿'),
+    ('齡', '龄'),
+    ('齣', '出'),
+    ('龍', '龙'),
+    ('龐', '庞'),
+    ('龕', '龛'),
+    ('龜', '龟'),
+];
+
+/// Simplified characters and their most common traditional forms, sorted.
+///
+/// Simplified characters that are also traditional characters in their own
+/// right, like 面 (face) for 麵 (noodles) or 干 (shield) for 幹 and 乾, are
+/// left alone, since which one is meant depends on the word.
+const TO_TRADITIONAL: &[(char, char)] = &[
+    ('万', '萬'),
+    ('与', '與'),
+    ('专', '專'),
+    ('业', '業'),
+    ('东', '東'),
+    ('丝', '絲'),
+    ('个', '個'),
+    ('丰', '豐'),
+    ('临', '臨'),
+    ('为', '為'),
+    ('丽', '麗'),
+    ('举', '舉'),
+    ('么', '麼'),
+    ('义', '義'),
+    ('乌', '烏'),
+    ('乐', '樂'),
+    ('习', '習'),
+    ('乡', '鄉'),
+    ('书', '書'),
+    ('买', '買'),
+    ('争', '爭'),
+    ('云', '雲'),
+    ('产', '產'),
+    ('亩', '畝'),
+    ('亲', '親'),
+    ('从', '從'),
+    ('们', '們'),
+    ('价', '價'),
+    ('众', '眾'),
+    ('优', '優'),
+    ('会', '會'),
+    ('传', '傳'),
+    ('体', '體'),
+    ('党', '黨'),
+    ('兰', '蘭'),
+    ('关', '關'),
+    ('兴', '興'),
+    ('养', '養'),
+    ('写', '寫'),
+    ('军', '軍'),
+    ('农', '農'),
+    ('冯', '馮'),
+    ('决', '決'),
+    ('况', '況'),
+    ('凤', '鳳'),
+    ('击', '擊'),
+    ('办', '辦'),
+    ('动', '動'),
+    ('区', '區'),
+    ('医', '醫'),
+    ('华', '華'),
+    ('单', '單'),
+    ('卖', '賣'),
+    ('卫', '衛'),
+    ('厂', '廠'),
+    ('厅', '廳'),
+    ('历', '歷'),
+    ('压', '壓'),
+    ('县', '縣'),
+    ('双', '雙'),
+    ('发', '發'),
+    ('变', '變'),
+    ('叶', '葉'),
+    ('号', '號'),
+    ('听', '聽'),
+    ('员', '員'),
+    ('周', '週'),
+    ('咏', '詠'),
+    ('响', '響'),
+    ('团', '團'),
+    ('园', '園'),
+    ('国', '國'),
+    ('图', '圖'),
+    ('圆', '圓'),
+    ('圣', '聖'),
+    ('场', '場'),
+    ('坏', '壞'),
+    ('块', '塊'),
+    ('坛', '壇'),
+    ('墙', '牆'),
+    ('声', '聲'),
+    ('处', '處'),
+    ('备', '備'),
+    ('复', '復'),
+    ('头', '頭'),
+    ('夸', '誇'),
+    ('奖', '獎'),
+    ('学', '學'),
+    ('宁', '寧'),
+    ('宝', '寶'),
+    ('实', '實'),
+    ('宾', '賓'),
+    ('对', '對'),
+    ('寻', '尋'),
+    ('导', '導'),
+    ('将', '將'),
+    ('尔', '爾'),
+    ('尽', '盡'),
+    ('层', '層'),
+    ('属', '屬'),
+    ('岁', '歲'),
+    ('岛', '島'),
+    ('币', '幣'),
+    ('师', '師'),
+    ('带', '帶'),
+    ('帮', '幫'),
+    ('广', '廣'),
+    ('庄', '莊'),
+    ('库', '庫'),
+    ('应', '應'),
+    ('庞', '龐'),
+    ('废', '廢'),
+    ('开', '開'),
+    ('异', '異'),
+    ('张', '張'),
+    ('弹', '彈'),
+    ('强', '強'),
+    ('归', '歸'),
+    ('当', '當'),
+    ('录', '錄'),
+    ('忆', '憶'),
+    ('怀', '懷'),
+    ('态', '態'),
+    ('总', '總'),
+    ('惊', '驚'),
+    ('愿', '願'),
+    ('戏', '戲'),
+    ('战', '戰'),
+    ('户', '戶'),
+    ('扩', '擴'),
+    ('护', '護'),
+    ('报', '報'),
+    ('担', '擔'),
+    ('拟', '擬'),
+    ('拥', '擁'),
+    ('拨', '撥'),
+    ('择', '擇'),
+    ('损', '損'),
+    ('换', '換'),
+    ('据', '據'),
+    ('摇', '搖'),
+    ('斋', '齋'),
+    ('断', '斷'),
+    ('无', '無'),
+    ('旧', '舊'),
+    ('时', '時'),
+    ('显', '顯'),
+    ('晋', '晉'),
+    ('术', '術'),
+    ('朴', '樸'),
+    ('机', '機'),
+    ('杀', '殺'),
+    ('杂', '雜'),
+    ('权', '權'),
+    ('条', '條'),
+    ('来', '來'),
+    ('极', '極'),
+    ('构', '構'),
+    ('柜', '櫃'),
+    ('标', '標'),
+    ('栏', '欄'),
+    ('树', '樹'),
+    ('样', '樣'),
+    ('档', '檔'),
+    ('桥', '橋'),
+    ('梦', '夢'),
+    ('检', '檢'),
+    ('楼', '樓'),
+    ('欢', '歡'),
+    ('欧', '歐'),
+    ('残', '殘'),
+    ('毁', '毀'),
+    ('毕', '畢'),
+    ('气', '氣'),
+    ('氢', '氫'),
+    ('汉', '漢'),
+    ('汤', '湯'),
+    ('沟', '溝'),
+    ('没', '沒'),
+    ('沪', '滬'),
+    ('泽', '澤'),
+    ('洁', '潔'),
+    ('洒', '灑'),
+    ('浆', '漿'),
+    ('测', '測'),
+    ('济', '濟'),
+    ('浏', '瀏'),
+    ('涨', '漲'),
+    ('渔', '漁'),
+    ('温', '溫'),
+    ('游', '遊'),
+    ('湾', '灣'),
+    ('湿', '濕'),
+    ('滚', '滾'),
+    ('满', '滿'),
+    ('潜', '潛'),
+    ('灭', '滅'),
+    ('灯', '燈'),
+    ('灵', '靈'),
+    ('灾', '災'),
+    ('灿', '燦'),
+    ('炉', '爐'),
+    ('点', '點'),
+    ('烂', '爛'),
+    ('烟', '煙'),
+    ('烧', '燒'),
+    ('热', '熱'),
+    ('爱', '愛'),
+    ('爷', '爺'),
+    ('牵', '牽'),
+    ('牺', '犧'),
+    ('状', '狀'),
+    ('犹', '猶'),
+    ('独', '獨'),
+    ('狮', '獅'),
+    ('猡', '玀'),
+    ('猪', '豬'),
+    ('猫', '貓'),
+    ('献', '獻'),
+    ('玛', '瑪'),
+    ('环', '環'),
+    ('现', '現'),
+    ('琼', '瓊'),
+    ('电', '電'),
+    ('画', '畫'),
+    ('疗', '療'),
+    ('疯', '瘋'),
+    ('痴', '癡'),
+    ('皱', '皺'),
+    ('盏', '盞'),
+    ('盐', '鹽'),
+    ('监', '監'),
+    ('盖', '蓋'),
+    ('盗', '盜'),
+    ('盘', '盤'),
+    ('矫', '矯'),
+    ('矿', '礦'),
+    ('码', '碼'),
+    ('础', '礎'),
+    ('硕', '碩'),
+    ('确', '確'),
+    ('礼', '禮'),
+    ('祷', '禱'),
+    ('祸', '禍'),
+    ('禅', '禪'),
+    ('离', '離'),
+    ('种', '種'),
+    ('秘', '祕'),
+    ('积', '積'),
+    ('称', '稱'),
+    ('税', '稅'),
+    ('稣', '穌'),
+    ('稳', '穩'),
+    ('穷', '窮'),
+    ('窃', '竊'),
+    ('窜', '竄'),
+    ('窝', '窩'),
+    ('窥', '窺'),
+    ('竖', '豎'),
+    ('竞', '競'),
+    ('笋', '筍'),
+    ('笔', '筆'),
+    ('筑', '築'),
+    ('筝', '箏'),
+    ('筹', '籌'),
+    ('签', '籤'),
+    ('简', '簡'),
+    ('篮', '籃'),
+    ('类', '類'),
+    ('粮', '糧'),
+    ('紧', '緊'),
+    ('纠', '糾'),
+    ('红', '紅'),
+    ('纤', '纖'),
+    ('约', '約'),
+    ('级', '級'),
+    ('纪', '紀'),
+    ('纯', '純'),
+    ('纱', '紗'),
+    ('纲', '綱'),
+    ('纳', '納'),
+    ('纷', '紛'),
+    ('纸', '紙'),
+    ('纹', '紋'),
+    ('纺', '紡'),
+    ('线', '線'),
+    ('练', '練'),
+    ('组', '組'),
+    ('细', '細'),
+    ('织', '織'),
+    ('终', '終'),
+    ('经', '經'),
+    ('绑', '綁'),
+    ('绒', '絨'),
+    ('结', '結'),
+    ('绘', '繪'),
+    ('给', '給'),
+    ('络', '絡'),
+    ('绝', '絕'),
+    ('绞', '絞'),
+    ('统', '統'),
+    ('继', '繼'),
+    ('续', '續'),
+    ('绳', '繩'),
+    ('维', '維'),
+    ('绵', '綿'),
+    ('绿', '綠'),
+    ('缓', '緩'),
+    ('编', '編'),
+    ('缘', '緣'),
+    ('缝', '縫'),
+    ('缩', '縮'),
+    ('网', '網'),
+    ('罗', '羅'),
+    ('罚', '罰'),
+    ('罢', '罷'),
+    ('耸', '聳'),
+    ('职', '職'),
+    ('联', '聯'),
+    ('聪', '聰'),
+    ('肃', '肅'),
+    ('肤', '膚'),
+    ('肮', '骯'),
+    ('胀', '脹'),
+    ('胁', '脅'),
+    ('胆', '膽'),
+    ('脏', '臟'),
+    ('脑', '腦'),
+    ('脚', '腳'),
+    ('脱', '脫'),
+    ('脸', '臉'),
+    ('舱', '艙'),
+    ('艰', '艱'),
+    ('艺', '藝'),
+    ('节', '節'),
+    ('芦', '蘆'),
+    ('苍', '蒼'),
+    ('苏', '蘇'),
+    ('苹', '蘋'),
+    ('荐', '薦'),
+    ('药', '藥'),
+    ('莲', '蓮'),
+    ('获', '獲'),
+    ('萝', '蘿'),
+    ('萤', '螢'),
+    ('营', '營'),
+    ('萧', '蕭'),
+    ('萨', '薩'),
+    ('葱', '蔥'),
+    ('蒋', '蔣'),
+    ('蓝', '藍'),
+    ('虚', '虛'),
+    ('虫', '蟲'),
+    ('虽', '雖'),
+    ('虾', '蝦'),
+    ('蚕', '蠶'),
+    ('蜡', '蠟'),
+    ('补', '補'),
+    ('袜', '襪'),
+    ('装', '裝'),
+    ('裤', '褲'),
+    ('见', '見'),
+    ('观', '觀'),
+    ('规', '規'),
+    ('觅', '覓'),
+    ('视', '視'),
+    ('览', '覽'),
+    ('觉', '覺'),
+    ('触', '觸'),
+    ('誉', '譽'),
+    ('计', '計'),
+    ('订', '訂'),
+    ('认', '認'),
+    ('让', '讓'),
+    ('训', '訓'),
+    ('议', '議'),
+    ('讯', '訊'),
+    ('记', '記'),
+    ('讲', '講'),
+    ('许', '許'),
+    ('论', '論'),
+    ('设', '設'),
+    ('访', '訪'),
+    ('证', '證'),
+    ('评', '評'),
+    ('识', '識'),
+    ('诈', '詐'),
+    ('诉', '訴'),
+    ('诊', '診'),
+    ('词', '詞'),
+    ('译', '譯'),
+    ('试', '試'),
+    ('诗', '詩'),
+    ('诚', '誠'),
+    ('话', '話'),
+    ('诞', '誕'),
+    ('询', '詢'),
+    ('该', '該'),
+    ('详', '詳'),
+    ('语', '語'),
+    ('误', '誤'),
+    ('诱', '誘'),
+    ('说', '說'),
+    ('请', '請'),
+    ('诸', '諸'),
+    ('诺', '諾'),
+    ('读', '讀'),
+    ('课', '課'),
+    ('谁', '誰'),
+    ('调', '調'),
+    ('谈', '談'),
+    ('谊', '誼'),
+    ('谋', '謀'),
+    ('谐', '諧'),
+    ('谓', '謂'),
+    ('谜', '謎'),
+    ('谢', '謝'),
+    ('谣', '謠'),
+    ('谦', '謙'),
+    ('谱', '譜'),
+    ('贝', '貝'),
+    ('贞', '貞'),
+    ('负', '負'),
+    ('贡', '貢'),
+    ('财', '財'),
+    ('贤', '賢'),
+    ('账', '賬'),
+    ('货', '貨'),
+    ('质', '質'),
+    ('贩', '販'),
+    ('贫', '貧'),
+    ('购', '購'),
+    ('贯', '貫'),
+    ('贴', '貼'),
+    ('贵', '貴'),
+    ('贸', '貿'),
+    ('费', '費'),
+    ('贺', '賀'),
+    ('贼', '賊'),
+    ('资', '資'),
+    ('赋', '賦'),
+    ('赌', '賭'),
+    ('赏', '賞'),
+    ('赐', '賜'),
+    ('赔', '賠'),
+    ('赖', '賴'),
+    ('赚', '賺'),
+    ('赛', '賽'),
+    ('赞', '讚'),
+    ('赠', '贈'),
+    ('赢', '贏'),
+    ('赵', '趙'),
+    ('赶', '趕'),
+    ('趋', '趨'),
+    ('跃', '躍'),
+    ('践', '踐'),
+    ('踪', '蹤'),
+    ('车', '車'),
+    ('轨', '軌'),
+    ('轩', '軒'),
+    ('转', '轉'),
+    ('轮', '輪'),
+    ('软', '軟'),
+    ('轴', '軸'),
+    ('轻', '輕'),
+    ('载', '載'),
+    ('轿', '轎'),
+    ('较', '較'),
+    ('辅', '輔'),
+    ('辆', '輛'),
+    ('辈', '輩'),
+    ('辉', '輝'),
+    ('辑', '輯'),
+    ('输', '輸'),
+    ('辞', '辭'),
+    ('辩', '辯'),
+    ('边', '邊'),
+    ('达', '達'),
+    ('迁', '遷'),
+    ('过', '過'),
+    ('迈', '邁'),
+    ('运', '運'),
+    ('还', '還'),
+    ('这', '這'),
+    ('进', '進'),
+    ('远', '遠'),
+    ('连', '連'),
+    ('迟', '遲'),
+    ('迹', '跡'),
+    ('适', '適'),
+    ('选', '選'),
+    ('逊', '遜'),
+    ('递', '遞'),
+    ('逻', '邏'),
+    ('遗', '遺'),
+    ('遥', '遙'),
+    ('邮', '郵'),
+    ('邻', '鄰'),
+    ('郁', '鬱'),
+    ('郑', '鄭'),
+    ('酝', '醞'),
+    ('酱', '醬'),
+    ('酿', '釀'),
+    ('释', '釋'),
+    ('针', '針'),
+    ('钓', '釣'),
+    ('钞', '鈔'),
+    ('钟', '鐘'),
+    ('钢', '鋼'),
+    ('钥', '鑰'),
+    ('钱', '錢'),
+    ('钵', '缽'),
+    ('铁', '鐵'),
+    ('铃', '鈴'),
+    ('铅', '鉛'),
+    ('铜', '銅'),
+    ('铭', '銘'),
+    ('银', '銀'),
+    ('铸', '鑄'),
+    ('铺', '鋪'),
+    ('链', '鏈'),
+    ('销', '銷'),
+    ('锁', '鎖'),
+    ('锅', '鍋'),
+    ('锋', '鋒'),
+    ('锐', '銳'),
+    ('错', '錯'),
+    ('锦', '錦'),
+    ('键', '鍵'),
+    ('锻', '鍛'),
+    ('镇', '鎮'),
+    ('镜', '鏡'),
+    ('长', '長'),
+    ('门', '門'),
+    ('闪', '閃'),
+    ('闭', '閉'),
+    ('问', '問'),
+    ('闯', '闖'),
+    ('闲', '閒'),
+    ('间', '間'),
+    ('闸', '閘'),
+    ('闹', '鬧'),
+    ('闻', '聞'),
+    ('闽', '閩'),
+    ('阁', '閣'),
+    ('阅', '閱'),
+    ('阐', '闡'),
+    ('阔', '闊'),
+    ('队', '隊'),
+    ('阳', '陽'),
+    ('阴', '陰'),
+    ('阵', '陣'),
+    ('阶', '階'),
+    ('际', '際'),
+    ('陆', '陸'),
+    ('陈', '陳'),
+    ('陕', '陝'),
+    ('陨', '隕'),
+    ('险', '險'),
+    ('随', '隨'),
+    ('隐', '隱'),
+    ('隶', '隸'),
+    ('难', '難'),
+    ('雏', '雛'),
+    ('雳', '靂'),
+    ('雾', '霧'),
+    ('霉', '黴'),
+    ('静', '靜'),
+    ('韦', '韋'),
+    ('韩', '韓'),
+    ('韵', '韻'),
+    ('页', '頁'),
+    ('顶', '頂'),
+    ('项', '項'),
+    ('顺', '順'),
+    ('须', '須'),
+    ('顾', '顧'),
+    ('顿', '頓'),
+    ('颁', '頒'),
+    ('颂', '頌'),
+    ('预', '預'),
+    ('领', '領'),
+    ('颇', '頗'),
+    ('颈', '頸'),
+    ('频', '頻'),
+    ('颗', '顆'),
+    ('题', '題'),
+    ('颜', '顏'),
+    ('额', '額'),
+    ('颠', '顛'),
+    ('风', '風'),
+    ('飓', '颶'),
+    ('飘', '飄'),
+    ('飞', '飛'),
+    ('饥', '饑'),
+    ('饭', '飯'),
+    ('饮', '飲'),
+    ('饱', '飽'),
+    ('饲', '飼'),
+    ('饶', '饒'),
+    ('饺', '餃'),
+    ('饼', '餅'),
+    ('饿', '餓'),
+    ('馆', '館'),
+    ('马', '馬'),
+    ('驰', '馳'),
+    ('驱', '驅'),
+    ('驴', '驢'),
+    ('驶', '駛'),
+    ('驻', '駐'),
+    ('骄', '驕'),
+    ('验', '驗'),
+    ('骑', '騎'),
+    ('骤', '驟'),
+    ('鱼', '魚'),
+    ('鲁', '魯'),
+    ('鲜', '鮮'),
+    ('鲤', '鯉'),
+    ('鲸', '鯨'),
+    ('鳗', '鰻'),
+    ('鸟', '鳥'),
+    ('鸡', '雞'),
+    ('鸣', '鳴'),
+    ('鸥', '鷗'),
+    ('鸭', '鴨'),
+    ('鸿', '鴻'),
+    ('鹅', '鵝'),
+    ('鹏', '鵬'),
+    ('鹤', '鶴'),
+    ('鹰', '鷹'),
+    ('麦', '麥'),
+    ('黄', '黃'),
+    ('鼹', '鼴'),
+    ('齐', '齊'),
+    ('齿', '齒'),
+    ('龄', '齡'),
+    ('龙', '龍'),
+    ('龛', '龕'),
+    ('龟', '龜'),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Preset, Slugifier};
+
+    #[test]
+    fn test_tables_are_sorted() {
+        for table in [TO_SIMPLIFIED, TO_TRADITIONAL] {
+            assert!(table.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        }
+    }
+
+    #[test]
+    fn test_convert() {
+        let simplify = |text: &str| {
+            let script = Some(ChineseScript::Simplified);
+            text.chars().map(|c| convert(c, script)).collect::<String>()
+        };
+        let traditionalize = |text: &str| {
+            let script = Some(ChineseScript::Traditional);
+            text.chars().map(|c| convert(c, script)).collect::<String>()
+        };
+        assert_eq!(simplify("學習中文，從這裡開始"), "学习中文，从这里开始");
+        assert_eq!(
+            traditionalize("学习中文，从这里开始"),
+            "學習中文，從這里開始"
+        );
+        assert_eq!(traditionalize("牛肉面"), "牛肉面");
+        assert_eq!(convert('國', None), '國');
+    }
+
+    #[test]
+    fn test_slugify_converts_before_matching() {
+        let slugifier = Slugifier::new()
+            .chinese_script(Some(ChineseScript::Simplified))
+            .replace("历史", "history");
+        assert_eq!(slugifier.slugify("中國 歷史"), "zhongguo-history");
+
+        let slugifier = Slugifier::new()
+            .preset(Preset::GitHubAnchor)
+            .chinese_script(Some(ChineseScript::Traditional));
+        assert_eq!(slugifier.slugify("中国 历史"), "中國-歷史");
+    }
+}
@@ -0,0 +1,40 @@
+//! Slugs stored inline in a `CompactString`.
+
+use compact_str::CompactString;
+
+use crate::Slugifier;
+
+impl Slugifier {
+    /// Generates a slug like `slugify`, as a `CompactString`.
+    ///
+    /// Slugs of up to 24 bytes are stored inline without allocating, which
+    /// covers most slugs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// let slug = Slugifier::new().slugify_compact("Hello, World!");
+    /// assert_eq!(slug, "hello-world");
+    /// assert!(!slug.is_heap_allocated());
+    /// ```
+    pub fn slugify_compact(&self, text: &str) -> CompactString {
+        let mut slug = CompactString::default();
+        // Writing to a `CompactString` never fails.
+        let _ = self.slugify_to(text, &mut slug);
+        slug
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slugify_compact_matches_slugify() {
+        let slugifier = Slugifier::new().truncate(40);
+        for text in ["Hello, World!", "", &"A very long title ".repeat(10)] {
+            assert_eq!(slugifier.slugify_compact(text), slugifier.slugify(text));
+        }
+    }
+}
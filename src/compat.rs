@@ -0,0 +1,603 @@
+//! Reimplementations of other slug libraries' algorithms, for compatibility presets.
+
+use unicode_normalization::char::is_combining_mark;
+
+use std::borrow::Cow;
+
+use crate::{JekyllMode, Slugifier, encoding, translit};
+
+/// Removes HTML and PHP tags the way PHP's `strip_tags` does for common input.
+fn strip_tags(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let starts_tag = c == '<'
+            && chars
+                .peek()
+                .is_some_and(|&next| next.is_ascii_alphabetic() || matches!(next, '/' | '!' | '?'));
+        if starts_tag {
+            chars.by_ref().find(|&c| c == '>');
+        } else {
+            stripped.push(c);
+        }
+    }
+
+    stripped
+}
+
+/// Approximates WordPress' `remove_accents` by transliterating Latin letters.
+fn remove_accents(text: &str) -> String {
+    if text.is_ascii() {
+        return text.to_string();
+    }
+
+    let mut ascii = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            'ß' => ascii.push('s'),
+            '\u{00C0}'..='\u{024F}' | '\u{1E00}'..='\u{1EFF}' if c.is_alphabetic() => {
+                ascii.push_str(translit::transliterate_char(c));
+            }
+            '€' => {
+                ascii.push_str(translit::transliterate_char(c));
+            }
+            _ => ascii.push(c),
+        }
+    }
+    ascii
+}
+
+/// Percent-encodes non-ASCII characters with lowercase hex digits, stopping
+/// before the encoded string would exceed `max_len` bytes, like WordPress'
+/// `utf8_uri_encode`.
+fn utf8_uri_encode(text: &str, max_len: usize) -> String {
+    let mut encoded = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        let piece = if c.is_ascii() {
+            c.to_string()
+        } else {
+            encoding::percent_encode(c.encode_utf8(&mut [0; 4]), |_| false).to_ascii_lowercase()
+        };
+        if encoded.len() + piece.len() > max_len {
+            break;
+        }
+        encoded.push_str(&piece);
+    }
+
+    encoded
+}
+
+/// Percent-encoded sequences that WordPress strips from titles when saving.
+const WORDPRESS_STRIPPED: &[&str] = &[
+    // Soft hyphen, inverted exclamation and question marks.
+    "%c2%ad",
+    "%c2%a1",
+    "%c2%bf",
+    // Angle quotes.
+    "%c2%ab",
+    "%c2%bb",
+    "%e2%80%b9",
+    "%e2%80%ba",
+    // Curly quotes.
+    "%e2%80%98",
+    "%e2%80%99",
+    "%e2%80%9c",
+    "%e2%80%9d",
+    "%e2%80%9a",
+    "%e2%80%9b",
+    "%e2%80%9e",
+    "%e2%80%9f",
+    // Bullet, copyright, registered, degree, ellipsis, and trademark signs.
+    "%e2%80%a2",
+    "%c2%a9",
+    "%c2%ae",
+    "%c2%b0",
+    "%e2%80%a6",
+    "%e2%84%a2",
+    // Acute accents, grave accent, macron, and caron.
+    "%c2%b4",
+    "%cb%8a",
+    "%cc%81",
+    "%cd%81",
+    "%cc%80",
+    "%cc%84",
+    "%cc%8c",
+    // Invisible characters without a width.
+    "%e2%80%8b",
+    "%e2%80%8c",
+    "%e2%80%8d",
+    "%e2%80%8e",
+    "%e2%80%8f",
+    "%e2%80%aa",
+    "%e2%80%ab",
+    "%e2%80%ac",
+    "%e2%80%ad",
+    "%e2%80%ae",
+    "%ef%bb%bf",
+    "%ef%bf%bc",
+];
+
+/// Percent-encoded sequences and entities that WordPress turns into hyphens.
+const WORDPRESS_HYPHENATED: &[&str] = &[
+    // No-break space, en dash, and em dash, encoded and as entities.
+    "%c2%a0",
+    "%e2%80%93",
+    "%e2%80%94",
+    "&nbsp;",
+    "&#160;",
+    "&ndash;",
+    "&#8211;",
+    "&mdash;",
+    "&#8212;",
+    // Invisible characters with a width.
+    "%e2%80%80",
+    "%e2%80%81",
+    "%e2%80%82",
+    "%e2%80%83",
+    "%e2%80%84",
+    "%e2%80%85",
+    "%e2%80%86",
+    "%e2%80%87",
+    "%e2%80%88",
+    "%e2%80%89",
+    "%e2%80%8a",
+    "%e2%80%a8",
+    "%e2%80%a9",
+    "%e2%80%af",
+    "/",
+];
+
+/// Reproduces WordPress' `sanitize_title`, which runs `remove_accents` and then
+/// `sanitize_title_with_dashes` in the `save` context.
+pub(crate) fn wordpress_title(title: &str) -> String {
+    let title = strip_tags(&remove_accents(title));
+
+    // Keep escaped octets, but drop any other percent sign.
+    let bytes = title.as_bytes();
+    let mut octets_kept = String::with_capacity(title.len());
+    for (i, c) in title.char_indices() {
+        let is_octet = |at: usize| bytes.get(at).is_some_and(u8::is_ascii_hexdigit);
+        if c != '%' || (is_octet(i + 1) && is_octet(i + 2)) {
+            octets_kept.push(c);
+        }
+    }
+
+    let mut title = utf8_uri_encode(&octets_kept.to_lowercase(), 200).to_ascii_lowercase();
+
+    for pattern in WORDPRESS_HYPHENATED {
+        title = title.replace(pattern, "-");
+    }
+    for pattern in WORDPRESS_STRIPPED {
+        title = title.replace(pattern, "");
+    }
+    title = title.replace("%c3%97", "x");
+
+    // Kill entities, i.e. everything from `&` up to the next `;`.
+    while let Some(start) = title.find('&') {
+        match title[start + 1..].find(';').filter(|&len| len > 0) {
+            Some(len) => title.replace_range(start..start + len + 2, ""),
+            None => break,
+        }
+    }
+
+    let mut slug = String::with_capacity(title.len());
+    for c in title.replace('.', "-").chars() {
+        let c = if c == ' ' { '-' } else { c };
+        let allowed = matches!(c, '%' | 'a'..='z' | '0'..='9' | '_' | '-');
+        if allowed && !(c == '-' && slug.ends_with('-')) {
+            slug.push(c);
+        }
+    }
+
+    slug.trim_matches('-').to_string()
+}
+
+/// Decodes HTML character references the way python-slugify does by default.
+///
+/// Numeric references are fully supported, named references only for the
+/// entities that commonly appear in titles.
+fn unescape_entities(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        unescaped.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let decoded = rest.find(';').and_then(|end| {
+            let name = &rest[1..end];
+            let c = match name.strip_prefix('#') {
+                Some(hex) if hex.starts_with(['x', 'X']) => u32::from_str_radix(&hex[1..], 16)
+                    .ok()
+                    .and_then(char::from_u32),
+                Some(decimal) => decimal.parse().ok().and_then(char::from_u32),
+                None => match name {
+                    "amp" => Some('&'),
+                    "lt" => Some('<'),
+                    "gt" => Some('>'),
+                    "quot" => Some('"'),
+                    "apos" => Some('\''),
+                    "nbsp" => Some('\u{A0}'),
+                    "ndash" => Some('–'),
+                    "mdash" => Some('—'),
+                    "hellip" => Some('…'),
+                    "copy" => Some('©'),
+                    "reg" => Some('®'),
+                    _ => None,
+                },
+            };
+            Some((c?, end))
+        });
+
+        match decoded {
+            Some((c, end)) => {
+                unescaped.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                unescaped.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    unescaped.push_str(rest);
+    unescaped
+}
+
+/// Reproduces python-slugify's `slugify` with the slugifier's separator, case,
+/// truncation options, and `allowed_chars` in place of `regex_pattern`.
+///
+/// Transliteration uses `any_ascii` instead of `unidecode`, so the two may
+/// romanize some scripts differently.
+pub(crate) fn python_slugify(slugifier: &Slugifier, text: &str) -> String {
+    use unicode_normalization::UnicodeNormalization;
+
+    // Quotes in the input separate words, but quotes produced by
+    // transliteration (e.g. for a Cyrillic soft sign) are dropped.
+    let text = translit::transliterate(&text.replace('\'', "-"));
+    let mut text: String = unescape_entities(&text).nfkd().collect();
+    if slugifier.to_lowercase {
+        text = text.to_lowercase();
+    }
+    text.retain(|c| c != '\'');
+
+    let mut slug = String::with_capacity(text.len());
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        // Thousands separators are removed, so that "1,000" becomes "1000".
+        let is_digit_group = c == ','
+            && text[..i].ends_with(|c: char| c.is_ascii_digit())
+            && chars.peek().is_some_and(|&(_, next)| next.is_ascii_digit());
+        if is_digit_group {
+            continue;
+        }
+
+        if c.is_ascii_alphanumeric() || slugifier.allowed_chars.contains(c) {
+            slug.push(c);
+        } else if !slug.ends_with(&slugifier.separator) {
+            slug.push_str(&slugifier.separator);
+        }
+    }
+
+    let mut slug = slug
+        .trim_matches(|c| slugifier.separator.contains(c))
+        .to_string();
+    slugifier.apply_truncation(&mut slug);
+    slug
+}
+
+/// Symbols that the npm `slugify` package spells out as words.
+const NPM_SLUGIFY_SYMBOLS: &[(char, &str)] = &[
+    ('$', "dollar"),
+    ('%', "percent"),
+    ('&', "and"),
+    ('<', "less"),
+    ('>', "greater"),
+    ('|', "or"),
+    ('¢', "cent"),
+    ('£', "pound"),
+    ('¤', "currency"),
+    ('¥', "yen"),
+    ('©', "(c)"),
+    ('®', "(r)"),
+    ('™', "tm"),
+    ('€', "euro"),
+    ('₹', "indian rupee"),
+    ('∑', "sum"),
+    ('∆', "delta"),
+    ('∞', "infinity"),
+    ('♥', "love"),
+];
+
+/// Reproduces the npm `slugify` package, using the slugifier's separator as
+/// `replacement`, its case as `lower`, and `remove_chars` as `remove`.
+///
+/// Like the package's character map, only Latin, Greek, and Cyrillic letters
+/// are transliterated, and other characters outside of ASCII are dropped.
+pub(crate) fn npm_slugify(slugifier: &Slugifier, text: &str, strict: bool) -> String {
+    use unicode_normalization::UnicodeNormalization;
+
+    let is_kept = |c: char| {
+        if strict {
+            c.is_ascii_alphanumeric() || c.is_whitespace()
+        } else if !slugifier.remove_chars.is_empty() {
+            !slugifier.remove_chars.contains(c)
+        } else {
+            c.is_ascii_alphanumeric() || c.is_whitespace() || "$*_+~.()'\"!-:@".contains(c)
+        }
+    };
+
+    let mut slug = String::with_capacity(text.len());
+    for c in text.nfc() {
+        let mapped = match NPM_SLUGIFY_SYMBOLS.iter().find(|&&(symbol, _)| symbol == c) {
+            Some(&(_, word)) => word,
+            None if matches!(c, '\u{00C0}'..='\u{052F}' | '\u{1E00}'..='\u{1FFF}') => {
+                translit::transliterate_char(c)
+            }
+            None => "",
+        };
+
+        let mut buf = [0; 4];
+        let mapped = if mapped.is_empty() && (c.is_ascii() || c.is_whitespace()) {
+            c.encode_utf8(&mut buf)
+        } else {
+            mapped
+        };
+        if mapped == slugifier.separator {
+            slug.push(' ');
+        } else {
+            slug.extend(mapped.chars().filter(|&c| is_kept(c)));
+        }
+    }
+
+    let mut joined = String::with_capacity(slug.len());
+    for (i, word) in slug.split_whitespace().enumerate() {
+        if i > 0 {
+            joined.push_str(&slugifier.separator);
+        }
+        joined.push_str(word);
+    }
+    if slugifier.to_lowercase {
+        joined = joined.to_lowercase();
+    }
+    slugifier.apply_truncation(&mut joined);
+    joined
+}
+
+/// Reproduces Hugo's `urlize` function: punctuation is dropped, whitespace
+/// between words becomes the separator, and characters outside of ASCII are
+/// percent-encoded, as Hugo does when it escapes the resulting URL.
+pub(crate) fn hugo_urlize(slugifier: &Slugifier, text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut path = String::with_capacity(text.len());
+    let mut prepend_separator = false;
+    let mut was_hyphen = false;
+
+    for (i, &c) in chars.iter().enumerate() {
+        let is_octet = c == '%'
+            && chars.get(i + 1).is_some_and(char::is_ascii_hexdigit)
+            && chars.get(i + 2).is_some_and(char::is_ascii_hexdigit);
+        let is_allowed =
+            c.is_alphanumeric() || is_combining_mark(c) || "./\\_#+~-@".contains(c) || is_octet;
+
+        if is_allowed {
+            was_hyphen = c == '-';
+            if prepend_separator && !was_hyphen {
+                path.push_str(&slugifier.separator);
+            }
+            prepend_separator = false;
+            path.push(c);
+        } else if !path.is_empty() && !was_hyphen && c.is_whitespace() {
+            prepend_separator = true;
+        }
+    }
+
+    if slugifier.to_lowercase {
+        path = path.to_lowercase();
+    }
+    let mut slug = encoding::percent_encode(&path, |b| b.is_ascii() && b != b'\\');
+    // Escapes kept from the text are written like the encoded characters, with
+    // uppercase hex digits.
+    let escapes: Vec<usize> = slug.match_indices('%').map(|(i, _)| i).collect();
+    for i in escapes {
+        slug[i + 1..i + 3].make_ascii_uppercase();
+    }
+
+    truncate_percent_encoded(slugifier, &mut slug);
+    slug
+}
+
+/// Applies the slugifier's truncation to a percent-encoded slug, without
+/// splitting an escaped character.
+pub(crate) fn truncate_percent_encoded(slugifier: &Slugifier, slug: &mut String) {
+    slugifier.apply_truncation(slug);
+    let end = encoding::floor_escape_boundary(slug, slug.len());
+    if end < slug.len() {
+        slug.truncate(end);
+        let trimmed_len = slug
+            .trim_end_matches(|c| slugifier.separator.contains(c))
+            .len();
+        slug.truncate(trimmed_len);
+    }
+}
+
+/// Reproduces Jekyll's `slugify` filter in the given mode, replacing each run
+/// of characters the mode doesn't keep with the separator.
+pub(crate) fn jekyll_slugify(slugifier: &Slugifier, text: &str, mode: JekyllMode) -> String {
+    let is_word_char = |c: char| c.is_alphanumeric() || is_combining_mark(c);
+    let is_kept = |c: char| match mode {
+        JekyllMode::Raw => !c.is_whitespace(),
+        JekyllMode::Default | JekyllMode::Latin => is_word_char(c),
+        JekyllMode::Pretty => is_word_char(c) || "._~!$&'()+,;=@".contains(c),
+        JekyllMode::Ascii => c.is_ascii_alphanumeric(),
+    };
+
+    // The latin mode drops accents from Latin letters, and replaces other
+    // characters outside of ASCII with `?`, which is then removed.
+    let text: Cow<str> = match mode {
+        JekyllMode::Latin => {
+            let mut latin = String::with_capacity(text.len());
+            for c in text.chars() {
+                match c {
+                    _ if c.is_ascii() => latin.push(c),
+                    '\u{00C0}'..='\u{024F}' | '\u{1E00}'..='\u{1EFF}' => {
+                        latin.push_str(translit::transliterate_char(c));
+                    }
+                    _ => latin.push('?'),
+                }
+            }
+            Cow::Owned(latin)
+        }
+        _ => Cow::Borrowed(text),
+    };
+
+    let mut slug = String::with_capacity(text.len());
+    let mut in_run = false;
+    for c in text.chars() {
+        if is_kept(c) {
+            // A run at the start or end is removed instead of replaced.
+            if in_run && !slug.is_empty() {
+                slug.push_str(&slugifier.separator);
+            }
+            slug.push(c);
+            in_run = false;
+        } else {
+            in_run = true;
+        }
+    }
+    // Jekyll removes a single leading and trailing hyphen, which only remain
+    // in the raw mode.
+    if mode == JekyllMode::Raw {
+        let trimmed = slug.strip_prefix('-').unwrap_or(&slug);
+        slug = trimmed.strip_suffix('-').unwrap_or(trimmed).to_string();
+    }
+
+    if slugifier.to_lowercase {
+        slug = slug.to_lowercase();
+    }
+    slugifier.apply_truncation(&mut slug);
+    slug
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wordpress_title() {
+        assert_eq!(wordpress_title("Hello World!"), "hello-world");
+        assert_eq!(
+            wordpress_title("This is a <b>bold</b> move"),
+            "this-is-a-bold-move"
+        );
+        assert_eq!(wordpress_title("Café & Crème Brûlée"), "cafe-creme-brulee");
+        assert_eq!(
+            wordpress_title("snake_case and v2.0"),
+            "snake_case-and-v2-0"
+        );
+        assert_eq!(wordpress_title("Tom &amp; Jerry"), "tom-jerry");
+        assert_eq!(wordpress_title("“Quoted” — dash"), "quoted-dash");
+        assert_eq!(wordpress_title("100% off 4×4"), "100-off-4x4");
+    }
+
+    #[test]
+    fn test_wordpress_title_percent_encodes_unicode() {
+        assert_eq!(wordpress_title("日本"), "%e6%97%a5%e6%9c%ac");
+        assert_eq!(wordpress_title("already %E6%97%A5"), "already-%e6%97%a5");
+    }
+
+    #[test]
+    #[cfg(feature = "transliteration")]
+    fn test_python_slugify() {
+        let slugifier = Slugifier::new().preset(crate::Preset::PythonSlugify);
+        let slugify = |text| python_slugify(&slugifier, text);
+        assert_eq!(slugify("This is a test ---"), "this-is-a-test");
+        assert_eq!(slugify("C'est déjà l'été."), "c-est-deja-l-ete");
+        assert_eq!(
+            slugify("Nín hǎo. Wǒ shì zhōng guó rén"),
+            "nin-hao-wo-shi-zhong-guo-ren"
+        );
+        assert_eq!(
+            slugify("1,000 reasons you are #1"),
+            "1000-reasons-you-are-1"
+        );
+        assert_eq!(slugify("foo &amp; bar"), "foo-bar");
+        assert_eq!(slugify("i love 🦄"), "i-love-unicorn");
+    }
+
+    #[test]
+    fn test_npm_slugify() {
+        let slugifier = Slugifier::new().preset(crate::Preset::NpmSlugify);
+        let slugify = |text| npm_slugify(&slugifier, text, false);
+        assert_eq!(slugify("some string"), "some-string");
+        assert_eq!(slugify("Hello, World!"), "Hello-World!");
+        assert_eq!(slugify("  you & me - 5$ "), "you-and-me-5dollar");
+        assert_eq!(slugify("Crème Brûlée 北京"), "Creme-Brulee");
+        assert_eq!(
+            npm_slugify(&slugifier, "Hello, World! (2nd)", true),
+            "Hello-World-2nd"
+        );
+    }
+
+    #[test]
+    fn test_hugo_urlize() {
+        let slugifier = Slugifier::new().preset(crate::Preset::HugoUrlize);
+        let urlize = |text| hugo_urlize(&slugifier, text);
+        assert_eq!(urlize("Hello, World!"), "hello-world");
+        assert_eq!(
+            urlize("  C# and C++ - a comparison "),
+            "c#-and-c++-a-comparison"
+        );
+        assert_eq!(urlize("Été à Zürich"), "%C3%A9t%C3%A9-%C3%A0-z%C3%BCrich");
+        assert_eq!(urlize("100%25 done, 50% left"), "100%25-done-50-left");
+        assert_eq!(urlize("caf%c3%a9"), "caf%C3%A9");
+
+        let slugifier = slugifier.truncate(5);
+        assert_eq!(hugo_urlize(&slugifier, "Café"), "caf");
+        assert_eq!(hugo_urlize(&slugifier, "É x"), "");
+        let slugifier = slugifier.truncate(8);
+        assert_eq!(hugo_urlize(&slugifier, "Été"), "%C3%A9t");
+    }
+
+    #[test]
+    fn test_jekyll_slugify() {
+        let slugifier = Slugifier::new().preset(crate::Preset::Jekyll(JekyllMode::Default));
+        let slugify = |text, mode| jekyll_slugify(&slugifier, text, mode);
+        let title = "The _config.yml file?! Café";
+        assert_eq!(
+            slugify(title, JekyllMode::Raw),
+            "the-_config.yml-file?!-café"
+        );
+        assert_eq!(
+            slugify(title, JekyllMode::Default),
+            "the-config-yml-file-café"
+        );
+        assert_eq!(
+            slugify(title, JekyllMode::Pretty),
+            "the-_config.yml-file-!-café"
+        );
+        assert_eq!(slugify(title, JekyllMode::Ascii), "the-config-yml-file-caf");
+        assert_eq!(
+            slugify(title, JekyllMode::Latin),
+            "the-config-yml-file-cafe"
+        );
+        assert_eq!(slugify("日本語 post", JekyllMode::Latin), "post");
+    }
+
+    #[test]
+    fn test_unescape_entities() {
+        assert_eq!(
+            unescape_entities("a &amp; b &#233; &#x41; &bogus; &"),
+            "a & b é A &bogus; &"
+        );
+    }
+
+    #[test]
+    fn test_strip_tags() {
+        assert_eq!(strip_tags("<p>a <em>b</em></p>"), "a b");
+        assert_eq!(strip_tags("1 < 2"), "1 < 2");
+    }
+}
@@ -0,0 +1,141 @@
+//! Slugifiers with their lookup tables built ahead of time.
+
+use std::collections::HashSet;
+
+use crate::builder;
+use crate::symbols::Terms;
+use crate::{SlugBuilder, Slugifier};
+
+/// A `Slugifier` with its configuration turned into lookup tables, for hot
+/// paths that slugify many texts with the same configuration.
+///
+/// `slugify` builds these tables on every call: the replacements sorted by
+/// precedence and the stop words.
+/// A compiled slugifier builds them once in [`Slugifier::compile`], and
+/// generates the same slugs.
+///
+/// # Example
+///
+/// ```
+/// use rslug::Slugifier;
+///
+/// let slugifier = Slugifier::new()
+///     .replace("C++", "cpp")
+///     .stop_words(&["a", "the"])
+///     .compile();
+/// assert_eq!(slugifier.slugify("The C++ Book"), "cpp-book");
+/// ```
+#[derive(Debug, Clone)]
+pub struct CompiledSlugifier {
+    slugifier: Slugifier,
+    terms: Terms<'static>,
+    /// The stop words in lowercase.
+    stop_words: HashSet<String>,
+}
+
+impl Slugifier {
+    /// Builds the lookup tables for this configuration, returning a slugifier
+    /// that is faster when used repeatedly.
+    pub fn compile(&self) -> CompiledSlugifier {
+        CompiledSlugifier {
+            slugifier: self.clone(),
+            terms: Terms::new(self).into_owned(),
+            stop_words: self
+                .stop_words
+                .iter()
+                .map(|word| word.to_ascii_lowercase())
+                .collect(),
+        }
+    }
+}
+
+impl CompiledSlugifier {
+    /// Returns the configuration this slugifier was compiled from.
+    pub fn slugifier(&self) -> &Slugifier {
+        &self.slugifier
+    }
+
+    /// Generates a slug like [`Slugifier::slugify`].
+    pub fn slugify(&self, text: &str) -> String {
+        let mut slug = String::new();
+        self.slugify_into(text, &mut slug);
+        slug
+    }
+
+    /// Generates a slug like [`Slugifier::slugify_into`], writing it into `buffer`.
+    pub fn slugify_into(&self, text: &str, buffer: &mut String) {
+        let slugifier = &self.slugifier;
+        if slugifier.on_loss.is_some() {
+            slugifier.slugify_into(text, buffer);
+            return;
+        }
+
+        let text = self.terms.apply(text);
+
+        if let Some(preset) = slugifier.preset
+            && let Some(slug) = preset.slugify(slugifier, &text)
+        {
+            buffer.clear();
+            buffer.push_str(&slug);
+            return;
+        }
+
+        buffer.clear();
+        buffer.reserve(slugifier.estimate_built_len(&text));
+        let mut builder = SlugBuilder::with_buffer(slugifier, std::mem::take(buffer));
+        builder.push_str(&text);
+        // Writing to a `String` never fails.
+        let slug = builder.into_sink().unwrap_or_default();
+        *buffer = builder::finish_slug_with(slugifier, slug, |word| {
+            if slugifier.to_lowercase {
+                self.stop_words.contains(word)
+            } else {
+                self.stop_words.contains(&word.to_ascii_lowercase())
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Preset;
+
+    #[test]
+    fn test_compiled_matches_slugify() {
+        let slugifiers = [
+            Slugifier::new(),
+            Slugifier::new().allowed_chars(".").remove_chars("'"),
+            Slugifier::new()
+                .replace("js", "javascript")
+                .lang(Some("de")),
+            Slugifier::new()
+                .to_lowercase(false)
+                .stop_words(&["the", "OF"])
+                .truncate(16),
+            Slugifier::new().stop_words(&["the"]).max_segments(2),
+            Slugifier::new().preset(Preset::RustIdent),
+            Slugifier::new().preset(Preset::S3Bucket),
+        ];
+        let inputs = [
+            "The Lord of the Rings",
+            "Don't use JS & jQuery v1.2",
+            "Mädchen für alles",
+            "192.168.0.1",
+            "",
+        ];
+        for slugifier in &slugifiers {
+            let compiled = slugifier.compile();
+            for text in inputs {
+                assert_eq!(compiled.slugify(text), slugifier.slugify(text), "{text:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_compiled_outlives_configuration() {
+        let compiled = Slugifier::new().replace("&", "and").compile();
+        assert_eq!(compiled.slugify("Tom & Jerry"), "tom-and-jerry");
+        assert_eq!(compiled.slugifier().slugify("Tom & Jerry"), "tom-and-jerry");
+    }
+}
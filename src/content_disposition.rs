@@ -0,0 +1,116 @@
+//! Building `Content-Disposition` header values from arbitrary titles.
+
+use std::fmt;
+
+use crate::{Slugifier, encoding, translit};
+
+/// The filename parameters of a `Content-Disposition` header.
+///
+/// Its `Display` implementation renders a complete `attachment` header value
+/// carrying both parameters, so clients that don't understand RFC 5987 still
+/// get a usable name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContentDisposition {
+    /// The plain ASCII fallback, suitable for the quoted `filename=` parameter.
+    pub filename: String,
+    /// The RFC 5987 extended value for the `filename*=` parameter, including
+    /// its `UTF-8''` prefix.
+    pub filename_star: String,
+}
+
+impl fmt::Display for ContentDisposition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "attachment; filename=\"{}\"; filename*={}",
+            self.filename, self.filename_star
+        )
+    }
+}
+
+/// The filename used for titles that leave nothing to name the file after.
+const FALLBACK_FILENAME: &str = "download";
+
+/// Returns `true` for the RFC 5987 `attr-char` set, which may appear unencoded.
+fn is_attr_char(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b"!#$&+-.^_`|~".contains(&b)
+}
+
+impl Slugifier {
+    /// Builds the `Content-Disposition` filename parameters for a download.
+    ///
+    /// The title is first passed through `sanitize_filename`. The UTF-8 result
+    /// is percent-encoded for `filename*=`, and a transliterated ASCII version
+    /// without quotes, backslashes, or percent signs is used for `filename=`.
+    /// Titles that leave no name at all are downloaded as `download`.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - The desired name of the downloaded file.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    ///
+    /// let header = Slugifier::new().content_disposition("Résumé \"final\".pdf");
+    /// assert_eq!(header.filename, "Resume final.pdf");
+    /// assert_eq!(header.filename_star, "UTF-8''R%C3%A9sum%C3%A9%20final.pdf");
+    /// assert_eq!(
+    ///     header.to_string(),
+    ///     "attachment; filename=\"Resume final.pdf\"; filename*=UTF-8''R%C3%A9sum%C3%A9%20final.pdf"
+    /// );
+    /// ```
+    pub fn content_disposition(&self, title: &str) -> ContentDisposition {
+        let mut sanitized = self.sanitize_filename(title);
+        if sanitized.trim().is_empty() {
+            sanitized = FALLBACK_FILENAME.to_string();
+        }
+
+        let mut filename: String = translit::transliterate(&sanitized)
+            .chars()
+            .filter(|&c| (' '..='~').contains(&c) && !matches!(c, '"' | '\\' | '%'))
+            .collect();
+        if filename.trim().is_empty() {
+            filename = FALLBACK_FILENAME.to_string();
+        }
+
+        ContentDisposition {
+            filename,
+            filename_star: format!(
+                "UTF-8''{}",
+                encoding::percent_encode(&sanitized, is_attr_char)
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_disposition_ascii_title() {
+        let header = Slugifier::new().content_disposition("report 2024.csv");
+        assert_eq!(header.filename, "report 2024.csv");
+        assert_eq!(header.filename_star, "UTF-8''report%202024.csv");
+    }
+
+    #[test]
+    #[cfg(feature = "transliteration")]
+    fn test_content_disposition_unicode_title() {
+        let header = Slugifier::new().content_disposition("日本語/資料.txt");
+        assert_eq!(header.filename, "RiBenYuZiLiao.txt");
+        assert_eq!(
+            header.filename_star,
+            "UTF-8''%E6%97%A5%E6%9C%AC%E8%AA%9E%E8%B3%87%E6%96%99.txt"
+        );
+    }
+
+    #[test]
+    fn test_content_disposition_empty_fallback() {
+        let header = Slugifier::new().content_disposition("???");
+        assert_eq!(header.filename, "download");
+        assert_eq!(header.filename_star, "UTF-8''download");
+    }
+}
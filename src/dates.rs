@@ -0,0 +1,269 @@
+//! Recognizing dates in titles, like the "May 1, 2024" of "Release notes for
+//! May 1, 2024", and formatting them for slugs.
+
+const MONTHS: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+/// A calendar date found in text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Date {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Returns the date, if it exists.
+    fn new(year: u16, month: u8, day: u8) -> Option<Self> {
+        let is_leap =
+            year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400));
+        let days = match month {
+            2 if is_leap => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            1..=12 => 31,
+            _ => return None,
+        };
+        (1..=days)
+            .contains(&day)
+            .then_some(Self { year, month, day })
+    }
+
+    /// Writes the date as `format` says, replacing `%Y` with the year, `%m`
+    /// and `%d` with the month and day as two digits, `%B` and `%b` with the
+    /// English name of the month and its first three letters, and `%%` with
+    /// `%`.
+    pub(crate) fn format(&self, format: &str) -> String {
+        let month = MONTHS[usize::from(self.month - 1)];
+        let mut formatted = String::with_capacity(format.len() + 8);
+        let mut chars = format.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                formatted.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('Y') => formatted.push_str(&format!("{:04}", self.year)),
+                Some('m') => formatted.push_str(&format!("{:02}", self.month)),
+                Some('d') => formatted.push_str(&format!("{:02}", self.day)),
+                Some('B') => formatted.push_str(month),
+                Some('b') => formatted.push_str(&month[..3]),
+                Some('%') => formatted.push('%'),
+                Some(other) => {
+                    formatted.push('%');
+                    formatted.push(other);
+                }
+                None => formatted.push('%'),
+            }
+        }
+        formatted
+    }
+}
+
+/// Returns the date at the start of `text` and its length, if there is one.
+///
+/// These forms are recognized, with English month names or their
+/// abbreviations in any case:
+///
+/// * "2024-05-01", with dashes, slashes, or dots.
+/// * "01.05.2024", the day-first form with dots.
+/// * "1 May 2024" and "1st May 2024".
+/// * "May 1, 2024", "May 1 2024", and "May 1st, 2024".
+///
+/// Forms like "05/01/2024" are left alone, since the day may come first or
+/// second.
+pub(crate) fn parse(text: &str) -> Option<(Date, usize)> {
+    let mut scanner = Scanner { text, pos: 0 };
+    let date = scanner
+        .restart()
+        .iso()
+        .or_else(|| scanner.restart().dotted())
+        .or_else(|| scanner.restart().day_first())
+        .or_else(|| scanner.restart().month_first())?;
+
+    // A date ends where a word can.
+    let is_word_end = !text[scanner.pos..]
+        .chars()
+        .next()
+        .is_some_and(char::is_alphanumeric);
+    is_word_end.then_some((date, scanner.pos))
+}
+
+/// A position in the text being parsed.
+struct Scanner<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl Scanner<'_> {
+    fn restart(&mut self) -> &mut Self {
+        self.pos = 0;
+        self
+    }
+
+    fn iso(&mut self) -> Option<Date> {
+        let year = self.number(4, 4)?;
+        let separator = self.one_of(&['-', '/', '.'])?;
+        let month = self.number(1, 2)?;
+        self.one_of(&[separator])?;
+        let day = self.number(1, 2)?;
+        Date::new(year, month as u8, day as u8)
+    }
+
+    fn dotted(&mut self) -> Option<Date> {
+        let day = self.number(1, 2)?;
+        self.one_of(&['.'])?;
+        let month = self.number(1, 2)?;
+        self.one_of(&['.'])?;
+        let year = self.number(4, 4)?;
+        Date::new(year, month as u8, day as u8)
+    }
+
+    fn day_first(&mut self) -> Option<Date> {
+        let day = self.day()?;
+        self.spaces(1)?;
+        let month = self.month()?;
+        self.spaces(1)?;
+        let year = self.number(4, 4)?;
+        Date::new(year, month, day)
+    }
+
+    fn month_first(&mut self) -> Option<Date> {
+        let month = self.month()?;
+        self.spaces(1)?;
+        let day = self.day()?;
+        self.one_of(&[',']);
+        self.spaces(1)?;
+        let year = self.number(4, 4)?;
+        Date::new(year, month, day)
+    }
+
+    /// Reads a number of `min` to `max` ASCII digits, not followed by another.
+    fn number(&mut self, min: usize, max: usize) -> Option<u16> {
+        let rest = &self.text.as_bytes()[self.pos..];
+        let len = rest.iter().take_while(|c| c.is_ascii_digit()).count();
+        if !(min..=max).contains(&len) {
+            return None;
+        }
+        self.pos += len;
+        self.text[self.pos - len..self.pos].parse().ok()
+    }
+
+    /// Reads a day of the month, with an optional ordinal suffix.
+    fn day(&mut self) -> Option<u8> {
+        let day = self.number(1, 2)?;
+        let rest = &self.text[self.pos..];
+        if let Some(suffix) = ["st", "nd", "rd", "th"].into_iter().find(|suffix| {
+            rest.get(..2)
+                .is_some_and(|s| s.eq_ignore_ascii_case(suffix))
+        }) {
+            self.pos += suffix.len();
+        }
+        u8::try_from(day).ok()
+    }
+
+    /// Reads the name of a month, or its abbreviation with an optional dot.
+    fn month(&mut self) -> Option<u8> {
+        let rest = &self.text[self.pos..];
+        let len = rest
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(rest.len());
+        let word = rest[..len].to_ascii_lowercase();
+        let month = MONTHS
+            .iter()
+            .position(|month| *month == word || (word.len() >= 3 && month.starts_with(&word)))?;
+        self.pos += len;
+        if len < MONTHS[month].len() {
+            self.one_of(&['.']);
+        }
+        Some(month as u8 + 1)
+    }
+
+    /// Reads one of `chars`.
+    fn one_of(&mut self, chars: &[char]) -> Option<char> {
+        let c = self.text[self.pos..]
+            .chars()
+            .next()
+            .filter(|c| chars.contains(c))?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    /// Reads at least `min` spaces.
+    fn spaces(&mut self, min: usize) -> Option<()> {
+        let rest = &self.text[self.pos..];
+        let len = rest.len() - rest.trim_start_matches(' ').len();
+        self.pos += len;
+        (len >= min).then_some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_dates() {
+        let may_first = Date::new(2024, 5, 1);
+        for text in [
+            "2024-05-01",
+            "2024/5/1",
+            "01.05.2024",
+            "1 May 2024",
+            "1st May 2024",
+            "May 1, 2024",
+            "may 1st 2024",
+            "Sept. 1, 2024",
+        ] {
+            let expected = if text.starts_with("Sept") {
+                Date::new(2024, 9, 1)
+            } else {
+                may_first
+            };
+            assert_eq!(
+                parse(text),
+                expected.map(|date| (date, text.len())),
+                "{text}"
+            );
+        }
+        assert_eq!(parse("May 1, 2024: notes").map(|(_, len)| len), Some(11));
+    }
+
+    #[test]
+    fn test_parse_rejects_non_dates() {
+        for text in [
+            "2024-13-01",
+            "2023-02-29",
+            "2024-05/01",
+            "05/01/2024",
+            "May 2024",
+            "Mayday 1 2024",
+            "2024-05-01x",
+            "12345-05-01",
+        ] {
+            assert_eq!(parse(text), None, "{text}");
+        }
+        assert!(parse("2024-02-29").is_some());
+    }
+
+    #[test]
+    fn test_format() {
+        let date = Date::new(2024, 5, 1).unwrap();
+        assert_eq!(date.format("%Y-%m-%d"), "2024-05-01");
+        assert_eq!(date.format("%Y%m%d"), "20240501");
+        assert_eq!(date.format("%d %b %Y"), "01 may 2024");
+        assert_eq!(date.format("%B %Y, 100%% %q"), "may 2024, 100% %q");
+    }
+}
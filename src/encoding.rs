@@ -0,0 +1,116 @@
+//! Percent-encoding helpers shared by the URL and HTTP header APIs.
+
+/// Percent-encodes every byte of `text` for which `keep` returns `false`,
+/// using uppercase hex digits.
+pub(crate) fn percent_encode(text: &str, keep: impl Fn(u8) -> bool) -> String {
+    let mut encoded = String::with_capacity(text.len());
+
+    for &b in text.as_bytes() {
+        if keep(b) {
+            encoded.push(b as char);
+        } else {
+            encoded.push('%');
+            encoded.push(
+                char::from_digit(u32::from(b >> 4), 16)
+                    .unwrap()
+                    .to_ascii_uppercase(),
+            );
+            encoded.push(
+                char::from_digit(u32::from(b & 0xF), 16)
+                    .unwrap()
+                    .to_ascii_uppercase(),
+            );
+        }
+    }
+
+    encoded
+}
+
+/// Decodes `%XX` escapes in `text`, leaving malformed escapes as they are and
+/// replacing invalid UTF-8 with U+FFFD.
+pub(crate) fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|hex| u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok());
+        match hex {
+            Some(b) if bytes[i] == b'%' => {
+                decoded.push(b);
+                i += 3;
+            }
+            _ => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Returns the largest index of at most `index` at which `text` can be cut
+/// without splitting an escape or the escapes of a single UTF-8 encoded
+/// character. Every `%` in `text` must start an escape.
+pub(crate) fn floor_escape_boundary(text: &str, index: usize) -> usize {
+    let bytes = &text.as_bytes()[..index];
+    let tail = index.saturating_sub(2);
+    let end = match bytes[tail..].iter().position(|&b| b == b'%') {
+        Some(i) => tail + i,
+        None => index,
+    };
+
+    // Walk back over the escapes of the last character to its first byte,
+    // which tells how many bytes the character has.
+    let mut start = end;
+    let mut continuations = 0;
+    while start >= 3 && bytes[start - 3] == b'%' {
+        let byte = u8::from_str_radix(&text[start - 2..start], 16).unwrap_or(0);
+        start -= 3;
+        if byte & 0xC0 == 0x80 {
+            continuations += 1;
+            continue;
+        }
+        let len = match byte {
+            0xC0..=0xDF => 2,
+            0xE0..=0xEF => 3,
+            0xF0.. => 4,
+            _ => 1,
+        };
+        return if len == continuations + 1 { end } else { start };
+    }
+    end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percent_encode() {
+        let keep = |b: u8| b.is_ascii_alphanumeric();
+        assert_eq!(percent_encode("abc123", keep), "abc123");
+        assert_eq!(percent_encode("a b/é", keep), "a%20b%2F%C3%A9");
+    }
+
+    #[test]
+    fn test_percent_decode() {
+        assert_eq!(percent_decode("a%20b%2F%C3%A9"), "a b/é");
+        assert_eq!(percent_decode("100% %zz %4"), "100% %zz %4");
+    }
+
+    #[test]
+    fn test_floor_escape_boundary() {
+        let text = "caf%C3%A9-%25";
+        assert_eq!(floor_escape_boundary(text, 3), 3);
+        assert_eq!(floor_escape_boundary(text, 5), 3);
+        assert_eq!(floor_escape_boundary(text, 8), 3);
+        assert_eq!(floor_escape_boundary(text, 9), 9);
+        assert_eq!(floor_escape_boundary(text, 12), 10);
+        assert_eq!(floor_escape_boundary(text, 13), 13);
+        assert_eq!(floor_escape_boundary("%e6%97%a5%e6", 12), 9);
+    }
+}
@@ -0,0 +1,140 @@
+//! Error types returned by the fallible `rslug` APIs.
+
+use std::fmt;
+use std::path::PathBuf;
+
+/// An error produced while generating or validating a slug.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// A single word of the input mixes letters from more than one script,
+    /// such as Latin and Cyrillic.
+    MixedScript {
+        /// The offending word, as it appeared in the input.
+        word: String,
+    },
+    /// The slug is empty, either because it was given empty or because nothing
+    /// of the input survived slugification.
+    Empty,
+    /// The slug is longer than the configured maximum length.
+    TooLong {
+        /// The length of the slug in bytes.
+        len: usize,
+        /// The maximum allowed length in bytes.
+        max: usize,
+    },
+    /// The slug is shorter than the minimum length required by the active preset.
+    TooShort {
+        /// The length of the slug in bytes.
+        len: usize,
+        /// The minimum required length in bytes.
+        min: usize,
+    },
+    /// The slug is well-formed, but reserved or disallowed by the active preset,
+    /// like an S3 bucket name formatted as an IP address.
+    Reserved {
+        /// The rejected slug.
+        slug: String,
+    },
+    /// The slug contains a character that the configuration never produces.
+    InvalidChar {
+        /// The offending character.
+        ch: char,
+        /// The byte index of the character in the slug.
+        index: usize,
+    },
+    /// The slug only contains valid characters, but slugifying it again would
+    /// change it, e.g. because of a leading or doubled separator.
+    NotNormalized,
+    /// A path contains a `..` component that could escape its base directory.
+    PathTraversal {
+        /// The path that was rejected.
+        path: PathBuf,
+    },
+    /// No preset is built in or registered under the given name.
+    UnknownPreset {
+        /// The name that was looked up.
+        name: String,
+    },
+    /// The process-wide default slugifier was already set or used.
+    DefaultAlreadySet,
+    /// The separator contains a character that slugs can't be separated by,
+    /// like an alphanumeric or a character the active preset doesn't allow.
+    InvalidSeparator {
+        /// The rejected separator.
+        separator: String,
+        /// The first offending character of the separator.
+        ch: char,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MixedScript { word } => {
+                write!(f, "word {word:?} mixes letters from multiple scripts")
+            }
+            Error::Empty => write!(f, "slug is empty"),
+            Error::TooLong { len, max } => {
+                write!(f, "slug is {len} bytes long, but at most {max} are allowed")
+            }
+            Error::TooShort { len, min } => {
+                write!(
+                    f,
+                    "slug is {len} bytes long, but at least {min} are required"
+                )
+            }
+            Error::Reserved { slug } => write!(f, "slug {slug:?} is reserved"),
+            Error::InvalidChar { ch, index } => {
+                write!(f, "slug contains invalid character {ch:?} at index {index}")
+            }
+            Error::NotNormalized => write!(f, "slug is not in normalized form"),
+            Error::PathTraversal { path } => {
+                write!(
+                    f,
+                    "path {} contains a parent directory component",
+                    path.display()
+                )
+            }
+            Error::UnknownPreset { name } => write!(f, "no preset is named {name:?}"),
+            Error::DefaultAlreadySet => write!(f, "default slugifier was already set or used"),
+            Error::InvalidSeparator { separator, ch } => {
+                write!(f, "separator {separator:?} can't contain {ch:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// An error returned by [`Slugifier::try_slugify_ascii`](crate::Slugifier::try_slugify_ascii)
+/// when its input isn't ASCII.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct AsciiError {
+    /// The bytes outside of ASCII, with their offsets in the input.
+    pub invalid_bytes: Vec<(usize, u8)>,
+}
+
+impl AsciiError {
+    /// Returns the length of the ASCII prefix of the input, i.e. the offset of
+    /// the first non-ASCII byte.
+    pub fn valid_up_to(&self) -> usize {
+        self.invalid_bytes.first().map_or(0, |&(offset, _)| offset)
+    }
+}
+
+impl fmt::Display for AsciiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let count = self.invalid_bytes.len();
+        match self.invalid_bytes.first() {
+            Some((offset, byte)) => write!(
+                f,
+                "input contains {count} non-ASCII byte(s), the first is {byte:#04x} at offset {offset}"
+            ),
+            None => write!(f, "input contains non-ASCII bytes"),
+        }
+    }
+}
+
+impl std::error::Error for AsciiError {}
@@ -0,0 +1,179 @@
+//! Slugs generated from the beginning of a longer text.
+
+use crate::Slugifier;
+
+/// How much of a text `slugify_excerpt` uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Excerpt {
+    /// The first `n` words, possibly ending mid-sentence.
+    Words(usize),
+    /// The first `n` sentences.
+    Sentences(usize),
+    /// As many whole sentences as fit in `n` words, so the excerpt never
+    /// ends mid-sentence. If the first sentence alone is longer, its first
+    /// `n` words are used instead.
+    WholeSentences(usize),
+}
+
+/// Abbreviations that a period doesn't end a sentence after.
+const ABBREVIATIONS: &[&str] = &[
+    "Dr", "Jr", "Mr", "Mrs", "Ms", "Mt", "Prof", "Sr", "St", "vs",
+];
+
+/// Returns `true` if the period at `end` of `text` ends a single letter, like
+/// an initial or the parts of "e.g.", or an abbreviation.
+fn is_abbreviation(text: &str, end: usize) -> bool {
+    let word = text[..end]
+        .rsplit(|c: char| c.is_whitespace() || c == '.')
+        .next()
+        .unwrap_or_default();
+    let mut chars = word.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => c.is_alphabetic(),
+        _ => ABBREVIATIONS.contains(&word),
+    }
+}
+
+/// Splits `text` into sentences, each including its terminating punctuation.
+fn sentences(text: &str) -> impl Iterator<Item = &str> {
+    let mut rest = text.trim_start();
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+
+        // A sentence ends at a terminator, including any closing quotes after
+        // it, that is followed by whitespace or the end of the text.
+        let mut end = rest.len();
+        let mut after_terminator = None;
+        for (i, c) in rest.char_indices() {
+            if c == '.' && is_abbreviation(rest, i) {
+                continue;
+            }
+            if matches!(c, '.' | '!' | '?' | '…' | '。' | '！' | '？') {
+                after_terminator = Some(i + c.len_utf8());
+            } else if let Some(at) = after_terminator {
+                if c.is_whitespace() {
+                    end = at;
+                    break;
+                }
+                after_terminator = "\"')”’".contains(c).then_some(i + c.len_utf8());
+            }
+        }
+        if let Some(at) = after_terminator {
+            end = end.min(at);
+        }
+
+        let sentence = &rest[..end];
+        rest = rest[end..].trim_start();
+        Some(sentence)
+    })
+}
+
+/// Returns the first `n` words of `text`, joined by spaces.
+fn first_words(text: &str, n: usize) -> String {
+    text.split_whitespace()
+        .take(n)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+impl Slugifier {
+    /// Generates a slug from the beginning of a longer text, e.g. the body of
+    /// a post without a title.
+    ///
+    /// Sentences end with `.`, `!`, `?`, or `…` followed by whitespace or the
+    /// end of the text, and words are separated by whitespace. A period after
+    /// a single letter or a title like `Dr` doesn't end a sentence, so a
+    /// sentence ending in one runs on into the next.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to take the excerpt from.
+    /// * `excerpt` - How much of the text to use.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::{Excerpt, Slugifier};
+    /// let body = "Today we ship v2.0. It is faster than ever! Read on for details.";
+    /// let slugifier = Slugifier::new();
+    /// assert_eq!(slugifier.slugify_excerpt(body, Excerpt::Words(5)), "today-we-ship-v2-0-it");
+    /// assert_eq!(slugifier.slugify_excerpt(body, Excerpt::Sentences(1)), "today-we-ship-v2-0");
+    /// assert_eq!(
+    ///     slugifier.slugify_excerpt(body, Excerpt::WholeSentences(10)),
+    ///     "today-we-ship-v2-0-it-is-faster-than-ever"
+    /// );
+    /// ```
+    pub fn slugify_excerpt(&self, text: &str, excerpt: Excerpt) -> String {
+        let excerpt = match excerpt {
+            Excerpt::Words(n) => first_words(text, n),
+            Excerpt::Sentences(n) => sentences(text).take(n).collect::<Vec<_>>().join(" "),
+            Excerpt::WholeSentences(n) => {
+                let mut words = 0;
+                let fitting: Vec<&str> = sentences(text)
+                    .take_while(|sentence| {
+                        words += sentence.split_whitespace().count();
+                        words <= n
+                    })
+                    .collect();
+                if fitting.is_empty() {
+                    first_words(text, n)
+                } else {
+                    fitting.join(" ")
+                }
+            }
+        };
+
+        self.slugify(&excerpt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sentences() {
+        let text = "  Hi there!  Version 1.5 is out… \"Really?\" Yes";
+        let split: Vec<_> = sentences(text).collect();
+        assert_eq!(
+            split,
+            ["Hi there!", "Version 1.5 is out…", "\"Really?\"", "Yes"]
+        );
+    }
+
+    #[test]
+    fn test_sentences_skip_abbreviations() {
+        let text = "Dr. Who is here. J. R. R. Tolkien wrote it, e.g. The Hobbit. Plan A.";
+        let split: Vec<_> = sentences(text).collect();
+        assert_eq!(
+            split,
+            [
+                "Dr. Who is here.",
+                "J. R. R. Tolkien wrote it, e.g. The Hobbit.",
+                "Plan A."
+            ]
+        );
+        assert_eq!(
+            Slugifier::new().slugify_excerpt("Dr. Who is here.", Excerpt::Sentences(1)),
+            "dr-who-is-here"
+        );
+    }
+
+    #[test]
+    fn test_slugify_excerpt() {
+        let slugifier = Slugifier::new();
+        let body = "A very long first sentence without any end in sight";
+        assert_eq!(
+            slugifier.slugify_excerpt(body, Excerpt::WholeSentences(3)),
+            "a-very-long"
+        );
+        assert_eq!(
+            slugifier.slugify_excerpt(body, Excerpt::Sentences(3)),
+            slugifier.slugify(body)
+        );
+        assert_eq!(slugifier.slugify_excerpt("", Excerpt::Words(3)), "");
+    }
+}
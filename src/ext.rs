@@ -0,0 +1,58 @@
+//! The `Slugify` extension trait for string types.
+
+use crate::Slugifier;
+
+/// Adds slugification methods to string types like `&str`, `String`, and
+/// `Cow<str>`.
+///
+/// # Example
+///
+/// ```
+/// use rslug::{Slugifier, Slugify};
+///
+/// assert_eq!("Hello World".slugify(), "hello-world");
+///
+/// let slugifier = Slugifier::new().separator("_");
+/// assert_eq!(String::from("Hello World").slugify_with(&slugifier), "hello_world");
+/// ```
+pub trait Slugify {
+    /// Generates a slug with the process-wide default slugifier.
+    fn slugify(&self) -> String;
+
+    /// Generates a slug with the configuration of `slugifier`.
+    ///
+    /// # Arguments
+    ///
+    /// * `slugifier` - The slugifier to use.
+    fn slugify_with(&self, slugifier: &Slugifier) -> String;
+}
+
+impl<T: AsRef<str> + ?Sized> Slugify for T {
+    fn slugify(&self) -> String {
+        crate::default().slugify(self.as_ref())
+    }
+
+    fn slugify_with(&self, slugifier: &Slugifier) -> String {
+        slugifier.slugify(self.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::*;
+
+    #[test]
+    fn test_string_types() {
+        assert_eq!("Hello World".slugify(), "hello-world");
+        assert_eq!(String::from("Hello World").slugify(), "hello-world");
+        assert_eq!(Cow::Borrowed("Hello World").slugify(), "hello-world");
+    }
+
+    #[test]
+    fn test_slugify_with() {
+        let slugifier = Slugifier::new().to_lowercase(false);
+        assert_eq!("Hello World".slugify_with(&slugifier), "Hello-World");
+    }
+}
@@ -0,0 +1,900 @@
+//! Helpers for filename sanitization rules that go beyond single characters.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use crate::{Error, Slugifier, chars, suffix};
+
+/// A target filesystem whose naming rules `sanitize_filename` should follow.
+///
+/// Selecting a profile with [`Slugifier::fs_profile`](crate::Slugifier::fs_profile)
+/// configures the illegal character set, reserved name handling, trailing dot
+/// stripping, and the maximum filename length in one call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FsProfile {
+    /// NTFS on Windows: no `<>:"/\|?*`, no reserved device names, no trailing dots.
+    Windows,
+    /// Linux and other POSIX filesystems: only `/` is illegal.
+    Posix,
+    /// FAT32 long filenames, which follow the same rules as Windows.
+    Fat32,
+    /// Apple's APFS: `/` is illegal and `:` is shown as `/` in the Finder.
+    Apfs,
+    /// exFAT, common on SD cards and USB drives, which follows the Windows rules.
+    Exfat,
+    /// Names that are valid on every profile above.
+    Portable,
+}
+
+/// A Unicode normalization form for sanitized filenames.
+///
+/// The same visible name can be encoded in several ways, for example `é` as a
+/// single code point or as `e` followed by a combining accent. Syncing files
+/// between systems that disagree on the form produces duplicate-looking names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Normalization {
+    /// Canonical composition, the form used by most systems and the web.
+    Nfc,
+    /// Canonical decomposition, the form HFS+ stores and macOS often produces.
+    Nfd,
+}
+
+/// The name used in place of a name that can't name a file, like one cut down
+/// to nothing.
+pub(crate) const PLACEHOLDER_NAME: &str = "_";
+
+/// Characters that are illegal on Windows, FAT32, and exFAT.
+const WINDOWS_ILLEGAL_CHARS: &[char] = &['/', '\\', ':', '*', '?', '"', '<', '>', '|'];
+
+impl FsProfile {
+    /// The characters that cannot appear in a filename on this filesystem.
+    pub(crate) fn illegal_chars(self) -> &'static [char] {
+        match self {
+            FsProfile::Windows | FsProfile::Fat32 | FsProfile::Exfat | FsProfile::Portable => {
+                WINDOWS_ILLEGAL_CHARS
+            }
+            FsProfile::Posix => &['/'],
+            FsProfile::Apfs => &['/', ':'],
+        }
+    }
+
+    /// Whether Windows device names like `CON` or `LPT1` are reserved.
+    pub(crate) fn has_reserved_names(self) -> bool {
+        !matches!(self, FsProfile::Posix | FsProfile::Apfs)
+    }
+
+    /// Whether trailing dots are silently dropped by the filesystem.
+    pub(crate) fn strips_trailing_dots(self) -> bool {
+        self.has_reserved_names()
+    }
+
+    /// The normalization form names should be stored in, if the filesystem has one.
+    pub(crate) fn normalization(self) -> Option<Normalization> {
+        match self {
+            FsProfile::Posix => None,
+            FsProfile::Apfs => Some(Normalization::Nfd),
+            _ => Some(Normalization::Nfc),
+        }
+    }
+
+    /// Whether names that differ only in case name the same file.
+    pub(crate) fn is_case_insensitive(self) -> bool {
+        !matches!(self, FsProfile::Posix)
+    }
+
+    /// The maximum filename length in bytes.
+    ///
+    /// The Windows family actually counts UTF-16 code units, so 255 bytes of
+    /// UTF-8 is always within their limit as well.
+    pub(crate) fn max_filename_bytes(self) -> usize {
+        255
+    }
+}
+
+impl Slugifier {
+    /// Sanitizes every component of a path while keeping its structure.
+    ///
+    /// Each normal component is passed through `sanitize_filename`, and
+    /// components that sanitize to nothing, or to `.` or `..`, are dropped.
+    /// Drive prefixes, the root directory, and `.`/`..` components are kept as
+    /// they are; use `try_sanitize_path` to reject `..` instead. If
+    /// `max_path_len` is set, the longest names are shortened until the path
+    /// fits.
+    ///
+    /// Paths in Windows syntax are recognized on every platform: drive
+    /// letters, UNC shares like `\\server\share`, and verbatim (`\\?\`) and
+    /// device (`\\.\`) prefixes are kept as they are, and the result uses
+    /// backslashes.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to sanitize.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use rslug::Slugifier;
+    ///
+    /// let slugifier = Slugifier::new();
+    /// let path = slugifier.sanitize_path(Path::new("/data/Q1 report?/notes*.txt"));
+    /// assert_eq!(path, Path::new("/data/Q1 report/notes.txt"));
+    /// ```
+    pub fn sanitize_path(&self, path: &Path) -> PathBuf {
+        if let Some(windows) = path.to_str().and_then(WindowsPath::parse) {
+            let mut parts: Vec<_> = windows
+                .parts()
+                .map(|part| self.sanitize_part(part))
+                .collect();
+            let max_len = self.max_path_len.filter(|_| !windows.is_verbatim);
+            return self.fit_path(&mut parts, max_len, |parts| {
+                let mut sanitized = windows.prefix.to_string();
+                if windows.has_root {
+                    sanitized.push('\\');
+                }
+                let names: Vec<&str> = parts.iter().filter_map(PathPart::as_str).collect();
+                sanitized.push_str(&names.join("\\"));
+                PathBuf::from(sanitized)
+            });
+        }
+
+        let mut parts: Vec<_> = path
+            .components()
+            .map(|component| match component {
+                Component::Normal(name) => self.sanitize_part(&name.to_string_lossy()),
+                other => PathPart::Kept(other.as_os_str().to_string_lossy().into_owned()),
+            })
+            .collect();
+        self.fit_path(&mut parts, self.max_path_len, |parts| {
+            parts.iter().filter_map(PathPart::as_str).collect()
+        })
+    }
+
+    /// Sanitizes one component of a path, keeping `.` and `..` as they are.
+    fn sanitize_part(&self, part: &str) -> PathPart {
+        match part {
+            "." | ".." => PathPart::Kept(part.to_string()),
+            _ => PathPart::Name(self.sanitize_component(part)),
+        }
+    }
+
+    /// Sanitizes a name of a path, which is dropped if it is empty.
+    ///
+    /// A name like ".. " is only `..` once it is sanitized, so names that
+    /// sanitize to `.` or `..` are dropped too, rather than becoming a
+    /// traversal the input didn't have.
+    fn sanitize_component(&self, name: &str) -> String {
+        let sanitized = self.sanitize_name(name);
+        if matches!(sanitized.as_str(), "." | "..") {
+            String::new()
+        } else {
+            sanitized
+        }
+    }
+
+    /// Sanitizes a path like `sanitize_path`, but rejects parent directory components.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::PathTraversal`] if the sanitized path contains a `..`
+    /// component.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use rslug::Slugifier;
+    ///
+    /// let slugifier = Slugifier::new();
+    /// assert!(slugifier.try_sanitize_path(Path::new("uploads/../etc/passwd")).is_err());
+    /// ```
+    pub fn try_sanitize_path(&self, path: &Path) -> Result<PathBuf, Error> {
+        let sanitized = self.sanitize_path(path);
+        // Windows paths are built with backslashes, which aren't separators
+        // on every platform, so the components are split on both.
+        let has_parent_dir = sanitized
+            .to_string_lossy()
+            .split(['/', '\\'])
+            .any(|component| component == "..");
+        if has_parent_dir {
+            return Err(Error::PathTraversal {
+                path: path.to_path_buf(),
+            });
+        }
+
+        Ok(sanitized)
+    }
+
+    /// Sanitizes the name of a zip or tar entry, so that extracting it can't
+    /// write outside the target directory.
+    ///
+    /// Backslashes are read as separators and the result always uses `/`.
+    /// Drive letters, leading slashes of absolute and UNC paths, and `.` and
+    /// `..` components are removed, and each remaining component is passed
+    /// through `sanitize_filename`, dropping the ones that sanitize to nothing.
+    /// A trailing `/`, which marks a directory entry, is kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The entry name, as stored in the archive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    ///
+    /// let slugifier = Slugifier::new();
+    /// assert_eq!(slugifier.sanitize_archive_entry("../../etc/passwd"), "etc/passwd");
+    /// assert_eq!(slugifier.sanitize_archive_entry("C:\\Users\\me\\notes?.txt"), "Users/me/notes.txt");
+    /// assert_eq!(slugifier.sanitize_archive_entry("/docs/Q1*/"), "docs/Q1/");
+    /// ```
+    pub fn sanitize_archive_entry(&self, name: &str) -> String {
+        let name = name.replace('\\', "/");
+        let is_dir = name.ends_with('/');
+
+        let bytes = name.as_bytes();
+        let rest = match bytes {
+            [drive, b':', ..] if drive.is_ascii_alphabetic() => &name[2..],
+            _ => &name,
+        };
+
+        let mut sanitized = rest
+            .split('/')
+            .map(|component| self.sanitize_component(component))
+            .filter(|component| !component.is_empty())
+            .collect::<Vec<_>>()
+            .join("/");
+        if is_dir && !sanitized.is_empty() {
+            sanitized.push('/');
+        }
+        sanitized
+    }
+
+    /// Returns a path in `dir` for a sanitized version of `desired` that doesn't exist yet.
+    ///
+    /// The name is first passed through `sanitize_filename`, and is `_` if
+    /// nothing of it is left. If it is already taken, the `unique_suffix` is
+    /// inserted before the extension with an increasing counter until a free
+    /// name is found, so `report.pdf` becomes `report (1).pdf`, then
+    /// `report (2).pdf`, and so on. Suffixed names still respect
+    /// `max_filename_bytes`.
+    ///
+    /// Note that the name may be taken by another process between this check and
+    /// the moment the file is created.
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - The directory the file will be created in.
+    /// * `desired` - The preferred filename.
+    pub fn unique_filename(&self, dir: &Path, desired: &str) -> PathBuf {
+        let existing: HashSet<String> = fs::read_dir(dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|entry| self.collision_key(&entry.file_name().to_string_lossy()))
+            .collect();
+        let is_taken =
+            |name: &str| existing.contains(&self.collision_key(name)) || dir.join(name).exists();
+
+        let mut name = self.sanitize_filename(desired);
+        if name.is_empty() {
+            name = PLACEHOLDER_NAME.to_string();
+        }
+        if !is_taken(&name) {
+            return dir.join(name);
+        }
+
+        (1..)
+            .map(|n| self.suffixed_filename(&name, n))
+            .find(|candidate| !is_taken(candidate))
+            .map(|candidate| dir.join(candidate))
+            .expect("an unbounded counter always finds a free name")
+    }
+
+    /// Makes a list of proposed filenames unique, for a filesystem that may
+    /// not tell names apart by case or Unicode normalization.
+    ///
+    /// Names collide if they are equal once normalized to NFC, so `é` as one
+    /// code point and as `e` with a combining accent collide, and once
+    /// lowercased too if `case_insensitive_collisions` is enabled. The first
+    /// of colliding names is kept, and the `unique_suffix` is inserted with an
+    /// increasing counter before the extension of the others, skipping names
+    /// in the list. The names are otherwise kept as they are, so the same list
+    /// always gets the same result.
+    ///
+    /// # Arguments
+    ///
+    /// * `names` - The proposed filenames, in order of precedence.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    ///
+    /// let slugifier = Slugifier::new().case_insensitive_collisions(true);
+    /// let names = slugifier.disambiguate_filenames(&["README.md", "readme.md", "readme (1).md"]);
+    /// assert_eq!(names, ["README.md", "readme (2).md", "readme (1).md"]);
+    /// ```
+    pub fn disambiguate_filenames<S: AsRef<str>>(&self, names: &[S]) -> Vec<String> {
+        let mut taken: HashSet<String> = names
+            .iter()
+            .map(|name| self.collision_key(name.as_ref()))
+            .collect();
+        let mut kept = HashSet::new();
+
+        names
+            .iter()
+            .map(|name| {
+                let name = name.as_ref();
+                if kept.insert(self.collision_key(name)) {
+                    return name.to_string();
+                }
+                let unique = (1..)
+                    .map(|n| self.suffixed_filename(name, n))
+                    .find(|candidate| !taken.contains(&self.collision_key(candidate)))
+                    .expect("an unbounded counter always finds a free name");
+                taken.insert(self.collision_key(&unique));
+                kept.insert(self.collision_key(&unique));
+                unique
+            })
+            .collect()
+    }
+
+    /// Returns the key under which `name` collides with other filenames: its
+    /// NFC form, lowercased if `case_insensitive_collisions` is enabled.
+    pub(crate) fn collision_key(&self, name: &str) -> String {
+        use unicode_normalization::UnicodeNormalization;
+
+        let name: String = name.nfc().collect();
+        if self.case_insensitive_collisions {
+            name.to_lowercase()
+        } else {
+            name
+        }
+    }
+
+    /// Inserts the `unique_suffix` with counter `n` before the extension of
+    /// `name`, shortening the stem to respect `max_filename_bytes`.
+    pub(crate) fn suffixed_filename(&self, name: &str, n: usize) -> String {
+        let suffix = suffix::fill_counter(&self.unique_suffix, &n.to_string());
+        let base = match self.max_filename_bytes {
+            Some(max_bytes) => truncate_preserving_extension(
+                name,
+                max_bytes.saturating_sub(suffix.len()),
+                self.trim_trailing_dots,
+            ),
+            None => name.to_string(),
+        };
+        let (stem, extension) = split_extension(&base);
+        format!("{stem}{suffix}{extension}")
+    }
+
+    /// Builds a path from `parts`, shortening the longest names until it is
+    /// at most `max_len` UTF-16 code units long, or no name can be shortened.
+    ///
+    /// Shortened names are sanitized again, since cutting a name can leave it
+    /// ending in a dot or space, or make it a reserved name.
+    fn fit_path(
+        &self,
+        parts: &mut [PathPart],
+        max_len: Option<usize>,
+        build: impl Fn(&[PathPart]) -> PathBuf,
+    ) -> PathBuf {
+        loop {
+            let path = build(parts);
+            let Some(max_len) = max_len else {
+                return path;
+            };
+            let len = path.as_os_str().to_string_lossy().encode_utf16().count();
+            if len <= max_len {
+                return path;
+            }
+
+            let longest = parts
+                .iter_mut()
+                .filter_map(|part| match part {
+                    PathPart::Name(name) => Some(name),
+                    PathPart::Kept(_) => None,
+                })
+                .max_by_key(|name| name.encode_utf16().count());
+            let Some(name) = longest else {
+                return path;
+            };
+            // Each code unit over the limit is at least one byte of the name.
+            let mut max_bytes = name.len().saturating_sub(len - max_len);
+            let shortened = loop {
+                let shortened = self.sanitize_component(&truncate_preserving_extension(
+                    name,
+                    max_bytes,
+                    self.trim_trailing_dots,
+                ));
+                // Sanitizing can lengthen the name again, like a reserved name
+                // that gets a prefix, so it is cut shorter.
+                if shortened.len() < name.len() || max_bytes == 0 {
+                    break shortened;
+                }
+                max_bytes -= 1;
+            };
+            if shortened.is_empty() || shortened.len() >= name.len() {
+                return path;
+            }
+            *name = shortened;
+        }
+    }
+}
+
+/// A component of a path being sanitized.
+enum PathPart {
+    /// A prefix, root, `.`, or `..`, which is kept as it is.
+    Kept(String),
+    /// A sanitized name, which is dropped if it is empty.
+    Name(String),
+}
+
+impl PathPart {
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            PathPart::Kept(part) => Some(part),
+            PathPart::Name(name) => (!name.is_empty()).then_some(name),
+        }
+    }
+}
+
+/// A path in Windows syntax, split into its prefix and the rest.
+struct WindowsPath<'a> {
+    /// The drive, UNC share, or verbatim or device prefix.
+    prefix: &'a str,
+    /// Whether the path is absolute, i.e. a separator follows the prefix.
+    has_root: bool,
+    /// The components after the prefix, separated by `\` or `/`.
+    rest: &'a str,
+    /// Whether the path has the `\\?\` prefix, which turns off the length
+    /// limit and path normalization.
+    is_verbatim: bool,
+}
+
+impl<'a> WindowsPath<'a> {
+    /// Parses `path` if it starts with a prefix only Windows paths have.
+    fn parse(path: &'a str) -> Option<Self> {
+        let is_separator = |c: char| c == '\\' || c == '/';
+        let is_verbatim = path.starts_with(r"\\?\");
+
+        let prefix_len = if is_verbatim || path.starts_with(r"\\.\") {
+            // Verbatim and device paths continue with `UNC\server\share`, a
+            // drive, or a device name, and only use backslashes.
+            let rest = &path[4..];
+            let is_unc = rest
+                .get(..4)
+                .is_some_and(|unc| unc.eq_ignore_ascii_case(r"UNC\"));
+            4 + components_len(rest, if is_unc { 3 } else { 1 }, |c| c == '\\')
+        } else if path.starts_with(is_separator) && path[1..].starts_with(is_separator) {
+            2 + components_len(&path[2..], 2, is_separator)
+        } else if let [drive, b':', ..] = path.as_bytes()
+            && drive.is_ascii_alphabetic()
+        {
+            2
+        } else {
+            return None;
+        };
+
+        let (prefix, rest) = path.split_at(prefix_len);
+        Some(Self {
+            prefix,
+            has_root: rest.starts_with(is_separator),
+            rest,
+            is_verbatim,
+        })
+    }
+
+    /// Returns the components after the prefix.
+    fn parts(&self) -> impl Iterator<Item = &'a str> {
+        self.rest.split(['\\', '/']).filter(|part| !part.is_empty())
+    }
+}
+
+/// Returns the length of the first `count` components of `text`, separated
+/// by characters matching `is_separator`, without the separator after them.
+fn components_len(text: &str, count: usize, is_separator: impl Fn(char) -> bool) -> usize {
+    text.match_indices(is_separator)
+        .nth(count - 1)
+        .map_or(text.len(), |(index, _)| index)
+}
+
+/// Returns `true` if `name` is reserved as a device name on Windows.
+///
+/// Windows reserves `CON`, `PRN`, `AUX`, `NUL`, `COM1`–`COM9`, and `LPT1`–`LPT9`
+/// case-insensitively, with or without an extension, so `nul.txt` and
+/// `Com1.tar.gz` are just as unusable as `NUL`.
+pub(crate) fn is_windows_reserved(name: &str) -> bool {
+    let stem = name.split('.').next().unwrap_or(name).trim_end();
+
+    if ["CON", "PRN", "AUX", "NUL", "CONIN$", "CONOUT$"]
+        .iter()
+        .any(|reserved| stem.eq_ignore_ascii_case(reserved))
+    {
+        return true;
+    }
+
+    let mut chars = stem.chars();
+    let prefix: String = chars.by_ref().take(3).collect();
+    let digit = chars.next();
+
+    (prefix.eq_ignore_ascii_case("COM") || prefix.eq_ignore_ascii_case("LPT"))
+        && matches!(digit, Some('1'..='9' | '¹' | '²' | '³'))
+        && chars.next().is_none()
+}
+
+/// Truncates `name` to at most `max_bytes` bytes, cutting the stem rather than
+/// the extension and never splitting a UTF-8 sequence, or a grapheme cluster
+/// with the `grapheme-clusters` feature.
+///
+/// If the extension alone doesn't fit, the whole name is hard-truncated.
+/// Spaces left at the end of the cut part are trimmed, and dots too if
+/// `trim_dots`, since Windows drops them. A name cut down to nothing, or to
+/// `.` or `..`, becomes the [`PLACEHOLDER_NAME`].
+pub(crate) fn truncate_preserving_extension(
+    name: &str,
+    max_bytes: usize,
+    trim_dots: bool,
+) -> String {
+    if name.len() <= max_bytes {
+        return name.to_string();
+    }
+
+    let (stem, extension) = split_extension(name);
+    let trim = |part: &str| {
+        if trim_dots {
+            part.trim_end_matches(['.', ' '])
+        } else {
+            part.trim_end()
+        }
+        .to_string()
+    };
+
+    let truncated = if extension.len() >= max_bytes {
+        trim(&name[..chars::floor_grapheme_boundary(name, max_bytes)])
+    } else {
+        let stem = &stem[..chars::floor_grapheme_boundary(stem, max_bytes - extension.len())];
+        // The extension can be a lone dot, which is trimmed as well.
+        trim(&format!("{}{extension}", trim(stem)))
+    };
+    if is_placeholder_needed(&truncated) {
+        PLACEHOLDER_NAME.to_string()
+    } else {
+        truncated
+    }
+}
+
+/// Returns `true` for names that can't name a file: the empty name and the
+/// `.` and `..` directory entries.
+pub(crate) fn is_placeholder_needed(name: &str) -> bool {
+    matches!(name, "" | "." | "..")
+}
+
+/// Splits a filename into its stem and extension (including the dot).
+///
+/// Dotfiles like `.gitignore` are treated as having no extension.
+pub(crate) fn split_extension(name: &str) -> (&str, &str) {
+    match name.rfind('.') {
+        Some(dot) if dot > 0 => name.split_at(dot),
+        _ => (name, ""),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scratch::scratch_dir;
+
+    #[test]
+    fn test_reserved_names() {
+        for name in [
+            "CON",
+            "con",
+            "Nul.txt",
+            "aux.tar.gz",
+            "COM1",
+            "lpt9.log",
+            "PRN ",
+        ] {
+            assert!(is_windows_reserved(name), "{name} should be reserved");
+        }
+    }
+
+    #[test]
+    fn test_unreserved_names() {
+        for name in [
+            "CONFIG",
+            "console.log",
+            "COM0",
+            "COM10",
+            "LPT",
+            "my NUL.txt",
+            "",
+        ] {
+            assert!(!is_windows_reserved(name), "{name} should not be reserved");
+        }
+    }
+
+    #[test]
+    fn test_sanitize_path_keeps_structure() {
+        let slugifier = Slugifier::new();
+        assert_eq!(
+            slugifier.sanitize_path(Path::new("./a|b/../c?d/con.txt")),
+            Path::new("./ab/../cd/_con.txt")
+        );
+        assert_eq!(
+            slugifier.sanitize_path(Path::new("docs/???/readme")),
+            Path::new("docs/readme")
+        );
+    }
+
+    #[test]
+    fn test_sanitize_windows_paths() {
+        let slugifier = Slugifier::new();
+        for (path, expected) in [
+            (r"C:\Users\a?b\notes*.txt", r"C:\Users\ab\notes.txt"),
+            ("C:/Users/con.txt", r"C:\Users\_con.txt"),
+            (r"C:relative\x|y", r"C:relative\xy"),
+            (r"\\?\C:\long\na:me", r"\\?\C:\long\name"),
+            (r"\\?\UNC\server\share\a<b", r"\\?\UNC\server\share\ab"),
+            (r"\\.\PhysicalDrive0", r"\\.\PhysicalDrive0"),
+            (
+                r"\\server\share\dir?\..\file",
+                r"\\server\share\dir\..\file",
+            ),
+            ("//server/share/x", r"//server/share\x"),
+        ] {
+            assert_eq!(
+                slugifier.sanitize_path(Path::new(path)),
+                Path::new(expected)
+            );
+        }
+        assert!(
+            slugifier
+                .try_sanitize_path(Path::new(r"C:\safe\..\etc"))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_sanitize_path_fits_max_path_len() {
+        let slugifier = Slugifier::new().max_path_len(259);
+        let long_name = format!("{}.txt", "a".repeat(200));
+        let path = format!(r"C:\{long_name}\{long_name}");
+        let sanitized = slugifier.sanitize_path(Path::new(&path));
+        let sanitized = sanitized.to_str().unwrap();
+        assert_eq!(sanitized.len(), 259);
+        assert!(sanitized.ends_with(".txt"));
+
+        let verbatim = format!(r"\\?\{path}");
+        assert_eq!(
+            slugifier.sanitize_path(Path::new(&verbatim)),
+            Path::new(&verbatim)
+        );
+
+        let slugifier = Slugifier::new().max_path_len(12);
+        assert_eq!(
+            slugifier.sanitize_path(Path::new("/data/report.pdf")),
+            Path::new("/data/re.pdf")
+        );
+
+        // Cutting "abc. def" to "abc. " leaves a name Windows can't store.
+        let slugifier = Slugifier::new()
+            .fs_profile(FsProfile::Windows)
+            .max_path_len(8);
+        assert_eq!(
+            slugifier.sanitize_path(Path::new(r"C:\abc. def")),
+            Path::new(r"C:\abc")
+        );
+        let slugifier = Slugifier::new()
+            .fs_profile(FsProfile::Windows)
+            .max_path_len(11);
+        assert_eq!(
+            slugifier.sanitize_path(Path::new(r"C:\com12.txt")),
+            Path::new(r"C:\com.txt")
+        );
+    }
+
+    #[test]
+    fn test_try_sanitize_path_rejects_traversal() {
+        let slugifier = Slugifier::new();
+        assert_eq!(
+            slugifier.try_sanitize_path(Path::new("../secret")),
+            Err(Error::PathTraversal {
+                path: PathBuf::from("../secret")
+            })
+        );
+        assert_eq!(
+            slugifier.try_sanitize_path(Path::new("safe/name?.txt")),
+            Ok(PathBuf::from("safe/name.txt"))
+        );
+    }
+
+    #[test]
+    fn test_sanitized_names_never_become_parent_dirs() {
+        let slugifier = Slugifier::new().fs_profile(FsProfile::Posix);
+        for path in ["uploads/.. /etc/passwd", "uploads/..\t/etc/passwd"] {
+            assert_eq!(
+                slugifier.try_sanitize_path(Path::new(path)),
+                Ok(PathBuf::from("uploads/etc/passwd"))
+            );
+        }
+        assert_eq!(
+            Slugifier::new().sanitize_path(Path::new(r"C:\a\.. \b")),
+            Path::new(r"C:\a\b")
+        );
+    }
+
+    #[test]
+    fn test_sanitize_archive_entry() {
+        let slugifier = Slugifier::new();
+        for (name, expected) in [
+            ("docs/readme.md", "docs/readme.md"),
+            ("../../../etc/passwd", "etc/passwd"),
+            ("/abs/./path", "abs/path"),
+            ("c:/windows/system.ini", "windows/system.ini"),
+            ("\\\\server\\share\\..\\x", "server/share/x"),
+            ("a/..\\../b", "a/b"),
+            ("dir/sub/", "dir/sub/"),
+            ("con/aux.txt", "_con/_aux.txt"),
+            ("../", ""),
+            ("??/...", ""),
+        ] {
+            assert_eq!(slugifier.sanitize_archive_entry(name), expected, "{name}");
+        }
+
+        let posix = Slugifier::new().fs_profile(FsProfile::Posix);
+        assert_eq!(posix.sanitize_archive_entry(".. /.. /x"), "x");
+        assert_eq!(posix.sanitize_archive_entry("a\\b:c"), "a/b:c");
+    }
+
+    #[test]
+    fn test_unique_filename() {
+        let dir = scratch_dir("unique");
+        let slugifier = Slugifier::new();
+
+        assert_eq!(
+            slugifier.unique_filename(&dir, "report?.pdf"),
+            dir.join("report.pdf")
+        );
+
+        fs::write(dir.join("report.pdf"), "").unwrap();
+        fs::write(dir.join("report (1).pdf"), "").unwrap();
+        assert_eq!(
+            slugifier.unique_filename(&dir, "report.pdf"),
+            dir.join("report (2).pdf")
+        );
+
+        let slugifier = Slugifier::new().unique_suffix("_v{n}");
+        assert_eq!(
+            slugifier.unique_filename(&dir, "report.pdf"),
+            dir.join("report_v1.pdf")
+        );
+
+        let slugifier = Slugifier::new().unique_suffix("_copy");
+        assert_eq!(
+            slugifier.unique_filename(&dir, "report.pdf"),
+            dir.join("report_copy1.pdf")
+        );
+
+        let slugifier = Slugifier::new();
+        assert_eq!(slugifier.unique_filename(&dir, "???"), dir.join("_"));
+        fs::write(dir.join("_"), "").unwrap();
+        assert_eq!(slugifier.unique_filename(&dir, "???"), dir.join("_ (1)"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_unique_filename_case_insensitive() {
+        let dir = scratch_dir("unique-case");
+        fs::write(dir.join("README.md"), "").unwrap();
+
+        let slugifier = Slugifier::new().case_insensitive_collisions(true);
+        assert_eq!(
+            slugifier.unique_filename(&dir, "readme.md"),
+            dir.join("readme (1).md")
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_disambiguate_filenames() {
+        let slugifier = Slugifier::new().case_insensitive_collisions(false);
+        let names = ["Caf\u{e9}.txt", "Cafe\u{301}.txt", "CAF\u{c9}.txt", "notes"];
+        assert_eq!(
+            slugifier.disambiguate_filenames(&names),
+            [
+                "Caf\u{e9}.txt",
+                "Cafe\u{301} (1).txt",
+                "CAF\u{c9}.txt",
+                "notes"
+            ]
+        );
+
+        let slugifier = slugifier
+            .case_insensitive_collisions(true)
+            .unique_suffix("_{n}");
+        assert_eq!(
+            slugifier.disambiguate_filenames(&names),
+            [
+                "Caf\u{e9}.txt",
+                "Cafe\u{301}_1.txt",
+                "CAF\u{c9}_2.txt",
+                "notes"
+            ]
+        );
+        assert!(slugifier.disambiguate_filenames::<&str>(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_disambiguate_filenames_suffix_without_counter() {
+        let slugifier = Slugifier::new().unique_suffix("_copy");
+        assert_eq!(
+            slugifier.disambiguate_filenames(&["a.txt", "a.txt", "a.txt"]),
+            ["a.txt", "a_copy1.txt", "a_copy2.txt"]
+        );
+    }
+
+    #[test]
+    fn test_truncate_preserving_extension() {
+        assert_eq!(
+            truncate_preserving_extension("short.txt", 255, true),
+            "short.txt"
+        );
+        assert_eq!(
+            truncate_preserving_extension("abcdefgh.txt", 8, true),
+            "abcd.txt"
+        );
+        assert_eq!(
+            truncate_preserving_extension("abcd efgh.txt", 9, true),
+            "abcd.txt"
+        );
+        assert_eq!(
+            truncate_preserving_extension("noextension", 5, true),
+            "noext"
+        );
+        assert_eq!(
+            truncate_preserving_extension("a.verylongext", 5, true),
+            "a.ver"
+        );
+        assert_eq!(truncate_preserving_extension("a.verylongext", 2, true), "a");
+        assert_eq!(
+            truncate_preserving_extension("ab. cd.txt", 8, true),
+            "ab.txt"
+        );
+        assert_eq!(
+            truncate_preserving_extension("abc.. .x......", 6, true),
+            "abc"
+        );
+        assert_eq!(
+            truncate_preserving_extension("abc.. .x......", 6, false),
+            "abc..."
+        );
+        assert_eq!(truncate_preserving_extension("..x", 2, true), "_");
+        assert_eq!(truncate_preserving_extension("..x", 2, false), "_");
+    }
+
+    #[test]
+    fn test_truncate_never_splits_utf8() {
+        // Each "é" is two bytes, so only two of them fit before the extension.
+        assert_eq!(truncate_preserving_extension("ééé.md", 8, true), "éé.md");
+        assert_eq!(truncate_preserving_extension("日本語", 4, true), "日");
+    }
+
+    #[test]
+    #[cfg(feature = "grapheme-clusters")]
+    fn test_truncate_never_splits_graphemes() {
+        assert_eq!(
+            truncate_preserving_extension("flag\u{1F1EB}\u{1F1F7}.txt", 14, true),
+            "flag.txt"
+        );
+        assert_eq!(
+            truncate_preserving_extension("cafe\u{301}.md", 8, true),
+            "caf.md"
+        );
+    }
+}
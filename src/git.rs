@@ -0,0 +1,107 @@
+//! Turning arbitrary text into valid git reference names.
+
+use crate::Slugifier;
+
+/// Characters git never allows in a reference name, besides control characters.
+const FORBIDDEN_REF_CHARS: &[char] = &[' ', '~', '^', ':', '?', '*', '[', '\\'];
+
+impl Slugifier {
+    /// Sanitizes text into a valid git branch or tag name.
+    ///
+    /// Each `/`-separated component is slugified on its own, so hierarchical
+    /// names like `feat/...` keep their structure. The result then follows the
+    /// rules of `git check-ref-format`: no `..` or `@{`, no component starting
+    /// with `.` or ending with `.lock`, no control characters or any of
+    /// `` ~^:?*[\`` and space, no empty components, and no trailing `.`.
+    /// An empty string is returned if nothing valid remains.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The text to derive the reference name from.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    ///
+    /// let slugifier = Slugifier::new();
+    /// assert_eq!(
+    ///     slugifier.sanitize_git_ref("feat/Add user login!"),
+    ///     "feat/add-user-login"
+    /// );
+    /// ```
+    pub fn sanitize_git_ref(&self, name: &str) -> String {
+        let components: Vec<String> = name
+            .split('/')
+            .map(|component| sanitize_ref_component(&self.slugify(component)))
+            .filter(|component| !component.is_empty())
+            .collect();
+
+        let sanitized = components.join("/");
+        if sanitized == "@" {
+            return String::new();
+        }
+
+        sanitized
+    }
+}
+
+/// Applies the per-component `check-ref-format` rules to an already slugified component.
+fn sanitize_ref_component(component: &str) -> String {
+    let mut component: String = component
+        .chars()
+        .filter(|&c| !c.is_control() && !FORBIDDEN_REF_CHARS.contains(&c))
+        .collect();
+
+    while component.contains("..") {
+        component = component.replace("..", ".");
+    }
+    while component.contains("@{") {
+        component = component.replace("@{", "@");
+    }
+
+    loop {
+        let trimmed = component
+            .trim_start_matches('.')
+            .trim_end_matches('.')
+            .trim_end_matches(".lock");
+        if trimmed.len() == component.len() {
+            return component;
+        }
+        component = trimmed.to_string();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_git_ref() {
+        let slugifier = Slugifier::new();
+        assert_eq!(
+            slugifier.sanitize_git_ref("fix/JIRA-42: Crash on start?"),
+            "fix/jira-42-crash-on-start"
+        );
+        assert_eq!(slugifier.sanitize_git_ref("//release//v2//"), "release/v2");
+        assert_eq!(slugifier.sanitize_git_ref("???"), "");
+    }
+
+    #[test]
+    fn test_sanitize_git_ref_with_dots() {
+        let slugifier = Slugifier::new().allowed_chars(".@{");
+        assert_eq!(slugifier.sanitize_git_ref("release/v1..2"), "release/v1-2");
+        assert_eq!(
+            slugifier.sanitize_git_ref("topic/config.lock"),
+            "topic/config"
+        );
+        assert_eq!(slugifier.sanitize_git_ref("a@{b"), "a-b");
+    }
+
+    #[test]
+    fn test_sanitize_ref_component() {
+        assert_eq!(sanitize_ref_component(".hidden.lock."), "hidden");
+        assert_eq!(sanitize_ref_component("a..b"), "a.b");
+        assert_eq!(sanitize_ref_component("x~y^z"), "xyz");
+    }
+}
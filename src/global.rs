@@ -0,0 +1,140 @@
+//! The process-wide default slugifier.
+
+use std::sync::OnceLock;
+
+use crate::{Error, Preset, Slugifier};
+
+static DEFAULT: OnceLock<Slugifier> = OnceLock::new();
+
+/// Sets the configuration used by the `slugify!` and `slugify_ascii!` macros
+/// and the [`Slugify`](crate::Slugify) extension trait.
+///
+/// The default can only be set once, before it is first used, so it is best
+/// set at the start of `main`.
+///
+/// # Errors
+///
+/// Returns [`Error::DefaultAlreadySet`] if the default was already set or used.
+///
+/// # Example
+///
+/// ```
+/// use rslug::{Slugifier, slugify};
+/// rslug::set_default(Slugifier::new().separator("_")).unwrap();
+/// assert_eq!(slugify!("Hello World"), "hello_world");
+/// assert!(rslug::set_default(Slugifier::new()).is_err());
+/// ```
+pub fn set_default(slugifier: Slugifier) -> Result<(), Error> {
+    DEFAULT.set(slugifier).map_err(|_| Error::DefaultAlreadySet)
+}
+
+/// Returns the process-wide default slugifier, which is `Slugifier::new()`
+/// unless another one was set with [`set_default`].
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(rslug::default().slugify("Hello World"), "hello-world");
+/// ```
+pub fn default() -> &'static Slugifier {
+    DEFAULT.get_or_init(Slugifier::new)
+}
+
+/// Checks whether `slug` is a valid slug for the process-wide default
+/// slugifier, like [`Slugifier::validate`].
+///
+/// This is the check behind a custom validator for slug fields of forms and
+/// API payloads. Set the default with [`set_default`] to validate against
+/// another configuration, or use [`validate_preset_slug`] for a preset.
+///
+/// There is no `validator` feature yet, so the error is an [`Error`] rather
+/// than a `validator::ValidationError`, and a custom validator has to convert
+/// it:
+///
+/// ```ignore
+/// fn validate_slug(slug: &str) -> Result<(), validator::ValidationError> {
+///     rslug::validate_slug(slug).map_err(|_| validator::ValidationError::new("slug"))
+/// }
+///
+/// #[derive(validator::Validate)]
+/// struct Post {
+///     #[validate(custom(function = "validate_slug"))]
+///     slug: String,
+/// }
+/// ```
+///
+/// # Errors
+///
+/// Returns the first rule the slug violates, like [`Slugifier::validate`].
+///
+/// # Example
+///
+/// ```
+/// use rslug::Error;
+///
+/// assert_eq!(rslug::validate_slug("hello-world"), Ok(()));
+/// assert_eq!(rslug::validate_slug(""), Err(Error::Empty));
+/// assert_eq!(rslug::validate_slug("-hello"), Err(Error::NotNormalized));
+/// ```
+pub fn validate_slug(slug: &str) -> Result<(), Error> {
+    default().validate(slug)
+}
+
+/// Checks whether `slug` is a valid slug for `preset`, like validating it with
+/// `Slugifier::new().preset(preset)`.
+///
+/// Unlike [`validate_slug`], this doesn't depend on the process-wide default,
+/// so a custom validator can check each field against its own preset.
+///
+/// # Errors
+///
+/// Returns the first rule the slug violates, like [`Slugifier::validate`].
+///
+/// # Example
+///
+/// ```
+/// use rslug::{Error, Preset};
+///
+/// assert_eq!(rslug::validate_preset_slug("my-bucket", Preset::S3Bucket), Ok(()));
+/// assert_eq!(
+///     rslug::validate_preset_slug("ab", Preset::S3Bucket),
+///     Err(Error::TooShort { len: 2, min: 3 })
+/// );
+/// ```
+pub fn validate_preset_slug(slug: &str, preset: Preset) -> Result<(), Error> {
+    Slugifier::new().preset(preset).validate(slug)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_is_fixed_once_used() {
+        assert_eq!(default().slugify("Hello World"), "hello-world");
+        assert_eq!(
+            set_default(Slugifier::new().separator("_")),
+            Err(Error::DefaultAlreadySet)
+        );
+        assert_eq!(default().slugify("Hello World"), "hello-world");
+    }
+
+    #[test]
+    fn test_validate_slug_uses_default() {
+        let field = String::from("hello-world");
+        assert_eq!(validate_slug(&field), Ok(()));
+        assert_eq!(
+            validate_slug("Hello"),
+            Err(Error::InvalidChar { ch: 'H', index: 0 })
+        );
+    }
+
+    #[test]
+    fn test_validate_preset_slug_ignores_default() {
+        assert_eq!(validate_preset_slug("MY_VAR", Preset::EnvVar), Ok(()));
+        assert_eq!(
+            validate_preset_slug("my-var", Preset::EnvVar),
+            Err(Error::InvalidChar { ch: '-', index: 2 })
+        );
+    }
+}
@@ -0,0 +1,200 @@
+//! Hashtags, a sibling transformation to slugs.
+//!
+//! The major platforms (X/Twitter, Instagram, Facebook, TikTok, LinkedIn)
+//! agree on the basic rules: a hashtag ends at the first space or punctuation
+//! character, may contain letters of any script, digits, and underscores, and
+//! isn't linked if it consists of digits only.
+
+use crate::{chars, translit};
+
+/// A configurable hashtag generator.
+///
+/// Use the builder pattern to create an instance with custom settings.
+///
+/// # Example
+///
+/// ```
+/// use rslug::Hashtagger;
+///
+/// let hashtagger = Hashtagger::new().max_len(12);
+/// assert_eq!(hashtagger.hashtag("my blog post").as_deref(), Some("#MyBlogPost"));
+/// assert_eq!(hashtagger.hashtag("rust is awesome").as_deref(), Some("#RustIs"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Hashtagger {
+    camel_case: bool,
+    ascii: bool,
+    max_len: Option<usize>,
+}
+
+impl Default for Hashtagger {
+    /// Creates a default `Hashtagger` instance.
+    /// Default camel case: `true`
+    /// Default ASCII only: `false`
+    fn default() -> Self {
+        Self {
+            camel_case: true,
+            ascii: false,
+            max_len: None,
+        }
+    }
+}
+
+impl Hashtagger {
+    /// Creates a new `Hashtagger` with default settings.
+    ///
+    /// This is an alias for `Hashtagger::default()`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether the first letter of each word is capitalized, which keeps
+    /// long hashtags readable. Otherwise the words are lowercased.
+    ///
+    /// # Arguments
+    ///
+    /// * `camel_case` - A boolean indicating if words are capitalized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Hashtagger;
+    /// let hashtagger = Hashtagger::new().camel_case(false);
+    /// assert_eq!(hashtagger.hashtag("Throwback Thursday").as_deref(), Some("#throwbackthursday"));
+    /// ```
+    pub fn camel_case(mut self, camel_case: bool) -> Self {
+        self.camel_case = camel_case;
+        self
+    }
+
+    /// Sets whether letters outside of ASCII are transliterated, for platforms
+    /// or audiences that handle them poorly.
+    ///
+    /// # Arguments
+    ///
+    /// * `ascii` - A boolean indicating if the hashtag is limited to ASCII.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Hashtagger;
+    /// assert_eq!(Hashtagger::new().hashtag("café olé").as_deref(), Some("#CaféOlé"));
+    /// let hashtagger = Hashtagger::new().ascii(true);
+    /// assert_eq!(hashtagger.hashtag("café olé").as_deref(), Some("#CafeOle"));
+    /// ```
+    pub fn ascii(mut self, ascii: bool) -> Self {
+        self.ascii = ascii;
+        self
+    }
+
+    /// Sets the maximum length of the hashtag in characters, not counting the
+    /// `#`. Words that don't fit are dropped, and a single word that is too
+    /// long is cut.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_len` - The maximum number of characters.
+    pub fn max_len(mut self, max_len: usize) -> Self {
+        self.max_len = Some(max_len);
+        self
+    }
+
+    /// Generates a hashtag from `text`.
+    ///
+    /// Returns `None` if the hashtag would be empty or consist only of digits
+    /// and underscores, since platforms don't link such hashtags.
+    pub fn hashtag(&self, text: &str) -> Option<String> {
+        let mut text = if self.ascii {
+            translit::transliterate(text)
+        } else {
+            text.to_string()
+        };
+        // Apostrophes would otherwise split contractions like "what's".
+        text.retain(|c| !matches!(c, '\'' | '’'));
+
+        let mut tag = String::with_capacity(text.len());
+        let mut len = 0;
+        let words = text
+            .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .filter(|word| !word.is_empty());
+        for word in words {
+            let mut cased = String::with_capacity(word.len());
+            let mut chars = word.chars();
+            if self.camel_case {
+                cased.extend(chars.next().into_iter().flat_map(char::to_uppercase));
+                cased.push_str(chars.as_str());
+            } else {
+                cased = word.to_lowercase();
+            }
+
+            let word_len = cased.chars().count();
+            match self.max_len {
+                Some(max) if len + word_len > max => {
+                    if tag.is_empty() {
+                        let end = cased
+                            .char_indices()
+                            .nth(max)
+                            .map_or(cased.len(), |(i, _)| i);
+                        tag.push_str(&cased[..chars::floor_grapheme_boundary(&cased, end)]);
+                    }
+                    break;
+                }
+                _ => {
+                    tag.push_str(&cased);
+                    len += word_len;
+                }
+            }
+        }
+
+        tag.contains(|c: char| c.is_alphabetic())
+            .then(|| format!("#{tag}"))
+    }
+}
+
+/// Generates a hashtag with default settings.
+///
+/// This is a shortcut for `Hashtagger::new().hashtag(text)`.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(rslug::to_hashtag("my blog post").as_deref(), Some("#MyBlogPost"));
+/// assert_eq!(rslug::to_hashtag("2024"), None);
+/// ```
+pub fn to_hashtag(text: &str) -> Option<String> {
+    Hashtagger::new().hashtag(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_hashtag() {
+        assert_eq!(
+            to_hashtag("Rust 2024: what's new?").as_deref(),
+            Some("#Rust2024WhatsNew")
+        );
+        assert_eq!(
+            to_hashtag("snake_case tips").as_deref(),
+            Some("#Snake_caseTips")
+        );
+        assert_eq!(to_hashtag("東京 タワー").as_deref(), Some("#東京タワー"));
+        assert_eq!(to_hashtag("I ❤️ NY").as_deref(), Some("#INY"));
+        assert_eq!(to_hashtag("!!!"), None);
+        assert_eq!(to_hashtag("1_000"), None);
+    }
+
+    #[test]
+    fn test_hashtag_max_len() {
+        let hashtagger = Hashtagger::new().max_len(5);
+        assert_eq!(
+            hashtagger.hashtag("supercalifragilistic").as_deref(),
+            Some("#Super")
+        );
+        assert_eq!(
+            hashtagger.hashtag("go go gophers").as_deref(),
+            Some("#GoGo")
+        );
+    }
+}
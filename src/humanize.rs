@@ -0,0 +1,286 @@
+//! Turning slugs back into human-readable text.
+
+/// Words that stay lowercase in title case, unless they are first or last.
+const SMALL_WORDS: &[&str] = &[
+    "a", "an", "and", "as", "at", "but", "by", "en", "for", "if", "in", "nor", "of", "on", "or",
+    "per", "the", "to", "v", "via", "vs", "with",
+];
+
+/// Words with a fixed spelling, which are restored when capitalizing.
+const ACRONYMS: &[&str] = &[
+    "AI",
+    "API",
+    "CLI",
+    "CSS",
+    "CSV",
+    "FAQ",
+    "GitHub",
+    "GraphQL",
+    "HTML",
+    "HTTP",
+    "HTTPS",
+    "ID",
+    "iOS",
+    "iPhone",
+    "JavaScript",
+    "JSON",
+    "macOS",
+    "OAuth",
+    "PDF",
+    "SDK",
+    "SQL",
+    "TypeScript",
+    "UI",
+    "URL",
+    "UX",
+    "XML",
+];
+
+/// How `Humanizer` capitalizes the words of a slug.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum Capitalization {
+    /// Only the first word is capitalized: "My post title".
+    #[default]
+    Sentence,
+    /// Title case: words are capitalized, except for small words like "of"
+    /// and "the" that are neither first nor last: "The Art of War".
+    Title,
+    /// All words are lowercase: "my post title".
+    Lower,
+    /// All words are uppercase: "MY POST TITLE".
+    Upper,
+    /// Words are kept as they appear in the slug.
+    Preserve,
+}
+
+/// Turns slugs back into readable text, e.g. for breadcrumbs or admin UIs.
+///
+/// Use the builder pattern to create an instance with custom settings.
+///
+/// # Example
+///
+/// ```
+/// use rslug::{Capitalization, Humanizer};
+///
+/// let humanizer = Humanizer::new();
+/// assert_eq!(humanizer.humanize("my-post-title"), "My post title");
+///
+/// let humanizer = Humanizer::new().capitalization(Capitalization::Title);
+/// assert_eq!(humanizer.humanize("my_post_title"), "My Post Title");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Humanizer {
+    separators: String,
+    capitalization: Capitalization,
+    small_words: Vec<String>,
+    acronyms: Vec<String>,
+}
+
+impl Default for Humanizer {
+    /// Creates a default `Humanizer` instance.
+    /// Default separators: `-` and `_`
+    /// Default capitalization: `Capitalization::Sentence`
+    fn default() -> Self {
+        Self {
+            separators: "-_".to_string(),
+            capitalization: Capitalization::default(),
+            small_words: SMALL_WORDS.iter().map(|w| w.to_string()).collect(),
+            acronyms: ACRONYMS.iter().map(|w| w.to_string()).collect(),
+        }
+    }
+}
+
+impl Humanizer {
+    /// Creates a new `Humanizer` with default settings.
+    ///
+    /// This is an alias for `Humanizer::default()`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the characters that separate words in slugs.
+    ///
+    /// # Arguments
+    ///
+    /// * `separators` - A string slice containing the separator characters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Humanizer;
+    /// let humanizer = Humanizer::new().separators(".");
+    /// assert_eq!(humanizer.humanize("release.notes"), "Release notes");
+    /// ```
+    pub fn separators(mut self, separators: &str) -> Self {
+        self.separators = separators.to_string();
+        self
+    }
+
+    /// Sets how words are capitalized.
+    ///
+    /// # Arguments
+    ///
+    /// * `capitalization` - The capitalization style.
+    pub fn capitalization(mut self, capitalization: Capitalization) -> Self {
+        self.capitalization = capitalization;
+        self
+    }
+
+    /// Sets the words that stay lowercase in title case, unless they are the
+    /// first or last word. Defaults to common English articles, conjunctions,
+    /// and short prepositions.
+    ///
+    /// # Arguments
+    ///
+    /// * `words` - The small words, in lowercase.
+    pub fn small_words(mut self, words: &[&str]) -> Self {
+        self.small_words = words.iter().map(|w| w.to_string()).collect();
+        self
+    }
+
+    /// Sets the words whose spelling is restored in sentence and title case,
+    /// like "API" or "iOS". Defaults to common technical terms.
+    ///
+    /// # Arguments
+    ///
+    /// * `acronyms` - The words, spelled as they should appear.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Humanizer;
+    /// let humanizer = Humanizer::new().acronyms(&["NASA", "eBay"]);
+    /// assert_eq!(humanizer.humanize("nasa-on-ebay"), "NASA on eBay");
+    /// ```
+    pub fn acronyms(mut self, acronyms: &[&str]) -> Self {
+        self.acronyms = acronyms.iter().map(|w| w.to_string()).collect();
+        self
+    }
+
+    /// Turns `slug` into readable text, joining its words with spaces.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::{Capitalization, Humanizer};
+    /// let humanizer = Humanizer::new().capitalization(Capitalization::Title);
+    /// assert_eq!(humanizer.humanize("the-state-of-ios-apis"), "The State of iOS Apis");
+    /// assert_eq!(humanizer.humanize("a-json-api-for-the-web"), "A JSON API for the Web");
+    /// ```
+    pub fn humanize(&self, slug: &str) -> String {
+        let words: Vec<&str> = slug
+            .split(|c| self.separators.contains(c))
+            .filter(|word| !word.is_empty())
+            .collect();
+
+        let mut text = String::with_capacity(slug.len());
+        for (i, &word) in words.iter().enumerate() {
+            if i > 0 {
+                text.push(' ');
+            }
+
+            let is_edge = i == 0 || i == words.len() - 1;
+            let acronym = self
+                .acronyms
+                .iter()
+                .find(|acronym| acronym.to_lowercase() == word.to_lowercase());
+            match (self.capitalization, acronym) {
+                (Capitalization::Sentence | Capitalization::Title, Some(acronym)) => {
+                    text.push_str(acronym);
+                }
+                (Capitalization::Sentence, _) if i == 0 => push_capitalized(&mut text, word),
+                (Capitalization::Title, _)
+                    if is_edge || !self.small_words.contains(&word.to_lowercase()) =>
+                {
+                    push_capitalized(&mut text, word)
+                }
+                (Capitalization::Upper, _) => text.push_str(&word.to_uppercase()),
+                (Capitalization::Preserve, _) => text.push_str(word),
+                _ => text.push_str(&word.to_lowercase()),
+            }
+        }
+
+        text
+    }
+}
+
+/// Pushes `word` with its first letter uppercased and the rest lowercased.
+fn push_capitalized(text: &mut String, word: &str) {
+    let mut chars = word.chars();
+    if let Some(first) = chars.next() {
+        text.extend(first.to_uppercase());
+        text.push_str(&chars.as_str().to_lowercase());
+    }
+}
+
+/// Turns a slug back into readable text with default settings.
+///
+/// This is a shortcut for `Humanizer::new().humanize(slug)`.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(rslug::deslugify("my-post-title"), "My post title");
+/// ```
+pub fn deslugify(slug: &str) -> String {
+    Humanizer::new().humanize(slug)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deslugify() {
+        assert_eq!(deslugify("my-post-title"), "My post title");
+        assert_eq!(
+            deslugify("--leading_and__trailing--"),
+            "Leading and trailing"
+        );
+        assert_eq!(deslugify(""), "");
+    }
+
+    #[test]
+    fn test_capitalization_styles() {
+        let humanize = |capitalization, slug| {
+            Humanizer::new()
+                .capitalization(capitalization)
+                .humanize(slug)
+        };
+        assert_eq!(humanize(Capitalization::Title, "über-uns"), "Über Uns");
+        assert_eq!(
+            humanize(Capitalization::Lower, "Hello-World"),
+            "hello world"
+        );
+        assert_eq!(
+            humanize(Capitalization::Upper, "hello-world"),
+            "HELLO WORLD"
+        );
+        assert_eq!(humanize(Capitalization::Preserve, "iOS-tips"), "iOS tips");
+    }
+
+    #[test]
+    fn test_title_case_small_words() {
+        let humanizer = Humanizer::new().capitalization(Capitalization::Title);
+        assert_eq!(
+            humanizer.humanize("the-lord-of-the-rings"),
+            "The Lord of the Rings"
+        );
+        assert_eq!(
+            humanizer.humanize("what-are-you-looking-at"),
+            "What Are You Looking At"
+        );
+        assert_eq!(humanizer.humanize("of"), "Of");
+    }
+
+    #[test]
+    fn test_acronyms_are_restored() {
+        assert_eq!(
+            deslugify("building-a-rest-api-for-ios"),
+            "Building a rest API for iOS"
+        );
+        let humanizer = Humanizer::new().capitalization(Capitalization::Lower);
+        assert_eq!(humanizer.humanize("ios-api"), "ios api");
+    }
+}
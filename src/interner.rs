@@ -0,0 +1,122 @@
+//! Sharing one allocation between equal slugs, for indexes that hold many
+//! repeated slugs, like site maps or tag clouds.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::Slugifier;
+
+/// A set of slugs that hands out shared `Arc<str>` handles, so each distinct
+/// slug is allocated once however often it is stored.
+///
+/// # Example
+///
+/// ```
+/// use std::sync::Arc;
+/// use rslug::{SlugInterner, Slugifier};
+///
+/// let slugifier = Slugifier::new();
+/// let mut interner = SlugInterner::new();
+/// let a = slugifier.slugify_interned("Rust Tips", &mut interner);
+/// let b = slugifier.slugify_interned("rust tips!", &mut interner);
+/// assert_eq!(&*a, "rust-tips");
+/// assert!(Arc::ptr_eq(&a, &b));
+/// assert_eq!(interner.len(), 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SlugInterner {
+    slugs: HashSet<Arc<str>>,
+    buffer: String,
+}
+
+impl SlugInterner {
+    /// Creates an empty interner.
+    ///
+    /// This is an alias for `SlugInterner::default()`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the shared handle for `slug`, adding it if it is new.
+    pub fn intern(&mut self, slug: &str) -> Arc<str> {
+        if let Some(interned) = self.slugs.get(slug) {
+            return Arc::clone(interned);
+        }
+        let interned: Arc<str> = Arc::from(slug);
+        self.slugs.insert(Arc::clone(&interned));
+        interned
+    }
+
+    /// Returns the shared handle for `slug`, if it was interned.
+    pub fn get(&self, slug: &str) -> Option<Arc<str>> {
+        self.slugs.get(slug).cloned()
+    }
+
+    /// Returns the number of distinct slugs.
+    pub fn len(&self) -> usize {
+        self.slugs.len()
+    }
+
+    /// Returns whether no slug was interned.
+    pub fn is_empty(&self) -> bool {
+        self.slugs.is_empty()
+    }
+
+    /// Removes the slugs that are only held by the interner, and returns how
+    /// many were removed.
+    pub fn shrink(&mut self) -> usize {
+        let len = self.slugs.len();
+        self.slugs.retain(|slug| Arc::strong_count(slug) > 1);
+        len - self.slugs.len()
+    }
+}
+
+impl Slugifier {
+    /// Generates a slug like `slugify`, as a handle shared with every equal
+    /// slug in `interner`.
+    ///
+    /// Slugs that were interned already don't allocate, since the slug is
+    /// written into a buffer the interner reuses.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to slugify.
+    /// * `interner` - The interner holding the slugs.
+    pub fn slugify_interned(&self, text: &str, interner: &mut SlugInterner) -> Arc<str> {
+        let mut buffer = std::mem::take(&mut interner.buffer);
+        self.slugify_into(text, &mut buffer);
+        let interned = interner.intern(&buffer);
+        interner.buffer = buffer;
+        interned
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intern_shares_equal_slugs() {
+        let mut interner = SlugInterner::new();
+        let a = interner.intern("tag");
+        let b = interner.intern("tag");
+        let c = interner.intern("other");
+        assert!(Arc::ptr_eq(&a, &b));
+        assert!(!Arc::ptr_eq(&a, &c));
+        assert_eq!(interner.len(), 2);
+        assert!(interner.get("tag").is_some_and(|tag| Arc::ptr_eq(&tag, &a)));
+        assert_eq!(interner.get("missing"), None);
+    }
+
+    #[test]
+    fn test_shrink_keeps_slugs_in_use() {
+        let slugifier = Slugifier::new();
+        let mut interner = SlugInterner::new();
+        let kept = slugifier.slugify_interned("Kept Tag", &mut interner);
+        drop(slugifier.slugify_interned("Dropped Tag", &mut interner));
+        assert_eq!(interner.shrink(), 1);
+        assert_eq!(interner.len(), 1);
+        assert_eq!(&*kept, "kept-tag");
+        assert!(!interner.is_empty());
+    }
+}
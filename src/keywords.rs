@@ -0,0 +1,99 @@
+//! Concise slugs made of the most significant words of a longer text.
+
+use std::collections::HashMap;
+
+use crate::{Slugifier, translit};
+
+/// Common English words that carry little meaning on their own.
+const ENGLISH_STOP_WORDS: &[&str] = &[
+    "a", "about", "above", "after", "again", "against", "all", "also", "am", "an", "and", "any",
+    "are", "as", "at", "be", "because", "been", "before", "being", "below", "between", "both",
+    "but", "by", "can", "could", "did", "do", "does", "doing", "down", "during", "each", "even",
+    "few", "for", "from", "further", "get", "had", "has", "have", "having", "he", "her", "here",
+    "hers", "him", "his", "how", "i", "if", "in", "into", "is", "it", "its", "itself", "just",
+    "me", "more", "most", "much", "my", "new", "no", "nor", "not", "now", "of", "off", "on",
+    "once", "one", "only", "or", "other", "our", "ours", "out", "over", "own", "same", "she",
+    "should", "so", "some", "such", "than", "that", "the", "their", "them", "then", "there",
+    "these", "they", "this", "those", "through", "to", "too", "under", "until", "up", "use",
+    "used", "using", "very", "was", "we", "were", "what", "when", "where", "which", "while", "who",
+    "whom", "why", "will", "with", "would", "you", "your",
+];
+
+impl Slugifier {
+    /// Generates a slug from the `n` most significant words of `text`, such as
+    /// an abstract or a long title.
+    ///
+    /// Words are ranked by how often they occur, ignoring case, and ties go to
+    /// the word that occurs first. Common English words, words in `stop_words`,
+    /// and single characters are never picked. The picked words keep the order
+    /// in which they first occur, and are slugified with the current configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to pick words from.
+    /// * `n` - The maximum number of words in the slug.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// let abstract_ = "We measure the performance of async Rust runtimes. \
+    ///     Async Rust performance depends on the runtime's scheduler.";
+    /// let slug = Slugifier::new().slugify_keywords(abstract_, 3);
+    /// assert_eq!(slug, "performance-async-rust");
+    /// ```
+    pub fn slugify_keywords(&self, text: &str, n: usize) -> String {
+        let text = translit::transliterate(text);
+        let is_insignificant = |word: &str| {
+            word.len() < 2
+                || ENGLISH_STOP_WORDS.contains(&word)
+                || self.stop_words.iter().any(|s| s.eq_ignore_ascii_case(word))
+        };
+
+        // Each word's original spelling, frequency, and first position.
+        let mut words: HashMap<String, (&str, usize, usize)> = HashMap::new();
+        let candidates = text
+            .split(|c: char| !c.is_ascii_alphanumeric() && c != '\'')
+            .map(|word| word.split('\'').next().unwrap_or_default())
+            .filter(|word| !word.is_empty());
+        for (position, word) in candidates.enumerate() {
+            let key = word.to_ascii_lowercase();
+            if is_insignificant(&key) {
+                continue;
+            }
+            words.entry(key).or_insert((word, 0, position)).1 += 1;
+        }
+
+        let mut ranked: Vec<_> = words.into_values().collect();
+        ranked.sort_by_key(|&(_, count, position)| (std::cmp::Reverse(count), position));
+        ranked.truncate(n);
+        ranked.sort_by_key(|&(_, _, position)| position);
+
+        let keywords: Vec<&str> = ranked.into_iter().map(|(word, _, _)| word).collect();
+        self.slugify(&keywords.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slugify_keywords() {
+        let slugifier = Slugifier::new();
+        let text = "Rust is fast. Rust is safe. Rust makes systems programming fun and safe.";
+        assert_eq!(slugifier.slugify_keywords(text, 2), "rust-safe");
+        assert_eq!(slugifier.slugify_keywords(text, 0), "");
+        assert_eq!(slugifier.slugify_keywords("the and of", 3), "");
+    }
+
+    #[test]
+    fn test_slugify_keywords_respects_configuration() {
+        let slugifier = Slugifier::new()
+            .separator("_")
+            .to_lowercase(false)
+            .stop_words(&["rust"]);
+        let text = "Rust makes Systems programming fun, and systems are fun.";
+        assert_eq!(slugifier.slugify_keywords(text, 2), "Systems_fun");
+    }
+}
@@ -17,9 +17,12 @@
 //! let slug = slugify!(text);
 //! assert_eq!(slug, "hello-world-this-is-a-test-123");
 //!
+//! # #[cfg(feature = "transliteration")]
+//! # {
 //! let unicode_text = "你好世界 & Rust";
 //! let unicode_slug = slugify!(unicode_text);
 //! assert_eq!(unicode_slug, "nihaoshijie-rust");
+//! # }
 //! ```
 //!
 //! ## Advanced Configuration
@@ -40,15 +43,143 @@
 //! assert_eq!(slug, "custom_separator_example");
 //! ```
 
+mod anchor;
+#[cfg(feature = "test-helpers")]
+mod arbitrary;
+mod ascii;
+mod batch;
+mod builder;
+mod bytes;
+mod chars;
+#[cfg(feature = "chinese-conversion")]
+mod chinese;
+#[cfg(feature = "compact_str")]
+mod compact;
+mod compat;
+mod compiled;
+mod content_disposition;
+mod dates;
+mod encoding;
+mod error;
+mod excerpt;
+mod ext;
+mod filename;
+mod git;
+mod global;
+mod hashtag;
+mod humanize;
+mod interner;
+mod keywords;
+mod loss;
+mod multilang;
+mod numbers;
+mod owned;
+mod parser;
+#[cfg(feature = "postgres")]
+mod postgres;
+mod preset;
+mod reader;
+#[cfg(feature = "redis")]
+mod redis;
+mod registry;
+mod report;
+mod roman;
+#[cfg(test)]
+mod scratch;
+mod script;
+mod segments;
+mod sink;
+mod spans;
+mod suffix;
+mod symbols;
+mod thai;
+mod trace;
+mod translit;
+mod tree;
+mod unique;
+mod url;
+
+use std::borrow::Cow;
+
+use builder::{ByteAction, ByteClasses, SlugBuilder};
+
+pub use anchor::AnchorGenerator;
+pub use ascii::NonAsciiPolicy;
+#[cfg(feature = "chinese-conversion")]
+pub use chinese::ChineseScript;
+pub use compiled::CompiledSlugifier;
+pub use content_disposition::ContentDisposition;
+pub use error::{AsciiError, Error};
+pub use excerpt::Excerpt;
+pub use ext::Slugify;
+pub use filename::{FsProfile, Normalization};
+pub use global::{default, set_default, validate_preset_slug, validate_slug};
+pub use hashtag::{Hashtagger, to_hashtag};
+pub use humanize::{Capitalization, Humanizer, deslugify};
+pub use interner::SlugInterner;
+pub use loss::LossEvent;
+pub use multilang::MultiLangSlugifier;
+pub use numbers::{NumberMode, OrdinalMode};
+#[cfg(feature = "postgres")]
+pub use postgres::{PostgresStore, SqlExecutor};
+pub use preset::{JekyllMode, Preset, SqlDialect};
+#[cfg(feature = "redis")]
+pub use redis::RedisStore;
+pub use report::SlugifyReport;
+pub use roman::RomanNumerals;
+pub use spans::SlugSpan;
+pub use suffix::Suffix;
+pub use symbols::CurrencyStyle;
+pub use tree::{ApplyReport, Progress, Rename, RenamePlan, TreeOptions, sanitize_tree};
+pub use unique::{SlugRegistry, UniquenessStore};
+pub use url::{encode_query_key, encode_query_value};
+
 /// A configurable slug generator.
 ///
 /// Use the builder pattern to create an instance with custom settings.
 #[derive(Debug, Clone)]
 pub struct Slugifier {
     separator: String,
+    collapse_separators: bool,
+    trim_separators: bool,
     to_lowercase: bool,
+    allowed_chars: String,
+    preserve_versions: bool,
+    roman_numerals: RomanNumerals,
+    numbers: NumberMode,
+    ordinals: OrdinalMode,
+    join_digit_groups: bool,
+    unicode_words: bool,
+    remove_chars: String,
+    /// The action for each byte, rebuilt when `to_lowercase`, `allowed_chars`,
+    /// or `remove_chars` changes.
+    classes: ByteClasses,
+    replacements: Vec<(String, String)>,
+    language: Option<&'static symbols::Language>,
+    programming_terms: bool,
+    compatibility_folding: bool,
+    #[cfg(feature = "chinese-conversion")]
+    chinese_script: Option<ChineseScript>,
+    verbalize_symbols: bool,
+    currency_style: Option<CurrencyStyle>,
+    date_format: Option<String>,
+    stop_words: Vec<String>,
     truncate: Option<usize>,
+    max_segments: Option<usize>,
+    word_boundary: bool,
+    save_order: bool,
     sanitize_replacement: String,
+    illegal_chars: Vec<char>,
+    reserved_name_prefix: Option<String>,
+    trim_trailing_dots: bool,
+    max_filename_bytes: Option<usize>,
+    max_path_len: Option<usize>,
+    filename_normalization: Option<Normalization>,
+    unique_suffix: String,
+    case_insensitive_collisions: bool,
+    reject_mixed_scripts: bool,
+    preset: Option<Preset>,
+    on_loss: Option<loss::LossHandler>,
 }
 
 impl Default for Slugifier {
@@ -58,9 +189,44 @@ impl Default for Slugifier {
     fn default() -> Self {
         Self {
             separator: "-".to_string(),
+            collapse_separators: true,
+            trim_separators: true,
             to_lowercase: true,
+            allowed_chars: String::new(),
+            preserve_versions: false,
+            roman_numerals: RomanNumerals::Ignore,
+            numbers: NumberMode::Keep,
+            ordinals: OrdinalMode::Keep,
+            join_digit_groups: false,
+            unicode_words: false,
+            remove_chars: String::new(),
+            classes: ByteClasses::new(true, "", ""),
+            replacements: Vec::new(),
+            language: None,
+            programming_terms: false,
+            compatibility_folding: false,
+            #[cfg(feature = "chinese-conversion")]
+            chinese_script: None,
+            verbalize_symbols: false,
+            currency_style: None,
+            date_format: None,
+            stop_words: Vec::new(),
             truncate: None,
+            max_segments: None,
+            word_boundary: true,
+            save_order: true,
             sanitize_replacement: String::new(),
+            illegal_chars: FsProfile::Windows.illegal_chars().to_vec(),
+            reserved_name_prefix: Some("_".to_string()),
+            trim_trailing_dots: true,
+            max_filename_bytes: None,
+            max_path_len: None,
+            filename_normalization: None,
+            unique_suffix: " ({n})".to_string(),
+            case_insensitive_collisions: cfg!(any(windows, target_os = "macos")),
+            reject_mixed_scripts: false,
+            preset: None,
+            on_loss: None,
         }
     }
 }
@@ -91,6 +257,47 @@ impl Slugifier {
         self
     }
 
+    /// Sets whether a run of characters between two words becomes a single
+    /// separator, which is the default.
+    ///
+    /// Without collapsing, each character between two words becomes a
+    /// separator of its own, which some legacy URL schemes rely on.
+    ///
+    /// # Arguments
+    ///
+    /// * `collapse` - A boolean indicating if separator runs are collapsed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// let slugifier = Slugifier::new().collapse_separators(false);
+    /// assert_eq!(slugifier.slugify("Hello, World"), "hello--world");
+    /// ```
+    pub fn collapse_separators(mut self, collapse: bool) -> Self {
+        self.collapse_separators = collapse;
+        self
+    }
+
+    /// Sets whether separators at the start and end of the slug are removed,
+    /// which is the default.
+    ///
+    /// # Arguments
+    ///
+    /// * `trim` - A boolean indicating if leading and trailing separators are removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// let slugifier = Slugifier::new().trim_separators(false);
+    /// assert_eq!(slugifier.slugify(" Hello World! "), "-hello-world-");
+    /// ```
+    pub fn trim_separators(mut self, trim: bool) -> Self {
+        self.trim_separators = trim;
+        self
+    }
+
     /// Sets whether the output slug should be lowercased.
     ///
     /// # Arguments
@@ -106,147 +313,1318 @@ impl Slugifier {
     /// ```
     pub fn to_lowercase(mut self, lowercase: bool) -> Self {
         self.to_lowercase = lowercase;
+        self.update_classes();
         self
     }
 
-    /// Sets the maximum length of the final slug.
+    /// Sets additional ASCII characters that are kept inside words.
     ///
-    /// This is a "smart" truncation that will attempt to cut the slug at the
-    /// last full word (separator) before the specified length. If the first
-    /// word itself is longer than the max length, it will be hard-truncated.
+    /// By default every non-alphanumeric character acts as a word boundary. An
+    /// allowed character is instead kept as-is when it sits directly between two
+    /// alphanumeric characters, so "v1.2" can keep its dot while a dot at the end
+    /// of a sentence still becomes a boundary.
     ///
     /// # Arguments
     ///
-    /// * `max_length` - The maximum number of characters for the final slug.
+    /// * `chars` - The characters to keep inside words.
     ///
     /// # Example
     ///
     /// ```
     /// use rslug::Slugifier;
-    /// let slugifier = Slugifier::new().truncate(20);
-    /// let text = "this is a very long title";
-    /// assert_eq!(slugifier.slugify(text), "this-is-a-very-long");
+    /// let slugifier = Slugifier::new().allowed_chars("._");
+    /// assert_eq!(slugifier.slugify("my_app.config v2."), "my_app.config-v2");
     /// ```
-    pub fn truncate(mut self, max_length: usize) -> Self {
-        self.truncate = Some(max_length);
+    pub fn allowed_chars(mut self, chars: &str) -> Self {
+        self.allowed_chars = chars.to_string();
+        self.update_classes();
         self
     }
 
-    /// Sets the replacement string for illegal filename characters.
+    /// Sets whether dots are kept inside version numbers, like "v1.2.3".
     ///
-    /// By default, illegal characters are simply removed.
+    /// A dot is kept when it sits directly between two digits, and separates
+    /// words elsewhere, so a slug can keep its version numbers without keeping
+    /// the dots of abbreviations and domain names. To keep every dot between
+    /// two alphanumerics instead, use `allowed_chars(".")`.
     ///
     /// # Arguments
     ///
-    /// * `replacement` - The string to use as a replacement (e.g., "_").
+    /// * `preserve` - A boolean indicating if dots in version numbers are kept.
     ///
     /// # Example
     ///
     /// ```
     /// use rslug::Slugifier;
-    /// let slugifier = Slugifier::new().sanitize_replacement("_");
-    /// let text = "file/with:illegal*chars";
-    /// assert_eq!(slugifier.sanitize_filename(text), "file_with_illegal_chars");
+    /// let slugifier = Slugifier::new().preserve_versions(true);
+    /// assert_eq!(slugifier.slugify("Release v1.2.3 notes"), "release-v1.2.3-notes");
+    /// assert_eq!(slugifier.slugify("Node.js 20.x"), "node-js-20-x");
+    /// ```
+    pub fn preserve_versions(mut self, preserve: bool) -> Self {
+        self.preserve_versions = preserve;
+        self
+    }
+
+    /// Sets how standalone roman numerals, like the "IV" of "Part IV", are
+    /// slugified.
     ///
-    pub fn sanitize_replacement(mut self, replacement: &str) -> Self {
-        self.sanitize_replacement = replacement.to_string();
+    /// By default they are treated like any other word. See [`RomanNumerals`]
+    /// for which words are recognized.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - How roman numerals are slugified.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::{RomanNumerals, Slugifier};
+    /// let slugifier = Slugifier::new().roman_numerals(RomanNumerals::PreserveCase);
+    /// assert_eq!(slugifier.slugify("Part IV: The Return"), "part-IV-the-return");
+    ///
+    /// let slugifier = Slugifier::new().roman_numerals(RomanNumerals::Arabic);
+    /// assert_eq!(slugifier.slugify("Chapter XII"), "chapter-12");
+    /// ```
+    pub fn roman_numerals(mut self, mode: RomanNumerals) -> Self {
+        self.roman_numerals = mode;
         self
     }
 
-    /// Sanitizes a string to create a valid and safe filename.
+    /// Sets how standalone numbers, like the "10" of "Top 10 tips", are
+    /// slugified.
     ///
-    /// This method is more conservative than `slugify`. It preserves case and spaces,
-    /// and only removes or replaces characters that are illegal in file paths on
-    /// major operating systems (e.g., `/`, `\`, `:`, `*`, `?`, `"`).
+    /// By default they are kept as digits. Numbers are spelled out in the
+    /// language set with `lang`, and roman numerals converted with
+    /// `RomanNumerals::Arabic` are spelled out too. See [`NumberMode`] for
+    /// which numbers are recognized.
     ///
     /// # Arguments
     ///
-    /// * `filename` - The string to sanitize.
-    pub fn sanitize_filename(&self, filename: &str) -> String {
-        const ILLEGAL_FILENAME_CHARS: &[char] = &['/', '\\', ':', '*', '?', '"', '<', '>', '|'];
-        let mut sanitized = String::with_capacity(filename.len());
-        let mut last_char_was_boundary = false;
+    /// * `mode` - How numbers are slugified.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::{NumberMode, Slugifier};
+    /// let slugifier = Slugifier::new().numbers(NumberMode::Words);
+    /// assert_eq!(slugifier.slugify("Top 10 tips"), "top-ten-tips");
+    /// assert_eq!(slugifier.slugify("21 mp3s in 3.5 hours"), "twenty-one-mp3s-in-3-5-hours");
+    ///
+    /// let slugifier = slugifier.lang(Some("de"));
+    /// assert_eq!(slugifier.slugify("Die 5 besten Tipps"), "die-funf-besten-tipps");
+    ///
+    /// let slugifier = Slugifier::new().numbers(NumberMode::Remove);
+    /// assert_eq!(slugifier.slugify("2024: 1,500 Rust jobs in Q3"), "rust-jobs-in-q3");
+    /// ```
+    pub fn numbers(mut self, mode: NumberMode) -> Self {
+        self.numbers = mode;
+        self
+    }
 
-        for c in filename.chars() {
-            if ILLEGAL_FILENAME_CHARS.contains(&c) || c.is_whitespace() {
-                if !last_char_was_boundary {
-                    if c.is_whitespace() {
-                        sanitized.push(' ');
-                    } else {
-                        sanitized.push_str(&self.sanitize_replacement);
-                    }
-                    last_char_was_boundary = true;
-                }
-            } else {
-                sanitized.push(c);
-                last_char_was_boundary = false;
-            }
-        }
+    /// Sets how ordinals, like the "1st" of "1st place", are slugified.
+    ///
+    /// By default they are kept as they are, and they are never split into
+    /// their digits and suffix or treated as numbers by `numbers`. See
+    /// [`OrdinalMode`] for which words are recognized.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - How ordinals are slugified.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::{OrdinalMode, Slugifier};
+    /// assert_eq!(Slugifier::new().slugify("1st Place"), "1st-place");
+    ///
+    /// let slugifier = Slugifier::new().ordinals(OrdinalMode::Digits);
+    /// assert_eq!(slugifier.slugify("The 22nd Annual Meeting"), "the-22-annual-meeting");
+    ///
+    /// let slugifier = Slugifier::new().ordinals(OrdinalMode::Words);
+    /// assert_eq!(slugifier.slugify("21st Century"), "twenty-first-century");
+    /// ```
+    pub fn ordinals(mut self, mode: OrdinalMode) -> Self {
+        self.ordinals = mode;
+        self
+    }
 
-        // Trim leading/trailing boundaries which manifest as spaces or replacements
-        sanitized.trim().to_string()
+    /// Sets whether the thousands separators of numbers are dropped, so
+    /// "1,000,000" becomes one segment instead of three.
+    ///
+    /// Commas, apostrophes, and spaces (including no-break and thin spaces)
+    /// separate thousands if every group after the first has three digits.
+    /// Dots are left alone, since they are also decimal points and separate
+    /// the parts of versions and IP addresses.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - A boolean indicating if digit groups are joined.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// let slugifier = Slugifier::new().join_digit_groups(true);
+    /// assert_eq!(slugifier.slugify("1,000,000 users"), "1000000-users");
+    /// assert_eq!(slugifier.slugify("2 000 views"), "2000-views");
+    /// ```
+    pub fn join_digit_groups(mut self, enabled: bool) -> Self {
+        self.join_digit_groups = enabled;
+        self
     }
 
-    /// Helper function to apply the truncation logic to a mutable slug string.
-    pub fn apply_truncation(&self, slug: &mut String) {
-        if let Some(max_len) = self.truncate
-            && slug.len() > max_len
-        {
-            if !self.separator.is_empty()
-                && let Some(last_sep_index) = slug[..max_len].rfind(&self.separator)
-            {
-                slug.truncate(last_sep_index);
-                return;
-            }
+    /// Sets whether word boundaries follow Unicode word segmentation
+    /// (UAX #29) instead of every non-alphanumeric character.
+    ///
+    /// Punctuation that UAX #29 keeps inside a word when it is between two
+    /// letters, like apostrophes, middle dots, colons, and dots, then joins
+    /// the letters around it instead of separating them: "can't" becomes
+    /// "cant" and Catalan "col·lecció" becomes "colleccio". Between digits,
+    /// the usual rules apply, so `preserve_versions` still decides what
+    /// happens to "v1.2".
+    ///
+    /// Scripts written without spaces between words, like Thai, aren't split
+    /// into words, since that needs a dictionary rather than these rules.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - A boolean indicating if Unicode word segmentation is used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// let slugifier = Slugifier::new().unicode_words(true);
+    /// assert_eq!(slugifier.slugify("Don’t Stop Me Now"), "dont-stop-me-now");
+    /// assert_eq!(slugifier.slugify("L'Hospitalet"), "lhospitalet");
+    /// ```
+    pub fn unicode_words(mut self, enabled: bool) -> Self {
+        self.unicode_words = enabled;
+        self
+    }
 
-            // If no separator was found (or separator is empty), hard-truncate.
-            slug.truncate(max_len);
-        }
+    /// Sets characters that are removed from the input instead of separating words.
+    ///
+    /// This is useful for characters like apostrophes, which would otherwise
+    /// split "don't" into two words. Removal happens after transliteration.
+    ///
+    /// # Arguments
+    ///
+    /// * `chars` - A string slice containing the characters to remove.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// let slugifier = Slugifier::new().remove_chars("'");
+    /// assert_eq!(slugifier.slugify("Don't stop"), "dont-stop");
+    /// ```
+    pub fn remove_chars(mut self, chars: &str) -> Self {
+        self.remove_chars = chars.to_string();
+        self.update_classes();
+        self
     }
 
-    /// Generates a slug from the given text based on the current configuration.
+    /// Rebuilds the action for each byte from the case and character options.
+    fn update_classes(&mut self) {
+        self.classes = ByteClasses::new(self.to_lowercase, &self.allowed_chars, &self.remove_chars);
+    }
+
+    /// Adds a custom replacement that is applied before slugification.
+    ///
+    /// The replacement becomes a separate word of the slug. Matching ignores
+    /// ASCII case, and a term starting or ending with a letter or digit only
+    /// matches at a word boundary on that side. Custom replacements take
+    /// precedence over the symbols of `lang`, and longer terms over shorter ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The term to replace.
+    /// * `to` - The text to replace it with.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// let slugifier = Slugifier::new().replace("JS", "javascript").replace("@", "at");
+    /// assert_eq!(slugifier.slugify("JS@Scale"), "javascript-at-scale");
+    /// assert_eq!(slugifier.slugify("jsdoc"), "jsdoc");
+    /// ```
+    pub fn replace(mut self, from: &str, to: &str) -> Self {
+        self.replacements.push((from.to_string(), to.to_string()));
+        self
+    }
+
+    /// Sets the language whose words replace symbols like `&` and `<`.
+    ///
+    /// Dictionaries are available for `en`, `de`, `fr`, `es`, `it`, `pt`,
+    /// `nl`, `ca`, and `haw`, and other languages fall back to English. The
+    /// dictionary may also transliterate letters differently, like German
+    /// umlauts to `ae`, `oe`, and `ue`, and keep punctuation that is part of
+    /// words from splitting them, like the middle dot of Catalan "col·lecció"
+    /// and the ʻokina of Hawaiian "Hawaiʻi". By default symbols are treated
+    /// like any other punctuation.
+    ///
+    /// # Arguments
+    ///
+    /// * `lang` - The language code, or `None` to not replace symbols.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// let slugifier = Slugifier::new().lang(Some("de"));
+    /// assert_eq!(slugifier.slugify("Äpfel & Birnen"), "aepfel-und-birnen");
+    ///
+    /// let slugifier = Slugifier::new().lang(Some("ca"));
+    /// assert_eq!(slugifier.slugify("Col·lecció d'art"), "colleccio-d-art");
+    /// ```
+    pub fn lang(mut self, lang: Option<&str>) -> Self {
+        self.language = lang.map(symbols::language);
+        self
+    }
+
+    /// Sets whether the names of programming languages and frameworks that
+    /// differ only in symbols are spelled out, like `C++` as `cpp`, `C#` as
+    /// `c-sharp`, and `.NET` as `dotnet`.
+    ///
+    /// Otherwise "C", "C++", and "C#" all become `c`. The terms are replaced
+    /// like the symbols of `lang`, so custom replacements take precedence
+    /// and can add more terms.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - A boolean indicating if programming terms are spelled out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// let slugifier = Slugifier::new().programming_terms(true).replace("F*", "fstar");
+    /// assert_eq!(slugifier.slugify("C# vs C++ on .NET"), "c-sharp-vs-cpp-on-dotnet");
+    /// assert_eq!(slugifier.slugify("Learn F*"), "learn-fstar");
+    /// ```
+    pub fn programming_terms(mut self, enabled: bool) -> Self {
+        self.programming_terms = enabled;
+        self
+    }
+
+    /// Sets whether compatibility characters are folded into their plain
+    /// form before anything else, like full-width `ＡＢＣ１２３` into `ABC123`
+    /// and mathematical `𝐇𝐞𝐥𝐥𝐨` into `Hello`.
+    ///
+    /// Folding uses Unicode compatibility normalization (NFKC), so that these
+    /// characters become ASCII by a defined rule rather than by the
+    /// transliteration table, and so that replacements and dictionaries match
+    /// them too, like a full-width `％` for `verbalize_symbols`.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - A boolean indicating if compatibility characters are folded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// let slugifier = Slugifier::new().compatibility_folding(true).replace("C++", "cpp");
+    /// assert_eq!(slugifier.slugify("Ｃ＋＋ ２０２４"), "cpp-2024");
+    /// ```
+    pub fn compatibility_folding(mut self, enabled: bool) -> Self {
+        self.compatibility_folding = enabled;
+        self
+    }
+
+    /// Sets the Chinese script that Chinese characters are converted to
+    /// before anything else, so that Traditional and Simplified Chinese titles
+    /// give the same slug.
+    ///
+    /// Like `compatibility_folding`, the conversion runs before replacements
+    /// and dictionaries, so they only need to list one script. Characters are
+    /// converted one at a time, using a table of the common characters.
+    ///
+    /// # Arguments
+    ///
+    /// * `script` - The script to convert to, or `None` to leave Chinese
+    ///   characters alone.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::{ChineseScript, Preset, Slugifier};
+    /// let slugifier = Slugifier::new()
+    ///     .preset(Preset::GitHubAnchor)
+    ///     .chinese_script(Some(ChineseScript::Simplified));
+    /// assert_eq!(slugifier.slugify("學習 Rust"), "学习-rust");
+    /// ```
+    #[cfg(feature = "chinese-conversion")]
+    pub fn chinese_script(mut self, script: Option<ChineseScript>) -> Self {
+        self.chinese_script = script;
+        self
+    }
+
+    /// Sets whether units and math symbols are spelled out, like `%` as
+    /// `percent`, `°` as `degrees`, `+` as `plus`, and `=` as `equals`.
+    ///
+    /// The words are in the language set with `lang`, or in English. The
+    /// symbols are replaced like the symbols of `lang`, so custom replacements
+    /// and longer terms, like `C++` of `programming_terms`, take precedence.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - A boolean indicating if symbols are spelled out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// let slugifier = Slugifier::new().verbalize_symbols(true);
+    /// assert_eq!(slugifier.slugify("100% Rust"), "100-percent-rust");
+    /// assert_eq!(slugifier.slugify("1 + 1 = 2"), "1-plus-1-equals-2");
+    /// ```
+    pub fn verbalize_symbols(mut self, enabled: bool) -> Self {
+        self.verbalize_symbols = enabled;
+        self
+    }
+
+    /// Sets how currency symbols like `$` and `€` are spelled out, as their
+    /// ISO code or as the name of the currency in the language set with
+    /// `lang`. By default they are dropped like other symbols.
+    ///
+    /// A symbol in front of an amount is moved after it, so slugs read like
+    /// "5 dollars". Symbols are replaced like the symbols of `lang`, so custom
+    /// replacements take precedence.
+    ///
+    /// # Arguments
+    ///
+    /// * `style` - How currency symbols are spelled out, or `None` to drop them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::{CurrencyStyle, Slugifier};
+    /// let slugifier = Slugifier::new().currency_symbols(Some(CurrencyStyle::Code));
+    /// assert_eq!(slugifier.slugify("$5 sale"), "5-usd-sale");
+    ///
+    /// let slugifier = Slugifier::new().currency_symbols(Some(CurrencyStyle::Word));
+    /// assert_eq!(slugifier.slugify("$5 sale"), "5-dollar-sale");
+    /// assert_eq!(slugifier.lang(Some("es")).slugify("£20"), "20-libra");
+    /// ```
+    pub fn currency_symbols(mut self, style: Option<CurrencyStyle>) -> Self {
+        self.currency_style = style;
+        self
+    }
+
+    /// Sets the format dates in the text are rewritten in, so that "May 1,
+    /// 2024", "1st May 2024", and "2024/05/01" all become the same slug.
+    /// By default dates are slugified like any other text.
+    ///
+    /// Dates are recognized in the forms "2024-05-01" (with dashes, slashes,
+    /// or dots), "01.05.2024", "1 May 2024", and "May 1, 2024", with English
+    /// month names or their abbreviations. Forms like "05/01/2024", where the
+    /// day may come first or second, are left alone.
+    ///
+    /// In `format`, `%Y` is the year, `%m` and `%d` are the month and day as
+    /// two digits, `%B` and `%b` are the name of the month and its
+    /// abbreviation, and `%%` is a `%`.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - The format dates are rewritten in, or `None` to leave them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// let slugifier = Slugifier::new().dates(Some("%Y-%m-%d"));
+    /// assert_eq!(slugifier.slugify("Release notes, May 1, 2024"), "release-notes-2024-05-01");
+    ///
+    /// let slugifier = Slugifier::new().separator("_").dates(Some("%Y%m%d"));
+    /// assert_eq!(slugifier.slugify("Standup 2024/5/1"), "standup_20240501");
+    /// ```
+    pub fn dates(mut self, format: Option<&str>) -> Self {
+        self.date_format = format.map(str::to_string);
+        self
+    }
+
+    /// Sets words that are left out of slugs, like "a" or "the".
+    ///
+    /// Words are compared after transliteration, ignoring ASCII case.
+    ///
+    /// # Arguments
+    ///
+    /// * `words` - The words to leave out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// let slugifier = Slugifier::new().stop_words(&["a", "the", "of"]);
+    /// assert_eq!(slugifier.slugify("The Art of War"), "art-war");
+    /// ```
+    pub fn stop_words(mut self, words: &[&str]) -> Self {
+        self.stop_words = words.iter().map(|w| w.to_string()).collect();
+        self
+    }
+
+    /// Sets the maximum length of the final slug.
+    ///
+    /// This is a "smart" truncation that will attempt to cut the slug at the
+    /// last full word (separator) before the specified length. If the first
+    /// word itself is longer than the max length, it will be hard-truncated.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_length` - The maximum number of characters for the final slug.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// let slugifier = Slugifier::new().truncate(20);
+    /// let text = "this is a very long title";
+    /// assert_eq!(slugifier.slugify(text), "this-is-a-very-long");
+    /// ```
+    pub fn truncate(mut self, max_length: usize) -> Self {
+        self.truncate = Some(max_length);
+        self
+    }
+
+    /// Sets whether `truncate` cuts slugs at a separator.
+    ///
+    /// When enabled (the default), truncated slugs only contain whole words.
+    /// Otherwise the slug is cut at exactly the maximum length, and only a
+    /// trailing separator is removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `word_boundary` - A boolean indicating if truncation keeps whole words.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// let slugifier = Slugifier::new().truncate(12).word_boundary(false);
+    /// assert_eq!(slugifier.slugify("The quick brown fox"), "the-quick-br");
+    /// ```
+    pub fn word_boundary(mut self, word_boundary: bool) -> Self {
+        self.word_boundary = word_boundary;
+        self
+    }
+
+    /// Sets the maximum number of segments, the words joined by the
+    /// separator, that a slug keeps.
+    ///
+    /// Words past the limit are removed after stop words are, and before the
+    /// slug is truncated. This keeps slugs used as hierarchical keys, like
+    /// `a-b-c`, at a fixed arity regardless of their length.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_segments` - The maximum number of segments.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// let slugifier = Slugifier::new().max_segments(3);
+    /// assert_eq!(slugifier.slugify("Europe / France / Paris / 7th"), "europe-france-paris");
+    /// ```
+    pub fn max_segments(mut self, max_segments: usize) -> Self {
+        self.max_segments = Some(max_segments);
+        self
+    }
+
+    /// Sets whether truncation at word boundaries keeps the words in order.
+    ///
+    /// When enabled (the default), truncation stops at the first word that
+    /// doesn't fit. Otherwise words that don't fit are skipped, and later
+    /// shorter words are kept if there is room for them. This has no effect
+    /// unless `word_boundary` is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `save_order` - A boolean indicating if words after a skipped word are dropped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// let slugifier = Slugifier::new().truncate(12).save_order(false);
+    /// assert_eq!(slugifier.slugify("one two three four"), "one-two-four");
+    /// ```
+    pub fn save_order(mut self, save_order: bool) -> Self {
+        self.save_order = save_order;
+        self
+    }
+
+    /// Sets the replacement string for illegal filename characters.
+    ///
+    /// By default, illegal characters are simply removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `replacement` - The string to use as a replacement (e.g., "_").
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// let slugifier = Slugifier::new().sanitize_replacement("_");
+    /// let text = "file/with:illegal*chars";
+    /// assert_eq!(slugifier.sanitize_filename(text), "file_with_illegal_chars");
+    ///
+    pub fn sanitize_replacement(mut self, replacement: &str) -> Self {
+        self.sanitize_replacement = replacement.to_string();
+        self
+    }
+
+    /// Sets the characters that `sanitize_filename` treats as illegal.
+    ///
+    /// By default these are `/`, `\`, `:`, `*`, `?`, `"`, `<`, `>` and `|`. ASCII
+    /// control characters are always treated as illegal, regardless of this set.
+    ///
+    /// # Arguments
+    ///
+    /// * `chars` - The characters to remove or replace in filenames.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// let slugifier = Slugifier::new().illegal_chars(&['/', '#']);
+    /// assert_eq!(slugifier.sanitize_filename("issue #42: notes?"), "issue 42: notes?");
+    /// ```
+    pub fn illegal_chars(mut self, chars: &[char]) -> Self {
+        self.illegal_chars = chars.to_vec();
+        self
+    }
+
+    /// Sets the prefix used to rename Windows reserved device names.
+    ///
+    /// Names like `CON`, `NUL.txt`, or `com1.log` cannot be created on Windows.
+    /// By default `sanitize_filename` prefixes them with `_`. Passing `None`
+    /// leaves reserved names untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The prefix to prepend to reserved names, or `None` to disable renaming.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// assert_eq!(Slugifier::new().sanitize_filename("CON.txt"), "_CON.txt");
+    ///
+    /// let slugifier = Slugifier::new().reserved_name_prefix(Some("file-"));
+    /// assert_eq!(slugifier.sanitize_filename("nul"), "file-nul");
+    ///
+    /// let slugifier = Slugifier::new().reserved_name_prefix(None);
+    /// assert_eq!(slugifier.sanitize_filename("nul"), "nul");
+    /// ```
+    pub fn reserved_name_prefix(mut self, prefix: Option<&str>) -> Self {
+        self.reserved_name_prefix = prefix.map(str::to_string);
+        self
+    }
+
+    /// Sets whether `sanitize_filename` strips trailing dots.
+    ///
+    /// Windows silently drops trailing dots and spaces from filenames, which makes
+    /// such files hard to open or delete. Trailing dots are stripped by default;
+    /// disable this if you only target POSIX filesystems. Trailing spaces are
+    /// always trimmed.
+    ///
+    /// # Arguments
+    ///
+    /// * `trim` - A boolean indicating if trailing dots should be stripped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// assert_eq!(Slugifier::new().sanitize_filename("draft..."), "draft");
+    ///
+    /// let slugifier = Slugifier::new().trim_trailing_dots(false);
+    /// assert_eq!(slugifier.sanitize_filename("draft..."), "draft...");
+    /// ```
+    pub fn trim_trailing_dots(mut self, trim: bool) -> Self {
+        self.trim_trailing_dots = trim;
+        self
+    }
+
+    /// Sets the maximum length, in bytes, of filenames produced by `sanitize_filename`.
+    ///
+    /// Most filesystems limit names to 255 bytes. When a name is too long, the
+    /// stem is shortened and the extension is kept intact. UTF-8 sequences are
+    /// never split.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_bytes` - The maximum number of bytes for the sanitized filename.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// let slugifier = Slugifier::new().max_filename_bytes(12);
+    /// assert_eq!(slugifier.sanitize_filename("quarterly report.pdf"), "quarterl.pdf");
+    /// ```
+    pub fn max_filename_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_filename_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Sets the maximum length of paths produced by `sanitize_path`, in UTF-16
+    /// code units as Windows counts them.
+    ///
+    /// Windows limits paths to 260 code units including a terminating NUL, so
+    /// use 259 for paths that must work without long path support. When a path
+    /// is too long, its longest names are shortened like `max_filename_bytes`
+    /// shortens them until it fits. Verbatim paths starting with `\\?\` are
+    /// exempt, since Windows allows them to be much longer.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_len` - The maximum number of UTF-16 code units for the sanitized path.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use rslug::Slugifier;
+    ///
+    /// let slugifier = Slugifier::new().max_path_len(24);
+    /// let path = slugifier.sanitize_path(Path::new(r"C:\data\quarterly report.pdf"));
+    /// assert_eq!(path, Path::new(r"C:\data\quarterly re.pdf"));
+    /// ```
+    pub fn max_path_len(mut self, max_len: usize) -> Self {
+        self.max_path_len = Some(max_len);
+        self
+    }
+
+    /// Configures filename sanitization for a target filesystem.
+    ///
+    /// This sets `illegal_chars`, `reserved_name_prefix`, `trim_trailing_dots`,
+    /// `max_filename_bytes`, and `filename_normalization` to match the chosen
+    /// [`FsProfile`]. Any of them can still be overridden afterwards.
+    ///
+    /// # Arguments
+    ///
+    /// * `profile` - The filesystem to produce filenames for.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::{FsProfile, Slugifier};
+    /// let posix = Slugifier::new().fs_profile(FsProfile::Posix);
+    /// assert_eq!(posix.sanitize_filename("a:b/c?.txt"), "a:bc?.txt");
+    ///
+    /// let windows = Slugifier::new().fs_profile(FsProfile::Windows);
+    /// assert_eq!(windows.sanitize_filename("a:b/c?.txt"), "abc.txt");
+    /// ```
+    pub fn fs_profile(mut self, profile: FsProfile) -> Self {
+        self.illegal_chars = profile.illegal_chars().to_vec();
+        self.reserved_name_prefix = profile.has_reserved_names().then(|| "_".to_string());
+        self.trim_trailing_dots = profile.strips_trailing_dots();
+        self.max_filename_bytes = Some(profile.max_filename_bytes());
+        self.filename_normalization = profile.normalization();
+        self
+    }
+
+    /// Sets the Unicode normalization form applied by `sanitize_filename`.
+    ///
+    /// By default names are left in whatever form they were given. Use NFC for
+    /// general interchange, or NFD to match how HFS+ and macOS store names.
+    ///
+    /// # Arguments
+    ///
+    /// * `normalization` - The form to normalize to, or `None` to leave names as-is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::{Normalization, Slugifier};
+    /// let nfc = Slugifier::new().filename_normalization(Some(Normalization::Nfc));
+    /// assert_eq!(nfc.sanitize_filename("Cafe\u{301}.txt"), "Caf\u{e9}.txt");
+    ///
+    /// let nfd = Slugifier::new().filename_normalization(Some(Normalization::Nfd));
+    /// assert_eq!(nfd.sanitize_filename("Caf\u{e9}.txt"), "Cafe\u{301}.txt");
+    /// ```
+    pub fn filename_normalization(mut self, normalization: Option<Normalization>) -> Self {
+        self.filename_normalization = normalization;
+        self
+    }
+
+    /// Sets the suffix `unique_filename` inserts to disambiguate taken names.
+    ///
+    /// The suffix is inserted before the extension and `{n}` is replaced with
+    /// an increasing counter starting at 1, which is appended if the suffix
+    /// has no `{n}`. Defaults to `" ({n})"`.
+    ///
+    /// # Arguments
+    ///
+    /// * `suffix` - The suffix template, containing `{n}`.
+    pub fn unique_suffix(mut self, suffix: &str) -> Self {
+        self.unique_suffix = suffix.to_string();
+        self
+    }
+
+    /// Sets whether `unique_filename` and `disambiguate_filenames` treat names
+    /// differing only in case as colliding.
+    ///
+    /// Windows and macOS filesystems are case-insensitive by default, so this
+    /// defaults to `true` on those platforms and `false` elsewhere.
+    ///
+    /// # Arguments
+    ///
+    /// * `case_insensitive` - A boolean indicating if collisions ignore case.
+    pub fn case_insensitive_collisions(mut self, case_insensitive: bool) -> Self {
+        self.case_insensitive_collisions = case_insensitive;
+        self
+    }
+
+    /// Sets whether `try_slugify` should reject words that mix scripts.
+    ///
+    /// Words like "pаypal" (with a Cyrillic `а`) look identical to their Latin
+    /// counterparts, which makes them dangerous in user-visible identifiers.
+    /// When enabled, `try_slugify` returns [`Error::MixedScript`] for such input.
+    /// `slugify` is unaffected and always transliterates the whole input to ASCII.
+    ///
+    /// # Arguments
+    ///
+    /// * `reject` - A boolean indicating if mixed-script words should be rejected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// let slugifier = Slugifier::new().reject_mixed_scripts(true);
+    /// assert!(slugifier.try_slugify("pаypal login").is_err());
+    /// assert_eq!(slugifier.try_slugify("paypal login").unwrap(), "paypal-login");
+    /// ```
+    pub fn reject_mixed_scripts(mut self, reject: bool) -> Self {
+        self.reject_mixed_scripts = reject;
+        self
+    }
+
+    /// Configures the slugifier for a commonly required slug format.
+    ///
+    /// The preset sets the relevant builder options, which can still be adjusted
+    /// afterwards, and adds format-specific rules enforced by `slugify` and checked
+    /// by `validate`.
+    ///
+    /// # Arguments
+    ///
+    /// * `preset` - The format to produce slugs for.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::{Preset, Slugifier};
+    /// let slugifier = Slugifier::new().preset(Preset::DnsLabel);
+    /// assert_eq!(slugifier.slugify("Tenant: ACME Corp."), "tenant-acme-corp");
+    /// ```
+    pub fn preset(mut self, preset: Preset) -> Self {
+        self = preset.configure(self);
+        self.preset = Some(preset);
+        self
+    }
+
+    /// Checks the configuration, returning the slugifier if it is consistent.
+    ///
+    /// The builder methods accept any separator, but one containing an
+    /// alphanumeric character, a character of `remove_chars`, or a character
+    /// the active preset doesn't allow produces slugs that fail `validate` or
+    /// change when slugified again.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidSeparator`] with the first offending character
+    /// of the separator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::{Error, Preset, Slugifier};
+    ///
+    /// assert!(Slugifier::new().separator("_").try_build().is_ok());
+    /// assert_eq!(
+    ///     Slugifier::new().separator("x").try_build().unwrap_err(),
+    ///     Error::InvalidSeparator { separator: "x".to_string(), ch: 'x' }
+    /// );
+    /// assert!(Slugifier::new().preset(Preset::DnsLabel).separator("_").try_build().is_err());
+    /// ```
+    pub fn try_build(self) -> Result<Self, Error> {
+        // The characters the preset produces on its own, with its own separator.
+        let preset_defaults = self.preset.map(|preset| preset.configure(Slugifier::new()));
+        let is_valid = |c: char| {
+            !c.is_alphanumeric()
+                && !self.remove_chars.contains(c)
+                && self
+                    .preset
+                    .zip(preset_defaults.as_ref())
+                    .is_none_or(|(preset, defaults)| {
+                        defaults.separator.contains(c)
+                            || defaults.allowed_chars.contains(c)
+                            || preset.allows_char(c)
+                    })
+        };
+        if let Some(ch) = self.separator.chars().find(|&c| !is_valid(c)) {
+            return Err(Error::InvalidSeparator {
+                separator: self.separator.clone(),
+                ch,
+            });
+        }
+
+        Ok(self)
+    }
+
+    /// Sanitizes a string to create a valid and safe filename.
+    ///
+    /// This method is more conservative than `slugify`. It preserves case and spaces,
+    /// and only removes or replaces characters that are illegal in file paths on
+    /// major operating systems (e.g., `/`, `\`, `:`, `*`, `?`, `"`) or that were
+    /// configured with `illegal_chars`. ASCII control characters are treated as
+    /// illegal too, invisible characters such as zero-width spaces, soft
+    /// hyphens, and bidi controls are always removed, and each run of
+    /// whitespace, including no-break spaces, becomes a single space. Windows
+    /// reserved device names are renamed according to `reserved_name_prefix`,
+    /// and the result is limited to `max_filename_bytes` if set. The name is
+    /// first converted to the `filename_normalization` form, if any. The names
+    /// `.` and `..`, which name directories on every filesystem, become `_`.
+    ///
+    /// # Arguments
+    ///
+    /// * `filename` - The string to sanitize.
+    pub fn sanitize_filename(&self, filename: &str) -> String {
+        let sanitized = self.sanitize_name(filename);
+        if matches!(sanitized.as_str(), "." | "..") {
+            filename::PLACEHOLDER_NAME.to_string()
+        } else {
+            sanitized
+        }
+    }
+
+    /// Sanitizes a filename like `sanitize_filename`, but leaves the names `.`
+    /// and `..` as they are, for callers that drop them.
+    pub(crate) fn sanitize_name(&self, filename: &str) -> String {
+        use unicode_normalization::UnicodeNormalization;
+
+        let filename: String = match self.filename_normalization {
+            Some(Normalization::Nfc) => filename.nfc().collect(),
+            Some(Normalization::Nfd) => filename.nfd().collect(),
+            None => filename.to_string(),
+        };
+        let mut sanitized = String::with_capacity(filename.len());
+        let mut last_char_was_boundary = false;
+
+        for c in filename.chars() {
+            if chars::is_invisible(c) {
+                continue;
+            }
+
+            if self.illegal_chars.contains(&c) || c.is_control() || c.is_whitespace() {
+                if !last_char_was_boundary {
+                    if c.is_whitespace() {
+                        sanitized.push(' ');
+                    } else {
+                        sanitized.push_str(&self.sanitize_replacement);
+                    }
+                    last_char_was_boundary = true;
+                }
+            } else {
+                sanitized.push(c);
+                last_char_was_boundary = false;
+            }
+        }
+
+        // Trim leading/trailing boundaries which manifest as spaces or replacements
+        let mut sanitized = sanitized.trim();
+        if self.trim_trailing_dots {
+            sanitized = sanitized.trim_end_matches(['.', ' ']);
+        }
+
+        let prefix_reserved = |name: String| match &self.reserved_name_prefix {
+            Some(prefix) if filename::is_windows_reserved(&name) => format!("{prefix}{name}"),
+            _ => name,
+        };
+        let sanitized = prefix_reserved(sanitized.to_string());
+
+        let Some(max_bytes) = self.max_filename_bytes else {
+            return sanitized;
+        };
+        let truncated =
+            filename::truncate_preserving_extension(&sanitized, max_bytes, self.trim_trailing_dots);
+        if truncated == sanitized {
+            return sanitized;
+        }
+        // Cutting the stem can leave a reserved name, like "CON.txt" from
+        // "CONx.txt", which is then prefixed and cut again.
+        filename::truncate_preserving_extension(
+            &prefix_reserved(truncated),
+            max_bytes,
+            self.trim_trailing_dots,
+        )
+    }
+
+    /// Helper function to apply the truncation logic to a mutable slug string.
+    pub fn apply_truncation(&self, slug: &mut String) {
+        if let Some(max_len) = self.truncate {
+            self.truncate_to(slug, max_len);
+        }
+    }
+
+    /// Truncates `slug` to at most `max_len` bytes like `truncate` says.
+    pub(crate) fn truncate_to(&self, slug: &mut String, max_len: usize) {
+        if slug.len() > max_len {
+            let max_len = chars::floor_grapheme_boundary(slug, max_len);
+            if self.word_boundary && !self.separator.is_empty() {
+                if !self.save_order {
+                    let mut packed = String::with_capacity(max_len);
+                    for word in slug.split(self.separator.as_str()) {
+                        let sep_len = if packed.is_empty() {
+                            0
+                        } else {
+                            self.separator.len()
+                        };
+                        if !word.is_empty() && packed.len() + sep_len + word.len() <= max_len {
+                            if !packed.is_empty() {
+                                packed.push_str(&self.separator);
+                            }
+                            packed.push_str(word);
+                        }
+                    }
+                    if !packed.is_empty() {
+                        *slug = packed;
+                        return;
+                    }
+                } else if let Some(last_sep_index) = slug[..max_len].rfind(&self.separator) {
+                    slug.truncate(last_sep_index);
+                    return;
+                }
+            }
+
+            // If no separator was found (or separator is empty), hard-truncate,
+            // making sure the slug doesn't end in the middle of a separator or
+            // with a character that is only allowed inside words.
+            slug.truncate(max_len);
+            let trimmed_len = slug
+                .trim_end_matches(|c| self.separator.contains(c) || self.allowed_chars.contains(c))
+                .len();
+            slug.truncate(trimmed_len);
+        }
+    }
+
+    /// Generates a slug from the given text based on the current configuration.
+    ///
+    /// Invisible characters (zero-width spaces and joiners, soft hyphens, and
+    /// bidi controls) are removed before transliteration, so they never split
+    /// a word or produce empty segments.
+    ///
+    /// # Examples
+    /// ```
+    /// use rslug::Slugifier;
+    ///
+    /// let slugifier = Slugifier::new();
+    /// let a = slugifier.slugify("Hello, World!");
+    /// let b = slugifier.slugify("Slugs are slow, but cool");
+    ///
+    /// assert_eq!(a, "hello-world");
+    /// assert_eq!(b, "slugs-are-slow-but-cool");
+    /// ```
+    pub fn slugify(&self, text: &str) -> String {
+        let mut slug = String::new();
+        self.slugify_into(text, &mut slug);
+        slug
+    }
+
+    /// Generates a slug like `slugify`, writing it into `buffer`.
+    ///
+    /// The buffer is cleared first, and its allocation is reused, which avoids
+    /// allocating a new string per slug in hot loops.
+    ///
+    /// # Examples
+    /// ```
+    /// use rslug::Slugifier;
+    ///
+    /// let slugifier = Slugifier::new();
+    /// let mut slug = String::with_capacity(64);
+    /// for title in ["First Post", "Second Post"] {
+    ///     slugifier.slugify_into(title, &mut slug);
+    ///     assert!(slug.ends_with("-post"));
+    /// }
+    /// ```
+    pub fn slugify_into(&self, text: &str, buffer: &mut String) {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::trace_span!("slugify", len = text.len()).entered();
+
+        if let Some(handler) = &self.on_loss {
+            *buffer = self.slugify_reporting_loss(text, handler);
+            return;
+        }
+
+        let len = text.len();
+        let text = symbols::apply(text, self);
+        let text = text.as_ref();
+        trace::stage!("replacement", len, text);
+
+        if let Some(preset) = self.preset
+            && let Some(slug) = preset.slugify(self, text)
+        {
+            trace::stage!("preset", text.len(), &slug);
+            buffer.clear();
+            buffer.push_str(&slug);
+            return;
+        }
+
+        buffer.clear();
+        buffer.reserve(self.estimate_built_len(text));
+        let mut builder = SlugBuilder::with_buffer(self, std::mem::take(buffer));
+        builder.push_str(text);
+        // Writing to a `String` never fails.
+        let slug = builder.into_sink().unwrap_or_default();
+        trace::stage!("transliteration", text.len(), &slug);
+        *buffer = builder::finish_slug(self, slug);
+    }
+
+    /// Estimates the length in bytes of the slug `slugify` generates for `text`.
+    ///
+    /// The estimate is computed in a single pass without transliterating, by
+    /// counting the words and their bytes, so it is only an approximation:
+    /// stop words, removed or allowed characters, replacements, and converted
+    /// numbers, ordinals, and roman numerals all change the length of the
+    /// slug, and a non-ASCII character is counted as its UTF-8 length.
+    ///
+    /// # Examples
+    /// ```
+    /// use rslug::Slugifier;
+    ///
+    /// let slugifier = Slugifier::new().separator("--");
+    /// assert_eq!(slugifier.estimate_len("Hello, World!"), 12);
+    /// assert_eq!(slugifier.slugify("Hello, World!").len(), 12);
+    /// ```
+    pub fn estimate_len(&self, text: &str) -> usize {
+        let len = self.estimate_built_len(text);
+        self.truncate.map_or(len, |max| len.min(max))
+    }
+
+    /// Estimates the length of the slug before truncation, which is what the
+    /// output needs room for while it is built.
+    fn estimate_built_len(&self, text: &str) -> usize {
+        let mut len = 0;
+        let mut words: usize = 0;
+        let mut in_word = false;
+        for &c in text.as_bytes() {
+            let is_word_byte = !c.is_ascii() || c.is_ascii_alphanumeric();
+            if is_word_byte && !in_word {
+                words += 1;
+            }
+            if is_word_byte {
+                len += 1;
+            }
+            in_word = is_word_byte;
+        }
+
+        len + words.saturating_sub(1) * self.separator.len()
+    }
+
+    /// Generates a slug like `slugify`, borrowing `text` if it already is one.
+    ///
+    /// The input is scanned first, and if `slugify` would leave it unchanged
+    /// it is returned without allocating, which makes re-processing stored
+    /// slugs cheap.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use rslug::Slugifier;
+    ///
+    /// let slugifier = Slugifier::new();
+    /// assert!(matches!(slugifier.slugify_cow("hello-world"), Cow::Borrowed("hello-world")));
+    /// assert_eq!(slugifier.slugify_cow("Hello World"), "hello-world");
+    /// ```
+    pub fn slugify_cow<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        if self.is_slug(text) {
+            return Cow::Borrowed(text);
+        }
+
+        let slug = self.slugify(text);
+        if slug == text {
+            Cow::Borrowed(text)
+        } else {
+            Cow::Owned(slug)
+        }
+    }
+
+    /// Returns `true` if `slugify` is known to leave `text` unchanged, without
+    /// building the slug.
+    ///
+    /// This is conservative: configurations whose output is hard to predict,
+    /// like presets and replacements, always return `false`.
+    fn is_slug(&self, text: &str) -> bool {
+        if self.preset.is_some() || self.has_terms() || self.roman_numerals != RomanNumerals::Ignore
+        {
+            return false;
+        }
+        if (self.numbers != NumberMode::Keep || self.ordinals != OrdinalMode::Keep)
+            && text.bytes().any(|c| c.is_ascii_digit())
+        {
+            return false;
+        }
+        if self
+            .separator
+            .contains(|c: char| c.is_ascii_alphanumeric() || self.remove_chars.contains(c))
+        {
+            return false;
+        }
+        if self.truncate.is_some_and(|max| text.len() > max) {
+            return false;
+        }
+
+        let bytes = text.as_bytes();
+        let separator = self.separator.as_bytes();
+        let mut i = 0;
+        while let Some(&c) = bytes.get(i) {
+            let action = self.classes.action(c);
+            if action == ByteAction::Keep {
+                i += 1;
+                continue;
+            }
+            if c.is_ascii_alphanumeric() {
+                return false;
+            }
+
+            // Separators and allowed characters only appear between two alphanumerics.
+            if i == 0 {
+                return false;
+            }
+            if !separator.is_empty() && bytes[i..].starts_with(separator) {
+                i += separator.len();
+            } else if action == ByteAction::Allow {
+                i += 1;
+            } else {
+                return false;
+            }
+            if !bytes.get(i).is_some_and(u8::is_ascii_alphanumeric) {
+                return false;
+            }
+        }
+
+        if let Some(max) = self.max_segments
+            && !self.separator.is_empty()
+            && text.split(self.separator.as_str()).count() > max
+        {
+            return false;
+        }
+
+        self.stop_words.is_empty()
+            || self.separator.is_empty()
+            || !text
+                .split(self.separator.as_str())
+                .any(|word| self.stop_words.iter().any(|s| s.eq_ignore_ascii_case(word)))
+    }
+
+    /// Generates a slug from the given text, failing if the input is rejected
+    /// by the current configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MixedScript`] if `reject_mixed_scripts` is enabled and a
+    /// word of the input mixes letters from more than one script, or
+    /// [`Error::Empty`] if nothing of the input is left in the slug.
+    ///
+    /// # Examples
+    /// ```
+    /// use rslug::{Error, Slugifier};
+    ///
+    /// let slugifier = Slugifier::new().reject_mixed_scripts(true);
+    /// let err = slugifier.try_slugify("Аpple Store").unwrap_err();
+    ///
+    /// assert_eq!(err, Error::MixedScript { word: "Аpple".to_string() });
+    /// ```
+    pub fn try_slugify(&self, text: &str) -> Result<String, Error> {
+        if self.reject_mixed_scripts
+            && let Some(word) = script::find_mixed_script_word(text)
+        {
+            return Err(Error::MixedScript {
+                word: word.to_string(),
+            });
+        }
+
+        let slug = self.slugify(text);
+        if slug.is_empty() {
+            return Err(Error::Empty);
+        }
+
+        Ok(slug)
+    }
+
+    /// Checks whether `slug` is a valid slug for the current configuration.
+    ///
+    /// A valid slug is non-empty, fits the `truncate` length, only contains
+    /// characters the configuration produces, satisfies the rules of the active
+    /// preset, and is left unchanged by `slugify`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first rule the slug violates: [`Error::Empty`],
+    /// [`Error::TooLong`], [`Error::InvalidChar`], a preset-specific error, or
+    /// [`Error::NotNormalized`].
     ///
     /// # Examples
     /// ```
-    /// use rslug::Slugifier;
+    /// use rslug::{Error, Slugifier};
     ///
     /// let slugifier = Slugifier::new();
-    /// let a = slugifier.slugify("Hello, World!");
-    /// let b = slugifier.slugify("Slugs are slow, but cool");
-    ///
-    /// assert_eq!(a, "hello-world");
-    /// assert_eq!(b, "slugs-are-slow-but-cool");
+    /// assert_eq!(slugifier.validate("hello-world"), Ok(()));
+    /// assert_eq!(slugifier.validate("hello--world"), Err(Error::NotNormalized));
+    /// assert_eq!(
+    ///     slugifier.validate("Hello-world"),
+    ///     Err(Error::InvalidChar { ch: 'H', index: 0 })
+    /// );
     /// ```
-    pub fn slugify(&self, text: &str) -> String {
-        use any_ascii::any_ascii;
-
-        let text = any_ascii(text);
-        let mut slug = String::new();
-        let mut found_sep = false;
-
-        for c in text.into_bytes() {
-            if c.is_ascii_alphanumeric() {
-                // If a separator was found before, add it before the character.
-                if found_sep && !slug.is_empty() {
-                    slug.push_str(&self.separator);
-                }
+    pub fn validate(&self, slug: &str) -> Result<(), Error> {
+        if slug.is_empty() {
+            return Err(Error::Empty);
+        }
 
-                // Push the character.
-                slug.push(if self.to_lowercase {
-                    c.to_ascii_lowercase()
-                } else {
-                    c
-                } as char);
+        if let Some(max) = self.truncate
+            && slug.len() > max
+        {
+            return Err(Error::TooLong {
+                len: slug.len(),
+                max,
+            });
+        }
 
-                found_sep = false;
-            } else {
-                found_sep = true;
+        let is_valid_char = |c: char| {
+            (c.is_ascii_alphanumeric() && !(self.to_lowercase && c.is_ascii_uppercase()))
+                || self.separator.contains(c)
+                || self.allowed_chars.contains(c)
+                || (self.preserve_versions && c == '.')
+                || (self.roman_numerals == RomanNumerals::PreserveCase && "IVXLCDM".contains(c))
+                || self.preset.is_some_and(|preset| preset.allows_char(c))
+        };
+        // The hex digits of a percent-encoded byte are valid in either case.
+        let mut escape_end = 0;
+        let invalid = slug.char_indices().find(|&(index, c)| {
+            if c == '%' && is_valid_char(c) {
+                escape_end = index + 3;
             }
+            !(is_valid_char(c) || (index < escape_end && c.is_ascii_hexdigit()))
+        });
+        if let Some((index, ch)) = invalid {
+            return Err(Error::InvalidChar { ch, index });
+        }
+
+        if let Some(preset) = self.preset {
+            preset.check(slug)?;
         }
 
-        self.apply_truncation(&mut slug);
+        if let Cow::Owned(_) = self.slugify_cow(slug) {
+            return Err(Error::NotNormalized);
+        }
 
-        slug
+        Ok(())
     }
 
     /// Generates a slug from the given ASCII text.
@@ -264,48 +1642,44 @@ impl Slugifier {
     /// ```
     pub fn slugify_ascii(&self, text: &[u8]) -> String {
         let mut slug = String::new();
-        let mut found_sep = false;
-
-        for &c in text {
-            if c.is_ascii_alphanumeric() {
-                // If a separator was found before, add it before the character.
-                if found_sep && !slug.is_empty() {
-                    slug.push_str(&self.separator);
-                }
-
-                // Push the character.
-                slug.push(if self.to_lowercase {
-                    c.to_ascii_lowercase()
-                } else {
-                    c
-                } as char);
-
-                found_sep = false;
-            } else {
-                found_sep = true;
-            }
-        }
-
-        self.apply_truncation(&mut slug);
-
+        self.slugify_ascii_into(text, &mut slug);
         slug
     }
+
+    /// Generates a slug like `slugify_ascii`, writing it into `buffer`.
+    ///
+    /// The buffer is cleared first, and its allocation is reused.
+    ///
+    /// # Examples
+    /// ```
+    /// use rslug::Slugifier;
+    ///
+    /// let mut slug = String::new();
+    /// Slugifier::new().slugify_ascii_into(b"Hello, World!", &mut slug);
+    /// assert_eq!(slug, "hello-world");
+    /// ```
+    pub fn slugify_ascii_into(&self, text: &[u8], buffer: &mut String) {
+        let mut builder = SlugBuilder::with_buffer(self, std::mem::take(buffer));
+        builder.push_bytes(text);
+        *buffer = builder.finish();
+    }
 }
 
 /// A convenient macro to slugify a string with default settings.
 ///
-/// This is a shortcut for `Slugifier::new().slugify(text)`.
+/// This is a shortcut for `rslug::default().slugify(text)`, which uses
+/// `Slugifier::new()` unless another default was set with `set_default`.
 #[macro_export]
 macro_rules! slugify {
     ($text:expr) => {
-        $crate::Slugifier::new().slugify($text)
+        $crate::default().slugify($text)
     };
 }
 
 #[macro_export]
 macro_rules! slugify_ascii {
     ($text:expr) => {
-        $crate::Slugifier::new().slugify_ascii($text)
+        $crate::default().slugify_ascii($text)
     };
 }
 
@@ -328,6 +1702,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "transliteration")]
     fn test_unicode_slug() {
         assert_eq!(slugify!("你好世界 & Rust"), "nihaoshijie-rust");
     }
@@ -393,6 +1768,141 @@ mod tests {
         assert_eq!(slugifier.slugify(text), "supercalifragilistic");
     }
 
+    #[test]
+    fn test_slugify_into_reuses_buffer() {
+        let slugifier = Slugifier::new();
+        let mut slug = String::with_capacity(100);
+        let ptr = slug.as_ptr();
+        slugifier.slugify_into("Hello World", &mut slug);
+        assert_eq!(slug, "hello-world");
+        slugifier.slugify_into("Ünïcode", &mut slug);
+        assert_eq!(slug, "unicode");
+        assert_eq!(slug.as_ptr(), ptr);
+
+        slugifier.slugify_ascii_into(b"", &mut slug);
+        assert_eq!(slug, "");
+    }
+
+    #[test]
+    fn test_slugify_cow_borrows_slugs() {
+        let slugifier = Slugifier::new().allowed_chars(".");
+        for slug in ["hello-world", "v1.2-notes", "a", ""] {
+            assert!(
+                matches!(slugifier.slugify_cow(slug), Cow::Borrowed(_)),
+                "{slug}"
+            );
+        }
+        for text in ["Hello-world", "hello--world", "-hello", "v1.-2", "héllo"] {
+            assert!(
+                matches!(slugifier.slugify_cow(text), Cow::Owned(_)),
+                "{text}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_slugify_cow_matches_slugify() {
+        let slugifiers = [
+            Slugifier::new(),
+            Slugifier::new().separator("").to_lowercase(false),
+            Slugifier::new().separator("--").allowed_chars("-"),
+            Slugifier::new().stop_words(&["the"]).truncate(10),
+            Slugifier::new().preset(Preset::RustIdent),
+            Slugifier::new()
+                .to_lowercase(false)
+                .roman_numerals(RomanNumerals::Arabic),
+            Slugifier::new().roman_numerals(RomanNumerals::PreserveCase),
+        ];
+        let inputs = [
+            "Chapter-IV",
+            "chapter-iv",
+            "the-end",
+            "a--b",
+            "a-b",
+            "ab",
+            "AB",
+            "loop",
+            "0-day",
+            "hello-world-again",
+        ];
+        for slugifier in &slugifiers {
+            for text in inputs {
+                assert_eq!(
+                    slugifier.slugify_cow(text),
+                    slugifier.slugify(text),
+                    "{text}"
+                );
+            }
+        }
+
+        let slugifier = Slugifier::new()
+            .to_lowercase(false)
+            .roman_numerals(RomanNumerals::Arabic);
+        assert!(slugifier.validate("Chapter-IV").is_err());
+        assert!(slugifier.validate("Chapter-4").is_ok());
+    }
+
+    #[test]
+    fn test_estimate_len() {
+        let slugifier = Slugifier::new();
+        for text in ["Hello, World!", "  --a  b--  ", "", "one"] {
+            assert_eq!(slugifier.estimate_len(text), slugifier.slugify(text).len());
+        }
+        assert_eq!(Slugifier::new().truncate(5).estimate_len("Hello World"), 5);
+        assert_eq!(slugifier.estimate_len("Ünïcode"), 9);
+    }
+
+    #[test]
+    fn test_stop_words() {
+        let slugifier = Slugifier::new().stop_words(&["a", "an", "the"]);
+        assert_eq!(
+            slugifier.slugify("A Tale of Two Cities"),
+            "tale-of-two-cities"
+        );
+        assert_eq!(slugifier.slugify("Theory of the Ant"), "theory-of-ant");
+        assert_eq!(slugifier.slugify("The"), "");
+    }
+
+    #[test]
+    fn test_remove_chars() {
+        let slugifier = Slugifier::new().remove_chars("'’");
+        assert_eq!(
+            slugifier.slugify("It’s the user's choice"),
+            "its-the-users-choice"
+        );
+        assert_eq!(slugifier.slugify_ascii(b"rock 'n' roll"), "rock-n-roll");
+    }
+
+    #[test]
+    fn test_truncation_without_word_boundary() {
+        let slugifier = Slugifier::new().truncate(10).word_boundary(false);
+        assert_eq!(slugifier.slugify("hello wonderful world"), "hello-wond");
+        assert_eq!(slugifier.slugify("hello world foo"), "hello-worl");
+        let slugifier = Slugifier::new().truncate(6).word_boundary(false);
+        assert_eq!(slugifier.slugify("hello world"), "hello");
+    }
+
+    #[test]
+    fn test_truncation_without_save_order() {
+        let slugifier = Slugifier::new().truncate(10).save_order(false);
+        assert_eq!(slugifier.slugify("jaja lol mememeoo a"), "jaja-lol-a");
+        assert_eq!(slugifier.slugify("supercalifragilistic"), "supercalif");
+    }
+
+    #[test]
+    fn test_allowed_chars_only_inside_words() {
+        let slugifier = Slugifier::new().allowed_chars(".");
+        assert_eq!(slugifier.slugify("example.com"), "example.com");
+        assert_eq!(slugifier.slugify(".hidden file."), "hidden-file");
+        assert_eq!(slugifier.slugify("a..b . c"), "a-b-c");
+    }
+
+    #[test]
+    fn test_hard_truncation_trims_allowed_chars() {
+        let slugifier = Slugifier::new().allowed_chars(".").truncate(4);
+        assert_eq!(slugifier.slugify("abc.def"), "abc");
+    }
+
     #[test]
     fn test_truncation_not_needed() {
         let slugifier = Slugifier::new().truncate(50);
@@ -407,6 +1917,435 @@ mod tests {
         assert_eq!(slugifier.slugify_ascii(text), "an-ascii-title");
     }
 
+    #[test]
+    fn test_invisible_characters_are_stripped() {
+        assert_eq!(slugify!("zero\u{200B}width joiner"), "zerowidth-joiner");
+        assert_eq!(slugify!("\u{202E}Hello\u{202C} World"), "hello-world");
+        assert_eq!(slugify!("\u{200B}\u{200D}"), "");
+    }
+
+    #[test]
+    fn test_sanitize_filename_control_characters() {
+        let slugifier = Slugifier::new();
+        assert_eq!(
+            slugifier.sanitize_filename("null\0byte\x1b.txt"),
+            "nullbyte.txt"
+        );
+        assert_eq!(
+            slugifier.sanitize_filename("two\nlines.txt"),
+            "two lines.txt"
+        );
+
+        let slugifier = Slugifier::new().sanitize_replacement("_");
+        assert_eq!(slugifier.sanitize_filename("bell\x07.txt"), "bell_.txt");
+    }
+
+    #[test]
+    fn test_sanitize_filename_custom_illegal_chars() {
+        let slugifier = Slugifier::new()
+            .illegal_chars(&['#', '%'])
+            .sanitize_replacement("-");
+        assert_eq!(
+            slugifier.sanitize_filename("100% #1 a/b.md"),
+            "100-1 a/b.md"
+        );
+    }
+
+    #[test]
+    fn test_sanitize_filename_reserved_names() {
+        let slugifier = Slugifier::new();
+        assert_eq!(slugifier.sanitize_filename("CON"), "_CON");
+        assert_eq!(slugifier.sanitize_filename("lpt1.txt"), "_lpt1.txt");
+        assert_eq!(slugifier.sanitize_filename("Console.txt"), "Console.txt");
+
+        let slugifier = Slugifier::new().reserved_name_prefix(None);
+        assert_eq!(slugifier.sanitize_filename("aux.log"), "aux.log");
+
+        // Truncation can leave a reserved name, which is renamed too.
+        let slugifier = Slugifier::new().max_filename_bytes(7);
+        assert_eq!(slugifier.sanitize_filename("CONx.txt"), "_CO.txt");
+        assert_eq!(slugifier.sanitize_filename("nul.txt"), "_nu.txt");
+    }
+
+    #[test]
+    fn test_sanitize_filename_trailing_dots_and_spaces() {
+        let slugifier = Slugifier::new();
+        assert_eq!(slugifier.sanitize_filename("notes. . "), "notes");
+        assert_eq!(slugifier.sanitize_filename("CON."), "_CON");
+        assert_eq!(slugifier.sanitize_filename(".hidden"), ".hidden");
+
+        let slugifier = Slugifier::new().trim_trailing_dots(false);
+        assert_eq!(slugifier.sanitize_filename("notes. . "), "notes. .");
+        let slugifier = slugifier.max_filename_bytes(6);
+        assert_eq!(slugifier.sanitize_filename("abc.. .x......"), "abc...");
+    }
+
+    #[test]
+    fn test_sanitize_filename_max_bytes() {
+        let slugifier = Slugifier::new().max_filename_bytes(255);
+        let long_name = format!("{}.pdf", "x".repeat(300));
+        let sanitized = slugifier.sanitize_filename(&long_name);
+        assert_eq!(sanitized.len(), 255);
+        assert!(sanitized.ends_with("x.pdf"));
+    }
+
+    #[test]
+    fn test_sanitize_filename_fs_profiles() {
+        let name = "CON draft?:.";
+
+        let posix = Slugifier::new().fs_profile(FsProfile::Posix);
+        assert_eq!(posix.sanitize_filename(name), "CON draft?:.");
+
+        let apfs = Slugifier::new().fs_profile(FsProfile::Apfs);
+        assert_eq!(apfs.sanitize_filename(name), "CON draft?.");
+
+        let portable = Slugifier::new().fs_profile(FsProfile::Portable);
+        assert_eq!(portable.sanitize_filename(name), "CON draft");
+        assert_eq!(portable.sanitize_filename("nul.txt"), "_nul.txt");
+    }
+
+    #[test]
+    fn test_sanitize_filename_normalization() {
+        let composed = "R\u{e9}sum\u{e9}.pdf";
+        let decomposed = "Re\u{301}sume\u{301}.pdf";
+
+        let nfc = Slugifier::new().filename_normalization(Some(Normalization::Nfc));
+        assert_eq!(nfc.sanitize_filename(decomposed), composed);
+
+        let apfs = Slugifier::new().fs_profile(FsProfile::Apfs);
+        assert_eq!(apfs.sanitize_filename(composed), decomposed);
+
+        assert_eq!(Slugifier::new().sanitize_filename(decomposed), decomposed);
+    }
+
+    #[test]
+    fn test_sanitize_filename_strips_invisible_characters() {
+        let slugifier = Slugifier::new();
+        assert_eq!(
+            slugifier.sanitize_filename("invoice\u{202E}fdp.exe"),
+            "invoicefdp.exe"
+        );
+        assert_eq!(
+            slugifier.sanitize_filename("re\u{00AD}port.txt"),
+            "report.txt"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "transliteration")]
+    fn test_try_slugify_rejects_mixed_scripts() {
+        let slugifier = Slugifier::new().reject_mixed_scripts(true);
+        assert_eq!(
+            slugifier.try_slugify("Visit gооgle now"),
+            Err(Error::MixedScript {
+                word: "gооgle".to_string()
+            })
+        );
+        assert_eq!(
+            slugifier.try_slugify("Привет World").unwrap(),
+            "privet-world"
+        );
+    }
+
+    #[test]
+    fn test_try_slugify_rejects_empty_result() {
+        let slugifier = Slugifier::new();
+        assert_eq!(slugifier.try_slugify("?!"), Err(Error::Empty));
+    }
+
+    #[test]
+    fn test_validate() {
+        let slugifier = Slugifier::new().separator("_").truncate(10);
+        assert_eq!(slugifier.validate("valid_slug"), Ok(()));
+        assert_eq!(slugifier.validate("_leading"), Err(Error::NotNormalized));
+        assert_eq!(
+            slugifier.validate("much_too_long"),
+            Err(Error::TooLong { len: 13, max: 10 })
+        );
+        assert_eq!(
+            slugifier.validate("a-b"),
+            Err(Error::InvalidChar { ch: '-', index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_preserve_versions() {
+        let slugifier = Slugifier::new().preserve_versions(true);
+        assert_eq!(
+            slugifier.slugify("Python 3.12. Released!"),
+            "python-3.12-released"
+        );
+        assert_eq!(slugifier.slugify("e.g. 1.x or 2..3"), "e-g-1-x-or-2-3");
+        assert_eq!(slugifier.slugify("3.14159"), "3.14159");
+        assert_eq!(slugifier.validate("v1.2.3"), Ok(()));
+        assert_eq!(Slugifier::new().slugify("v1.2.3"), "v1-2-3");
+    }
+
+    #[test]
+    fn test_roman_numerals() {
+        let preserve = Slugifier::new().roman_numerals(RomanNumerals::PreserveCase);
+        let arabic = Slugifier::new().roman_numerals(RomanNumerals::Arabic);
+        let text = "Book II, Part XIV-b: CIVIL War of MMXXIV in VIé";
+        assert_eq!(
+            preserve.slugify(text),
+            "book-II-part-XIV-b-civil-war-of-MMXXIV-in-vie"
+        );
+        assert_eq!(
+            arabic.slugify(text),
+            "book-2-part-14-b-civil-war-of-2024-in-vie"
+        );
+        assert_eq!(arabic.slugify("I am X"), "i-am-x");
+        assert_eq!(preserve.validate("part-IV"), Ok(()));
+        assert_eq!(Slugifier::new().slugify("Part IV"), "part-iv");
+    }
+
+    #[test]
+    fn test_numbers_as_words() {
+        let slugifier = Slugifier::new().numbers(NumberMode::Words);
+        assert_eq!(
+            slugifier.slugify("Top 10 Tips for 2024, v2 and 007"),
+            "top-ten-tips-for-two-thousand-twenty-four-v2-and-007"
+        );
+        assert_eq!(slugifier.slugify("1.5 or 1,000"), "1-5-or-1-000");
+        assert_eq!(
+            slugifier
+                .clone()
+                .roman_numerals(RomanNumerals::Arabic)
+                .slugify("Rocky IV"),
+            "rocky-four"
+        );
+        assert_eq!(slugifier.validate("top-10"), Err(Error::NotNormalized));
+        assert_eq!(slugifier.validate("top-ten"), Ok(()));
+
+        let mut title = String::from("Ocean's 11");
+        slugifier.slugify_in_place(&mut title);
+        assert_eq!(title, "ocean-s-eleven");
+    }
+
+    #[test]
+    #[cfg(feature = "transliteration")]
+    fn test_numbers_next_to_non_ascii() {
+        let slugifier = Slugifier::new().numbers(NumberMode::Words);
+        for (text, expected) in [
+            ("Top １０ tips", "top-ten-tips"),
+            ("10€ sale", "ten-eur-sale"),
+            ("€10 sale", "eur-ten-sale"),
+            ("Top 10½ tips", "top-ten-one-two-tips"),
+            ("x１０ café2", "x10-cafe2"),
+        ] {
+            let slug = slugifier.slugify(text);
+            assert_eq!(slug, expected, "{text}");
+            assert_eq!(slugifier.slugify(&slug), slug);
+        }
+    }
+
+    #[test]
+    fn test_numbers_removed() {
+        let slugifier = Slugifier::new().numbers(NumberMode::Remove);
+        assert_eq!(
+            slugifier.slugify("10 Best Albums of 2023 (v2, 4.5 stars)"),
+            "best-albums-of-v2-stars"
+        );
+        assert_eq!(slugifier.slugify("Python 3.12.1 or 1.5x"), "python-or-1-5x");
+        assert_eq!(slugifier.slugify("404"), "");
+        assert_eq!(
+            slugifier
+                .clone()
+                .separator("_")
+                .slugify("1984 by George Orwell"),
+            "by_george_orwell"
+        );
+
+        for (text, expected) in [("Top １０ tips", "top-tips"), ("Top 10½ tips", "top-tips")] {
+            let slug = slugifier.slugify(text);
+            assert_eq!(slug, expected, "{text}");
+            assert_eq!(slugifier.slugify(&slug), slug);
+        }
+        #[cfg(feature = "transliteration")]
+        assert_eq!(slugifier.slugify("10€ sale"), "eur-sale");
+    }
+
+    #[test]
+    fn test_ordinals() {
+        let text = "1st, 2ND and 13th Place, not 1th or 5";
+        assert_eq!(
+            Slugifier::new().slugify(text),
+            "1st-2nd-and-13th-place-not-1th-or-5"
+        );
+        assert_eq!(
+            Slugifier::new().ordinals(OrdinalMode::Digits).slugify(text),
+            "1-2-and-13-place-not-1th-or-5"
+        );
+        let words = Slugifier::new()
+            .ordinals(OrdinalMode::Words)
+            .numbers(NumberMode::Words);
+        assert_eq!(
+            words.slugify(text),
+            "first-second-and-thirteenth-place-not-1th-or-five"
+        );
+        assert_eq!(
+            Slugifier::new()
+                .numbers(NumberMode::Remove)
+                .slugify("3rd of 5"),
+            "3rd-of"
+        );
+        assert_eq!(words.validate("1st-place"), Err(Error::NotNormalized));
+    }
+
+    #[test]
+    fn test_dates() {
+        let slugifier = Slugifier::new().dates(Some("%Y-%m-%d"));
+        for text in [
+            "Notes: May 1st, 2024",
+            "Notes: 1 May 2024",
+            "Notes 01.05.2024",
+        ] {
+            assert_eq!(slugifier.slugify(text), "notes-2024-05-01", "{text}");
+        }
+        assert_eq!(
+            slugifier.slugify("Call 2024-05-01x or 05/01/2024"),
+            "call-2024-05-01x-or-05-01-2024"
+        );
+        assert_eq!(
+            Slugifier::new()
+                .dates(Some("%b-%Y"))
+                .compile()
+                .slugify("Feb 29 2024 recap"),
+            "feb-2024-recap"
+        );
+        assert_eq!(slugifier.slugify_report("On 1 May 2024").1.replacements, 1);
+    }
+
+    #[test]
+    fn test_join_digit_groups() {
+        let slugifier = Slugifier::new().join_digit_groups(true);
+        assert_eq!(
+            slugifier.slugify("From 12 500 to 1,250,000 (v1.000.000)"),
+            "from-12500-to-1250000-v1-000-000"
+        );
+        assert_eq!(
+            slugifier
+                .clone()
+                .numbers(NumberMode::Words)
+                .slugify("1,000,000 Years"),
+            "one-million-years"
+        );
+
+        let (slug, spans) = slugifier.slugify_spans("Over 9,000");
+        assert_eq!(slug, "over-9000");
+        assert_eq!(spans[1].input, 5..10);
+    }
+
+    #[test]
+    fn test_no_break_spaces_and_soft_hyphens() {
+        let text = "Prix\u{202f}: 100\u{a0}% co\u{ad}opé\u{a0}\u{a0}ratif";
+        assert_eq!(Slugifier::new().slugify(text), "prix-100-coope-ratif");
+        assert_eq!(
+            Slugifier::new().sanitize_filename(text),
+            "Prix 100 % coopé ratif"
+        );
+        assert_eq!(
+            Slugifier::new()
+                .dates(Some("%Y-%m-%d"))
+                .join_digit_groups(true)
+                .slugify("1\u{a0}May\u{a0}2024: 10\u{202f}000 visits"),
+            "2024-05-01-10000-visits"
+        );
+    }
+
+    #[test]
+    fn test_unicode_words() {
+        let slugifier = Slugifier::new().unicode_words(true);
+        assert_eq!(
+            slugifier.slugify("Can't stop: col·lecció d'art, e.g. Node.js"),
+            "cant-stop-colleccio-dart-eg-nodejs"
+        );
+        assert_eq!(
+            slugifier.slugify("v1.2 'quoted' a'1 x..y"),
+            "v1-2-quoted-a-1-x-y"
+        );
+        assert_eq!(
+            slugifier.clone().allowed_chars("'").slugify("can't"),
+            "can't"
+        );
+        assert_eq!(Slugifier::new().slugify("can't"), "can-t");
+
+        let mut title = String::from("Rock'n'Roll");
+        slugifier.slugify_in_place(&mut title);
+        assert_eq!(title, "rocknroll");
+    }
+
+    #[test]
+    fn test_max_segments() {
+        let slugifier = Slugifier::new().stop_words(&["the"]).max_segments(2);
+        assert_eq!(slugifier.slugify("The Lord of the Rings"), "lord-of");
+        assert_eq!(slugifier.slugify("Dune"), "dune");
+        assert_eq!(slugifier.slugify_cow("a-b-c"), "a-b");
+        assert_eq!(slugifier.validate("a-b-c"), Err(Error::NotNormalized));
+        assert_eq!(slugifier.slugify_report("a b c").1.segments_removed, 1);
+
+        let unseparated = Slugifier::new().separator("").max_segments(1);
+        assert_eq!(unseparated.slugify("One Two"), "onetwo");
+    }
+
+    #[test]
+    fn test_separator_runs_and_edges() {
+        let text = "  ¡Hola, Mundo!  ";
+        let slugify = |collapse, trim| {
+            Slugifier::new()
+                .collapse_separators(collapse)
+                .trim_separators(trim)
+                .slugify(text)
+        };
+        assert_eq!(slugify(true, true), "hola-mundo");
+        assert_eq!(slugify(false, true), "hola--mundo");
+        assert_eq!(slugify(true, false), "-hola-mundo-");
+        assert_eq!(slugify(false, false), "---hola--mundo---");
+    }
+
+    #[test]
+    fn test_try_build_rejects_invalid_separators() {
+        for separator in ["", "-", "_", "--", "."] {
+            assert!(Slugifier::new().separator(separator).try_build().is_ok());
+        }
+        assert_eq!(
+            Slugifier::new().separator("-and-").try_build().unwrap_err(),
+            Error::InvalidSeparator {
+                separator: "-and-".to_string(),
+                ch: 'a'
+            }
+        );
+        assert!(
+            Slugifier::new()
+                .remove_chars("'")
+                .separator("'")
+                .try_build()
+                .is_err()
+        );
+        assert!(
+            Slugifier::new()
+                .preset(Preset::EmailLocalPart)
+                .separator("-")
+                .try_build()
+                .is_ok()
+        );
+        assert!(
+            Slugifier::new()
+                .preset(Preset::RustIdent)
+                .separator("-")
+                .try_build()
+                .is_err()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "transliteration")]
+    fn test_mixed_scripts_allowed_by_default() {
+        let slugifier = Slugifier::new();
+        assert_eq!(slugifier.try_slugify("gооgle").unwrap(), "google");
+        assert_eq!(slugifier.slugify("gооgle"), "google");
+    }
+
     #[test]
     fn test_sanitize_filename_default() {
         let slugifier = Slugifier::new();
@@ -439,4 +2378,14 @@ mod tests {
         let slugifier = Slugifier::new();
         assert_eq!(slugifier.sanitize_filename(""), "");
     }
+
+    #[test]
+    fn test_sanitize_filename_directory_entries() {
+        let posix = Slugifier::new().fs_profile(FsProfile::Posix);
+        assert_eq!(posix.sanitize_filename("."), "_");
+        assert_eq!(posix.sanitize_filename(".."), "_");
+        assert_eq!(posix.sanitize_filename(".. "), "_");
+        assert_eq!(posix.sanitize_filename("..."), "...");
+        assert_eq!(Slugifier::new().sanitize_filename(".."), "");
+    }
 }
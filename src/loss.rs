@@ -0,0 +1,152 @@
+//! Callbacks for slugs that lost part of their input.
+
+use std::fmt;
+use std::sync::Arc;
+
+use crate::Slugifier;
+
+/// A way a slug lost part of its input, passed to the callback set with
+/// [`Slugifier::on_loss`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LossEvent {
+    /// Characters were dropped, like punctuation and symbols without a
+    /// transliteration. Whitespace isn't counted.
+    DroppedChars {
+        /// The number of characters dropped.
+        count: usize,
+    },
+    /// The slug was truncated.
+    Truncated {
+        /// The length of the slug before it was truncated.
+        from: usize,
+        /// The length of the truncated slug.
+        to: usize,
+    },
+    /// The slug is empty.
+    Empty,
+}
+
+/// The callback set with `Slugifier::on_loss`.
+#[derive(Clone)]
+pub(crate) struct LossHandler(Arc<dyn Fn(LossEvent) + Send + Sync>);
+
+impl fmt::Debug for LossHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("LossHandler")
+    }
+}
+
+impl Slugifier {
+    /// Sets a callback that is called when a slug loses part of its input:
+    /// when characters are dropped, when the slug is truncated, or when it
+    /// is empty.
+    ///
+    /// This lets applications log or surface warnings about slugs that may
+    /// not identify their input well. The callback is called by `slugify`
+    /// and the methods built on it, like `slugify_reader`, which then take
+    /// the slower path of [`slugify_report`](Slugifier::slugify_report). The
+    /// `slugify_ascii` fast path doesn't call it.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - The function called with each `LossEvent`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::{Arc, Mutex};
+    /// use rslug::{LossEvent, Slugifier};
+    ///
+    /// let events = Arc::new(Mutex::new(Vec::new()));
+    /// let log = Arc::clone(&events);
+    /// let slugifier = Slugifier::new().on_loss(move |event| log.lock().unwrap().push(event));
+    ///
+    /// assert_eq!(slugifier.slugify("Tom & Jerry"), "tom-jerry");
+    /// assert_eq!(*events.lock().unwrap(), [LossEvent::DroppedChars { count: 1 }]);
+    /// ```
+    pub fn on_loss(mut self, handler: impl Fn(LossEvent) + Send + Sync + 'static) -> Self {
+        self.on_loss = Some(LossHandler(Arc::new(handler)));
+        self
+    }
+
+    /// Generates a slug, calling `handler` with what was lost.
+    pub(crate) fn slugify_reporting_loss(&self, text: &str, handler: &LossHandler) -> String {
+        let (slug, report) = self.slugify_report(text);
+        if report.dropped_chars > 0 {
+            (handler.0)(LossEvent::DroppedChars {
+                count: report.dropped_chars,
+            });
+        }
+        if let Some(from) = report.truncated_from {
+            (handler.0)(LossEvent::Truncated {
+                from,
+                to: slug.len(),
+            });
+        }
+        if slug.is_empty() {
+            (handler.0)(LossEvent::Empty);
+        }
+        slug
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    fn slugify_logged(slugifier: Slugifier, text: &str) -> (String, Vec<LossEvent>) {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&events);
+        let slugifier = slugifier.on_loss(move |event| log.lock().unwrap().push(event));
+        let slug = slugifier.slugify(text);
+        let events = events.lock().unwrap().clone();
+        (slug, events)
+    }
+
+    #[test]
+    fn test_loss_events() {
+        let (slug, events) =
+            slugify_logged(Slugifier::new().truncate(10), "Hello, Wonderful World!");
+        assert_eq!(slug, "hello");
+        assert_eq!(
+            events,
+            [
+                LossEvent::DroppedChars { count: 2 },
+                LossEvent::Truncated { from: 21, to: 5 },
+            ]
+        );
+
+        let (slug, events) = slugify_logged(Slugifier::new(), "!!!");
+        assert_eq!(slug, "");
+        assert_eq!(
+            events,
+            [LossEvent::DroppedChars { count: 3 }, LossEvent::Empty]
+        );
+    }
+
+    #[test]
+    fn test_no_events_without_loss() {
+        let (slug, events) = slugify_logged(Slugifier::new(), "Crème Brûlée");
+        assert_eq!(slug, "creme-brulee");
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn test_other_paths_report_loss() {
+        let events = Arc::new(Mutex::new(0));
+        let count = Arc::clone(&events);
+        let slugifier = Slugifier::new().on_loss(move |_| *count.lock().unwrap() += 1);
+
+        let mut out = String::new();
+        slugifier.slugify_to("a?", &mut out).unwrap();
+        let mut owned = String::from("b?");
+        slugifier.slugify_in_place(&mut owned);
+        assert_eq!(slugifier.compile().slugify("c?"), "c");
+        assert_eq!(slugifier.slugify_reader("d?\ne".as_bytes()).unwrap(), "d-e");
+        assert_eq!((out.as_str(), owned.as_str()), ("a", "b"));
+        assert_eq!(*events.lock().unwrap(), 4);
+    }
+}
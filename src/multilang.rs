@@ -0,0 +1,93 @@
+//! Per-language slugifier configurations for multilingual content.
+
+use std::collections::HashMap;
+
+use crate::Slugifier;
+
+/// A set of slugifiers, one per language, for content in several languages
+/// that needs different stop words, symbols, or transliteration for each.
+///
+/// The slugifier for a language is picked by its language tag, falling back
+/// from a regional tag like `de-AT` to its language `de`, and then to the
+/// default slugifier.
+///
+/// # Example
+///
+/// ```
+/// use rslug::{MultiLangSlugifier, Slugifier};
+///
+/// let slugifiers = MultiLangSlugifier::new(Slugifier::new().lang(Some("en")))
+///     .with_lang("de", Slugifier::new().lang(Some("de")).stop_words(&["der", "die", "das"]));
+/// assert_eq!(slugifiers.slugify_lang("Die Äpfel & Birnen", "de-AT"), "aepfel-und-birnen");
+/// assert_eq!(slugifiers.slugify_lang("Apples & Pears", "en"), "apples-and-pears");
+/// assert_eq!(slugifiers.slugify_lang("Pommes & Poires", "fr"), "pommes-and-poires");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MultiLangSlugifier {
+    default: Slugifier,
+    languages: HashMap<String, Slugifier>,
+}
+
+impl MultiLangSlugifier {
+    /// Creates a set with `default` for languages without their own slugifier.
+    pub fn new(default: Slugifier) -> Self {
+        Self {
+            default,
+            languages: HashMap::new(),
+        }
+    }
+
+    /// Sets the slugifier for the language with the tag `lang`, like `de` or
+    /// `pt-BR`. Tags are matched regardless of case, and `_` is read as `-`.
+    ///
+    /// # Arguments
+    ///
+    /// * `lang` - The language tag.
+    /// * `slugifier` - The slugifier for the language.
+    pub fn with_lang(mut self, lang: &str, slugifier: Slugifier) -> Self {
+        self.languages.insert(normalize_tag(lang), slugifier);
+        self
+    }
+
+    /// Returns the slugifier for the language with the tag `lang`.
+    pub fn get(&self, lang: &str) -> &Slugifier {
+        let tag = normalize_tag(lang);
+        let primary = tag.split('-').next().unwrap_or_default();
+        self.languages
+            .get(&tag)
+            .or_else(|| self.languages.get(primary))
+            .unwrap_or(&self.default)
+    }
+
+    /// Generates a slug with the slugifier for the language with the tag `lang`.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to slugify.
+    /// * `lang` - The language tag of the text.
+    pub fn slugify_lang(&self, text: &str, lang: &str) -> String {
+        self.get(lang).slugify(text)
+    }
+}
+
+/// Lowercases `tag` and uses `-` between its subtags.
+fn normalize_tag(tag: &str) -> String {
+    tag.trim().to_ascii_lowercase().replace('_', "-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_language_fallback() {
+        let slugifiers = MultiLangSlugifier::new(Slugifier::new())
+            .with_lang("pt", Slugifier::new().separator("_"))
+            .with_lang("pt_BR", Slugifier::new().separator("."));
+        assert_eq!(slugifiers.slugify_lang("Olá Mundo", "PT-br"), "ola.mundo");
+        assert_eq!(slugifiers.slugify_lang("Olá Mundo", "pt-PT"), "ola_mundo");
+        assert_eq!(slugifiers.slugify_lang("Olá Mundo", "pt"), "ola_mundo");
+        assert_eq!(slugifiers.slugify_lang("Olá Mundo", "es"), "ola-mundo");
+        assert_eq!(slugifiers.slugify_lang("Olá Mundo", ""), "ola-mundo");
+    }
+}
@@ -0,0 +1,363 @@
+//! Spelling out or removing numbers, like the "10" of "Top 10 tips", and
+//! ordinals, like "1st".
+
+/// How standalone numbers are slugified, set with
+/// [`Slugifier::numbers`](crate::Slugifier::numbers).
+///
+/// A number is a standalone word of digits, like "10" or "2024". Digits that
+/// are part of a word, like the "3" of "mp3", are left alone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum NumberMode {
+    /// Keep numbers as digits.
+    #[default]
+    Keep,
+    /// Spell numbers out in the slugifier's language, like "10" as "ten".
+    ///
+    /// English and German are supported, and other languages fall back to
+    /// English. Numbers with a leading zero, like "007", and numbers too
+    /// large to spell out are kept as digits, as are numbers with decimal or
+    /// grouping punctuation, like "3.5" or "1,000".
+    Words,
+    /// Drop numbers, along with their decimal or grouping punctuation, for
+    /// keyword-style slugs where years and counts are noise.
+    Remove,
+}
+
+/// How ordinal numbers, like "1st" or "22nd", are slugified, set with
+/// [`Slugifier::ordinals`](crate::Slugifier::ordinals).
+///
+/// An ordinal is a standalone word of digits followed by the English suffix
+/// that matches them, in any case, so "1st" and "11TH" are ordinals but "1th"
+/// isn't. Ordinals are never split into their digits and suffix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum OrdinalMode {
+    /// Keep ordinals as they are, like "1st".
+    #[default]
+    Keep,
+    /// Drop the suffix, like "1st" as "1".
+    Digits,
+    /// Spell ordinals out in English, like "1st" as "first". Ordinals too
+    /// large to spell out are kept as they are.
+    Words,
+}
+
+/// Returns the length of the digits of `word` if it is an ordinal.
+pub(crate) fn parse_ordinal(word: &[u8]) -> Option<usize> {
+    let digits = word.iter().take_while(|c| c.is_ascii_digit()).count();
+    let (number, suffix) = word.split_at(digits);
+    let ones = *number.last()?;
+    let tens = digits.checked_sub(2).map_or(b'0', |i| number[i]);
+
+    let expected = match (tens, ones) {
+        (b'1', _) => "th",
+        (_, b'1') => "st",
+        (_, b'2') => "nd",
+        (_, b'3') => "rd",
+        _ => "th",
+    };
+    suffix
+        .eq_ignore_ascii_case(expected.as_bytes())
+        .then_some(digits)
+}
+
+/// Returns the ordinal of `digits` spelled out in English, with the words
+/// separated by spaces, or `None` if it can't be spelled out.
+pub(crate) fn to_ordinal_words(digits: &[u8]) -> Option<String> {
+    let mut words = to_words(digits, None)?;
+    let last = words.rfind(' ').map_or(0, |i| i + 1);
+    let ordinal = match &words[last..] {
+        "one" => "first",
+        "two" => "second",
+        "three" => "third",
+        "five" => "fifth",
+        "eight" => "eighth",
+        "nine" => "ninth",
+        "twelve" => "twelfth",
+        word if word.ends_with('y') => {
+            words.truncate(words.len() - 1);
+            words.push_str("ieth");
+            return Some(words);
+        }
+        _ => {
+            words.push_str("th");
+            return Some(words);
+        }
+    };
+    words.truncate(last);
+    words.push_str(ordinal);
+    Some(words)
+}
+
+/// Returns the length of the number with thousands separators at the start of
+/// `text`, like "1,000,000" or "2 000", if there is one.
+///
+/// The separators are commas, apostrophes, and spaces, including no-break and
+/// thin spaces, used the same way throughout the number. Dots are left alone,
+/// since they are also decimal points and separate the parts of versions and
+/// IP addresses.
+pub(crate) fn digit_groups_len(text: &str) -> Option<usize> {
+    let digits = |from: usize| {
+        text.as_bytes()[from..]
+            .iter()
+            .take_while(|c| c.is_ascii_digit())
+            .count()
+    };
+
+    let first = digits(0);
+    if !(1..=3).contains(&first) {
+        return None;
+    }
+
+    let mut len = first;
+    let mut separator = None;
+    while let Some(c) = text[len..].chars().next() {
+        let is_separator = matches!(c, ',' | '\'' | ' ' | '\u{00A0}' | '\u{2009}' | '\u{202F}');
+        if !is_separator || separator.is_some_and(|separator| separator != c) {
+            break;
+        }
+        let group = digits(len + c.len_utf8());
+        if group != 3 {
+            break;
+        }
+        separator = Some(c);
+        len += c.len_utf8() + group;
+    }
+
+    // A number ends where a word can.
+    let is_end = !text[len..]
+        .chars()
+        .next()
+        .is_some_and(char::is_alphanumeric);
+    (separator.is_some() && is_end).then_some(len)
+}
+
+/// Returns `digits` spelled out in the language with `code`, with the words
+/// separated by spaces, or `None` if it can't be spelled out.
+pub(crate) fn to_words(digits: &[u8], code: Option<&str>) -> Option<String> {
+    if digits.len() > 1 && digits[0] == b'0' {
+        return None;
+    }
+    let value: u64 = std::str::from_utf8(digits).ok()?.parse().ok()?;
+
+    match code {
+        Some("de") if value < 1_000_000 => Some(german(value)),
+        Some("de") => None,
+        _ if value < 1_000_000_000_000 => Some(english(value)),
+        _ => None,
+    }
+}
+
+const ENGLISH_ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const ENGLISH_TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// Spells out `value`, which is below a trillion, in English.
+fn english(value: u64) -> String {
+    if value == 0 {
+        return ENGLISH_ONES[0].to_string();
+    }
+
+    let mut words = Vec::new();
+    for (scale, name) in [
+        (1_000_000_000, "billion"),
+        (1_000_000, "million"),
+        (1_000, "thousand"),
+    ] {
+        let count = value / scale % 1000;
+        if count > 0 {
+            english_below_thousand(count, &mut words);
+            words.push(name);
+        }
+    }
+    english_below_thousand(value % 1000, &mut words);
+    words.join(" ")
+}
+
+/// Appends the words for `value`, which is below a thousand, and nothing for
+/// zero.
+fn english_below_thousand(value: u64, words: &mut Vec<&'static str>) {
+    let (hundreds, rest) = (value / 100, value % 100);
+    if hundreds > 0 {
+        words.push(ENGLISH_ONES[hundreds as usize]);
+        words.push("hundred");
+    }
+    match rest {
+        0 => {}
+        1..20 => words.push(ENGLISH_ONES[rest as usize]),
+        _ => {
+            words.push(ENGLISH_TENS[(rest / 10) as usize]);
+            if rest % 10 > 0 {
+                words.push(ENGLISH_ONES[(rest % 10) as usize]);
+            }
+        }
+    }
+}
+
+const GERMAN_ONES: [&str; 20] = [
+    "null",
+    "eins",
+    "zwei",
+    "drei",
+    "vier",
+    "fünf",
+    "sechs",
+    "sieben",
+    "acht",
+    "neun",
+    "zehn",
+    "elf",
+    "zwölf",
+    "dreizehn",
+    "vierzehn",
+    "fünfzehn",
+    "sechzehn",
+    "siebzehn",
+    "achtzehn",
+    "neunzehn",
+];
+
+const GERMAN_TENS: [&str; 10] = [
+    "", "", "zwanzig", "dreißig", "vierzig", "fünfzig", "sechzig", "siebzig", "achtzig", "neunzig",
+];
+
+/// Spells out `value`, which is below a million, in German, where it is a
+/// single word.
+fn german(value: u64) -> String {
+    if value == 0 {
+        return GERMAN_ONES[0].to_string();
+    }
+
+    let mut word = String::new();
+    if value >= 1000 {
+        german_below_thousand(value / 1000, false, &mut word);
+        word.push_str("tausend");
+    }
+    german_below_thousand(value % 1000, true, &mut word);
+    word
+}
+
+/// Appends the word for `value`, which is below a thousand, and nothing for
+/// zero. A final one is "eins" if `is_last`, and "ein" before "tausend".
+fn german_below_thousand(value: u64, is_last: bool, word: &mut String) {
+    let (hundreds, rest) = (value / 100, value % 100);
+    if hundreds > 0 {
+        word.push_str(if hundreds == 1 {
+            "ein"
+        } else {
+            GERMAN_ONES[hundreds as usize]
+        });
+        word.push_str("hundert");
+    }
+    match rest {
+        0 => {}
+        1 if !is_last => word.push_str("ein"),
+        1..20 => word.push_str(GERMAN_ONES[rest as usize]),
+        _ => {
+            // The ones come first, as in "einundzwanzig" for 21.
+            match rest % 10 {
+                0 => {}
+                1 => word.push_str("einund"),
+                ones => {
+                    word.push_str(GERMAN_ONES[ones as usize]);
+                    word.push_str("und");
+                }
+            }
+            word.push_str(GERMAN_TENS[(rest / 10) as usize]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_english_words() {
+        let words = |digits: &str| to_words(digits.as_bytes(), None);
+        assert_eq!(words("0").as_deref(), Some("zero"));
+        assert_eq!(words("10").as_deref(), Some("ten"));
+        assert_eq!(words("42").as_deref(), Some("forty two"));
+        assert_eq!(words("1900").as_deref(), Some("one thousand nine hundred"));
+        assert_eq!(words("2000016").as_deref(), Some("two million sixteen"));
+        assert_eq!(words("007"), None);
+        assert_eq!(words("1000000000000"), None);
+    }
+
+    #[test]
+    fn test_parse_ordinal() {
+        assert_eq!(parse_ordinal(b"1st"), Some(1));
+        assert_eq!(parse_ordinal(b"22ND"), Some(2));
+        assert_eq!(parse_ordinal(b"113th"), Some(3));
+        assert_eq!(parse_ordinal(b"3rd"), Some(1));
+        for word in [&b"1th"[..], b"11st", b"st", b"12", b"4thx", b"2nd3"] {
+            assert_eq!(
+                parse_ordinal(word),
+                None,
+                "{:?}",
+                String::from_utf8_lossy(word)
+            );
+        }
+    }
+
+    #[test]
+    fn test_ordinal_words() {
+        let words = |digits: &str| to_ordinal_words(digits.as_bytes());
+        assert_eq!(words("1").as_deref(), Some("first"));
+        assert_eq!(words("12").as_deref(), Some("twelfth"));
+        assert_eq!(words("20").as_deref(), Some("twentieth"));
+        assert_eq!(words("22").as_deref(), Some("twenty second"));
+        assert_eq!(words("100").as_deref(), Some("one hundredth"));
+        assert_eq!(words("007"), None);
+    }
+
+    #[test]
+    fn test_digit_groups() {
+        assert_eq!(digit_groups_len("1,000,000 users"), Some(9));
+        assert_eq!(digit_groups_len("2 000 views"), Some(5));
+        assert_eq!(digit_groups_len("12\u{202F}345"), Some(8));
+        assert_eq!(digit_groups_len("1'000.50"), Some(5));
+        assert_eq!(digit_groups_len("1,000 000"), Some(5));
+        for text in ["1000", "1,00", "1234,567", "1.000.000", "10 100x", "1, 000"] {
+            assert_eq!(digit_groups_len(text), None, "{text}");
+        }
+    }
+
+    #[test]
+    fn test_german_words() {
+        let words = |digits: &str| to_words(digits.as_bytes(), Some("de"));
+        assert_eq!(words("1").as_deref(), Some("eins"));
+        assert_eq!(words("21").as_deref(), Some("einundzwanzig"));
+        assert_eq!(words("101").as_deref(), Some("einhunderteins"));
+        assert_eq!(words("1001").as_deref(), Some("eintausendeins"));
+        assert_eq!(
+            words("37500").as_deref(),
+            Some("siebenunddreißigtausendfünfhundert")
+        );
+        assert_eq!(words("1000000"), None);
+    }
+}
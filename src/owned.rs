@@ -0,0 +1,153 @@
+//! Slugification that reuses the allocation of an owned input string.
+
+use crate::Slugifier;
+use crate::builder::{self, ByteAction};
+
+impl Slugifier {
+    /// Generates a slug like `slugify`, reusing the allocation of `text` when
+    /// possible.
+    ///
+    /// See `slugify_in_place` for when the allocation is reused.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// let title = String::from("Hello, World!");
+    /// assert_eq!(Slugifier::new().slugify_owned(title), "hello-world");
+    /// ```
+    pub fn slugify_owned(&self, mut text: String) -> String {
+        self.slugify_in_place(&mut text);
+        text
+    }
+
+    /// Replaces `text` with its slug.
+    ///
+    /// ASCII text is slugified in place, without allocating, when every byte
+    /// is only kept, lowercased, removed, or turned into a separator, since
+    /// the slug is then never longer than the text. That rules out presets,
+    /// `on_loss`, terms like replacements and languages, `preserve_versions`,
+    /// `unicode_words`, conversions of roman numerals, numbers, and ordinals,
+    /// separators that aren't collapsed and trimmed, and separators longer
+    /// than one byte. Otherwise the slug is generated into a new string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// let mut title = String::from("Hello, World!");
+    /// Slugifier::new().slugify_in_place(&mut title);
+    /// assert_eq!(title, "hello-world");
+    /// ```
+    pub fn slugify_in_place(&self, text: &mut String) {
+        if !text.is_ascii()
+            || self.preset.is_some()
+            || self.on_loss.is_some()
+            || !self.collapse_separators
+            || !self.trim_separators
+            || self.has_terms()
+            || self.preserve_versions
+            || self.unicode_words
+            || self.converts_words()
+            || self.separator.len() > 1
+        {
+            *text = self.slugify(text);
+            return;
+        }
+
+        let mut bytes = std::mem::take(text).into_bytes();
+        let separator = self.separator.as_bytes().first().copied();
+
+        // The same rules as `SlugBuilder::push_bytes`. Each separator or
+        // allowed character written stands for at least one byte read, so
+        // writing never overtakes reading.
+        let mut written = 0;
+        let mut found_sep = false;
+        let mut after_alphanumeric = false;
+        let mut pending = None;
+        for read in 0..bytes.len() {
+            let c = bytes[read];
+            let action = self.classes.action(c);
+            if action == ByteAction::Remove {
+                continue;
+            }
+
+            if matches!(action, ByteAction::Keep | ByteAction::KeepLower) {
+                let insert = match pending.take() {
+                    Some(allowed) => Some(allowed),
+                    None if found_sep && written > 0 => separator,
+                    None => None,
+                };
+                if let Some(insert) = insert {
+                    bytes[written] = insert;
+                    written += 1;
+                }
+
+                bytes[written] = if action == ByteAction::KeepLower {
+                    c.to_ascii_lowercase()
+                } else {
+                    c
+                };
+                written += 1;
+
+                found_sep = false;
+                after_alphanumeric = true;
+            } else {
+                if action == ByteAction::Allow && after_alphanumeric {
+                    pending = Some(c);
+                } else {
+                    pending = None;
+                    found_sep = true;
+                }
+                after_alphanumeric = false;
+            }
+        }
+        bytes.truncate(written);
+
+        // Only ASCII input was read, so the slug is ASCII as well.
+        let slug = String::from_utf8(bytes).unwrap_or_default();
+        *text = builder::finish_slug(self, slug);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_in_place_matches_slugify() {
+        let slugifiers = [
+            Slugifier::new(),
+            Slugifier::new().separator("").to_lowercase(false),
+            Slugifier::new()
+                .separator("_")
+                .allowed_chars(".")
+                .remove_chars("'"),
+            Slugifier::new().stop_words(&["the"]).truncate(12),
+            Slugifier::new().separator("--"),
+        ];
+        let inputs = [
+            "  The Quick, brown fox!  ",
+            "v1.2. -- Release.Notes",
+            "Don't Stop",
+            "Ünïcode input",
+            "",
+        ];
+        for slugifier in &slugifiers {
+            for text in inputs {
+                let mut slug = text.to_string();
+                slugifier.slugify_in_place(&mut slug);
+                assert_eq!(slug, slugifier.slugify(text), "{text:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_slugify_owned_reuses_allocation() {
+        let title = String::from("Hello, World!");
+        let ptr = title.as_ptr();
+        let slug = Slugifier::new().slugify_owned(title);
+        assert_eq!(slug, "hello-world");
+        assert_eq!(slug.as_ptr(), ptr);
+    }
+}
@@ -0,0 +1,74 @@
+//! Parsing slug arguments of command-line tools.
+
+use crate::{Error, Slugifier};
+
+impl Slugifier {
+    /// Returns a parser for slug arguments of command-line tools, which
+    /// checks them against this configuration at parse time.
+    ///
+    /// The parser is a function from `&str` to `Result<String, Error>` that
+    /// can be cloned and shared between threads, so it can be passed to
+    /// `clap`'s `value_parser` as it is. Without `auto_slugify`, it accepts
+    /// only valid slugs, like [`validate`](Slugifier::validate). With
+    /// `auto_slugify`, it slugifies the argument instead, like
+    /// [`try_slugify`](Slugifier::try_slugify).
+    ///
+    /// # Arguments
+    ///
+    /// * `auto_slugify` - A boolean indicating if arguments are slugified
+    ///   instead of validated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::{Error, Slugifier};
+    ///
+    /// let strict = Slugifier::new().slug_parser(false);
+    /// assert_eq!(strict("hello-world"), Ok("hello-world".to_string()));
+    /// assert_eq!(strict("hello--world"), Err(Error::NotNormalized));
+    ///
+    /// let lenient = Slugifier::new().slug_parser(true);
+    /// assert_eq!(lenient("Hello, World!"), Ok("hello-world".to_string()));
+    /// assert_eq!(lenient("!!!"), Err(Error::Empty));
+    /// ```
+    pub fn slug_parser(
+        &self,
+        auto_slugify: bool,
+    ) -> impl Fn(&str) -> Result<String, Error> + Clone + Send + Sync + 'static {
+        let slugifier = self.clone();
+        move |arg: &str| {
+            if auto_slugify {
+                slugifier.try_slugify(arg)
+            } else {
+                slugifier.validate(arg).map(|()| arg.to_string())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slug_parser_validates() {
+        let parse = Slugifier::new().separator("_").slug_parser(false);
+        assert_eq!(parse("hello_world").as_deref(), Ok("hello_world"));
+        assert_eq!(
+            parse("hello-world"),
+            Err(Error::InvalidChar { ch: '-', index: 5 })
+        );
+        assert_eq!(parse(""), Err(Error::Empty));
+    }
+
+    #[test]
+    fn test_slug_parser_slugifies() {
+        let parse = Slugifier::new().truncate(10).slug_parser(true);
+        let handle = std::thread::spawn({
+            let parse = parse.clone();
+            move || parse("Release Notes 2024")
+        });
+        assert_eq!(handle.join().unwrap().as_deref(), Ok("release"));
+        assert_eq!(parse("release").as_deref(), Ok("release"));
+    }
+}
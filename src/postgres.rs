@@ -0,0 +1,160 @@
+//! A [`UniquenessStore`] backed by a PostgreSQL table, for allocating unique
+//! slugs in the database that stores them.
+
+use crate::UniquenessStore;
+
+/// Runs SQL statements on a PostgreSQL connection, for a [`PostgresStore`].
+///
+/// Implement this for the connection or transaction of the database driver in
+/// use, like a `postgres::Client`, or an `sqlx` connection driven by
+/// `block_on`.
+pub trait SqlExecutor {
+    /// The error returned when a statement fails.
+    type Error;
+
+    /// Runs `sql` with `params` bound to its `$1`, `$2`, … placeholders as
+    /// text, and returns the number of rows it affected.
+    fn execute(&mut self, sql: &str, params: &[&str]) -> Result<u64, Self::Error>;
+}
+
+impl<E: SqlExecutor + ?Sized> SqlExecutor for &mut E {
+    type Error = E::Error;
+
+    fn execute(&mut self, sql: &str, params: &[&str]) -> Result<u64, Self::Error> {
+        (**self).execute(sql, params)
+    }
+}
+
+/// A [`UniquenessStore`] that reserves slugs as rows of a PostgreSQL table.
+///
+/// A slug is reserved with a single `INSERT … ON CONFLICT DO NOTHING`
+/// statement, which inserts the slug unless the table already has it, so the
+/// check and the insert can't race. The slug column needs a unique constraint
+/// or index, which the conflict is detected on.
+///
+/// Reserving in the transaction that saves the rest of the record makes a
+/// reservation that is rolled back free the slug again.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashSet;
+/// use rslug::{PostgresStore, Slugifier, SqlExecutor};
+///
+/// /// A stand-in for a database connection.
+/// struct Table(HashSet<String>);
+///
+/// impl SqlExecutor for Table {
+///     type Error = String;
+///
+///     fn execute(&mut self, sql: &str, params: &[&str]) -> Result<u64, String> {
+///         assert_eq!(sql, r#"INSERT INTO "posts" ("slug") VALUES ($1) ON CONFLICT DO NOTHING"#);
+///         Ok(u64::from(self.0.insert(params[0].to_string())))
+///     }
+/// }
+///
+/// let mut store = PostgresStore::new(Table(HashSet::new()), "posts", "slug");
+/// let slugifier = Slugifier::new();
+/// assert_eq!(slugifier.slugify_unique("Hello", &mut store), Ok("hello".to_string()));
+/// assert_eq!(slugifier.slugify_unique("Hello", &mut store), Ok("hello-2".to_string()));
+/// ```
+#[derive(Debug, Clone)]
+pub struct PostgresStore<E> {
+    executor: E,
+    insert: String,
+}
+
+impl<E: SqlExecutor> PostgresStore<E> {
+    /// Creates a store that reserves slugs in `column` of `table`.
+    ///
+    /// The names are quoted, so they are case-sensitive. A table name of the
+    /// form `schema.table` names a table in that schema.
+    ///
+    /// # Arguments
+    ///
+    /// * `executor` - The connection to run the statements on.
+    /// * `table` - The name of the table.
+    /// * `column` - The name of the column with the unique constraint.
+    pub fn new(executor: E, table: &str, column: &str) -> Self {
+        let table = table.split('.').map(quote).collect::<Vec<_>>().join(".");
+        let insert = format!(
+            "INSERT INTO {table} ({}) VALUES ($1) ON CONFLICT DO NOTHING",
+            quote(column)
+        );
+        Self { executor, insert }
+    }
+
+    /// Returns the executor the store runs its statements on.
+    pub fn into_inner(self) -> E {
+        self.executor
+    }
+}
+
+impl<E: SqlExecutor> UniquenessStore for PostgresStore<E> {
+    type Error = E::Error;
+
+    fn reserve(&mut self, slug: &str) -> Result<bool, Self::Error> {
+        Ok(self.executor.execute(&self.insert, &[slug])? == 1)
+    }
+}
+
+/// Quotes `name` as a PostgreSQL identifier.
+fn quote(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Slugifier;
+
+    /// An executor that records the statements and reports the given results.
+    struct Recorder {
+        statements: Vec<(String, Vec<String>)>,
+        results: Vec<Result<u64, &'static str>>,
+    }
+
+    impl SqlExecutor for Recorder {
+        type Error = &'static str;
+
+        fn execute(&mut self, sql: &str, params: &[&str]) -> Result<u64, Self::Error> {
+            let params = params.iter().map(|param| param.to_string()).collect();
+            self.statements.push((sql.to_string(), params));
+            self.results.remove(0)
+        }
+    }
+
+    #[test]
+    fn test_reserve_inserts_on_conflict_do_nothing() {
+        let recorder = Recorder {
+            statements: Vec::new(),
+            results: vec![Ok(0), Ok(1)],
+        };
+        let mut store = PostgresStore::new(recorder, "blog.Posts", "slug\"");
+        let slug = Slugifier::new().slugify_unique("Hello World", &mut store);
+        assert_eq!(slug, Ok("hello-world-2".to_string()));
+
+        let statements = store.into_inner().statements;
+        let sql = r#"INSERT INTO "blog"."Posts" ("slug""") VALUES ($1) ON CONFLICT DO NOTHING"#;
+        assert_eq!(
+            statements,
+            [
+                (sql.to_string(), vec!["hello-world".to_string()]),
+                (sql.to_string(), vec!["hello-world-2".to_string()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reserve_propagates_errors() {
+        let mut recorder = Recorder {
+            statements: Vec::new(),
+            results: vec![Err("connection closed")],
+        };
+        let mut store = PostgresStore::new(&mut recorder, "posts", "slug");
+        assert_eq!(
+            Slugifier::new().slugify_unique("Hello", &mut store),
+            Err("connection closed")
+        );
+    }
+}
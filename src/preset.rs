@@ -0,0 +1,1197 @@
+//! Ready-made configurations for common slug formats.
+
+use std::fmt;
+use std::str::FromStr;
+
+use unicode_normalization::char::is_combining_mark;
+
+use crate::{Error, Slugifier, anchor, compat};
+
+/// A named configuration for a commonly required slug format.
+///
+/// Apply a preset with [`Slugifier::preset`]. Presets configure the regular
+/// builder options and may add format-specific rules that `slugify` enforces
+/// and `validate` checks.
+///
+/// # Example
+///
+/// ```
+/// use rslug::{Preset, Slugifier};
+///
+/// let slugifier = Slugifier::new().preset(Preset::DnsLabel);
+/// assert_eq!(slugifier.slugify("ACME Corp. (EU)"), "acme-corp-eu");
+/// assert!(slugifier.validate("acme-corp-eu").is_ok());
+/// assert!(slugifier.validate("-acme").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Preset {
+    /// A DNS label as defined by RFC 1123, e.g. a subdomain derived from a
+    /// tenant name: lowercase ASCII letters, digits, and hyphens, neither
+    /// starting nor ending with a hyphen, and at most 63 characters long.
+    DnsLabel,
+    /// A Kubernetes resource name, which must be a DNS subdomain (RFC 1123):
+    /// lowercase alphanumerics, `-`, and `.`, starting and ending with an
+    /// alphanumeric character, and at most 253 characters long.
+    K8sName,
+    /// A Kubernetes label value: alphanumerics, `-`, `_`, and `.`, starting and
+    /// ending with an alphanumeric character, and at most 63 characters long.
+    /// Case is preserved, since label values are case-sensitive.
+    K8sLabelValue,
+    /// A Docker image repository name: lowercase path components made of
+    /// alphanumerics joined by `.`, `_`, or `-`, separated by `/`, and at most
+    /// 255 characters long.
+    DockerRepository,
+    /// A Docker image tag, e.g. derived from a branch name: alphanumerics, `_`,
+    /// `.`, and `-`, never starting with a separator, and at most 128 characters.
+    DockerTag,
+    /// An Amazon S3 bucket name: 3 to 63 lowercase alphanumerics, `-`, and `.`,
+    /// without adjacent periods, not formatted as an IP address, and without the
+    /// prefixes and suffixes AWS reserves. Shorter names are padded with `0`.
+    S3Bucket,
+    /// A safe Amazon S3 or Google Cloud Storage object key: alphanumerics, `-`,
+    /// `_`, `.`, and `/` for prefixes, at most 1024 bytes long. Case is preserved.
+    S3ObjectKey,
+    /// An npm package name: lowercase alphanumerics, `-`, `.`, `_`, and `~`, at
+    /// most 214 characters long. Input of the form `@scope/name` produces a
+    /// scoped package name.
+    NpmPackage,
+    /// A crates.io crate name: lowercase alphanumerics, `-`, and `_`, starting
+    /// with a letter, and at most 64 characters long. Leading digits are dropped.
+    CratesIo,
+    /// A Rust identifier in snake_case. Identifiers that would start with a
+    /// digit are prefixed with `_`, and keywords are escaped as raw identifiers
+    /// (`r#type`), or suffixed with `_` for keywords that can't be raw (`self_`).
+    RustIdent,
+    /// An unquoted SQL identifier in snake_case for the given dialect, e.g. a
+    /// column name derived from a CSV header. Identifiers are limited to the
+    /// dialect's maximum length, identifiers that would start with a digit are
+    /// prefixed, and reserved keywords are suffixed with `_` (`order_`).
+    Sql(SqlDialect),
+    /// An environment variable name in SCREAMING_SNAKE_CASE, e.g. for mapping
+    /// config labels to env keys. Names that would not start with a letter are
+    /// prefixed with `VAR_`.
+    EnvVar,
+    /// A value that is both a valid HTML `id` and a CSS identifier usable as a
+    /// class name or selector without escaping. Identifiers that would start
+    /// with a digit are prefixed with `_`.
+    HtmlId,
+    /// A markdown heading anchor exactly as GitHub generates it: lowercased,
+    /// with punctuation and symbols removed rather than turned into separators,
+    /// underscores kept, and each space replaced by a hyphen. Non-ASCII letters
+    /// are preserved. Use an [`AnchorGenerator`](crate::AnchorGenerator) to also
+    /// deduplicate anchors.
+    GitHubAnchor,
+    /// A markdown heading anchor as GitLab generates it. Unlike GitHub, runs of
+    /// hyphens are squeezed and digit-only anchors are prefixed with `anchor-`.
+    GitLabAnchor,
+    /// A heading identifier as Pandoc's `auto_identifiers` extension generates
+    /// it. Periods are kept, everything before the first letter is dropped, and
+    /// headings without letters become `section`.
+    PandocAnchor,
+    /// A heading anchor as mdBook generates it. Whitespace characters become
+    /// hyphens and only ASCII letters are lowercased.
+    MdBookAnchor,
+    /// A heading anchor as Docusaurus generates it. Docusaurus uses the same
+    /// `github-slugger` algorithm as GitHub.
+    DocusaurusAnchor,
+    /// WordPress' `sanitize_title`, so permalinks migrated from WordPress keep
+    /// working: punctuation is removed, HTML tags and entities are stripped,
+    /// dots become hyphens, and characters outside of ASCII are percent-encoded.
+    /// Accent removal is approximated by transliterating Latin letters.
+    WordPress,
+    /// The defaults of the python-slugify package: apostrophes are dropped,
+    /// thousands separators are removed from numbers, and truncation cuts at
+    /// exactly the maximum length. Set `word_boundary` and `save_order` for
+    /// python-slugify's options of the same names, and `allowed_chars` in place
+    /// of a custom `regex_pattern`.
+    PythonSlugify,
+    /// The defaults of the npm `slugify` package: case is preserved, symbols
+    /// like `&` and `$` are spelled out, and the punctuation `*+~.()'"!:@` is
+    /// kept. Set `to_lowercase` for its `lower` option, and `remove_chars` for
+    /// its `remove` option, which replaces the default set of removed characters.
+    NpmSlugify,
+    /// The npm `slugify` package with its `strict` option, which removes
+    /// everything but ASCII letters, digits, and whitespace.
+    NpmSlugifyStrict,
+    /// The defaults of the speakingurl package, which spells out symbols in
+    /// English. Set `lang` for its `lang` option, and `replace` for each
+    /// entry of its `custom` option.
+    SpeakingUrl,
+    /// Hugo's `urlize` function: punctuation other than `./_#+~-@` is dropped
+    /// without separating words, and characters outside of ASCII are
+    /// percent-encoded.
+    HugoUrlize,
+    /// Jekyll's `slugify` filter in the given mode. Set `to_lowercase` to
+    /// `false` for its `cased` option.
+    Jekyll(JekyllMode),
+    /// An X (formerly Twitter) username: ASCII letters, digits, and
+    /// underscores, between 5 and 15 characters long. Shorter names are kept
+    /// as they are, so `validate` rejects them with
+    /// [`Error::TooShort`](crate::Error::TooShort), like `al` slugified from `Al`.
+    TwitterHandle,
+    /// A GitHub username: ASCII letters, digits, and single hyphens, neither
+    /// starting nor ending with a hyphen, and at most 39 characters long.
+    GitHubUsername,
+    /// An Instagram username: ASCII letters, digits, underscores, and periods,
+    /// without consecutive periods or a period at either end, and at most 30
+    /// characters long.
+    InstagramHandle,
+    /// The local part of an email address, as a dot-atom (RFC 5322): words
+    /// of ASCII letters, digits, `-`, and `_` joined by single periods, and
+    /// at most 64 characters long.
+    EmailLocalPart,
+}
+
+/// The mode of Jekyll's `slugify` filter, which determines the characters
+/// that are kept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum JekyllMode {
+    /// Only whitespace is replaced.
+    Raw,
+    /// Letters and digits of any script are kept.
+    Default,
+    /// Like `Default`, but also keeps the punctuation `._~!$&'()+,;=@`.
+    Pretty,
+    /// Only ASCII letters and digits are kept.
+    Ascii,
+    /// Like `Default`, after removing accents from Latin letters and dropping
+    /// all other letters outside of ASCII.
+    Latin,
+}
+
+/// A SQL database dialect, which determines identifier length limits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SqlDialect {
+    /// Standard SQL, with 128-character identifiers.
+    Ansi,
+    /// PostgreSQL, with 63-byte identifiers.
+    Postgres,
+    /// MySQL and MariaDB, with 64-character identifiers.
+    MySql,
+    /// SQLite, which has no practical identifier length limit.
+    Sqlite,
+    /// Microsoft SQL Server, with 128-character identifiers.
+    SqlServer,
+    /// Oracle Database 12.2 and later, with 128-byte identifiers that must
+    /// start with a letter.
+    Oracle,
+}
+
+impl SqlDialect {
+    /// The maximum identifier length, if the dialect has one.
+    fn max_identifier_len(self) -> Option<usize> {
+        match self {
+            SqlDialect::Ansi | SqlDialect::SqlServer | SqlDialect::Oracle => Some(128),
+            SqlDialect::Postgres => Some(63),
+            SqlDialect::MySql => Some(64),
+            SqlDialect::Sqlite => None,
+        }
+    }
+
+    /// The prefix for identifiers that would otherwise start with a digit.
+    fn digit_prefix(self) -> &'static str {
+        match self {
+            SqlDialect::Oracle => "x_",
+            _ => "_",
+        }
+    }
+}
+
+/// Keywords reserved by at least one of the common SQL dialects.
+const SQL_RESERVED_WORDS: &[&str] = &[
+    "all",
+    "alter",
+    "and",
+    "any",
+    "as",
+    "asc",
+    "between",
+    "by",
+    "case",
+    "cast",
+    "check",
+    "column",
+    "constraint",
+    "create",
+    "cross",
+    "current_date",
+    "current_time",
+    "current_timestamp",
+    "current_user",
+    "database",
+    "default",
+    "delete",
+    "desc",
+    "distinct",
+    "drop",
+    "else",
+    "end",
+    "except",
+    "exists",
+    "false",
+    "fetch",
+    "for",
+    "foreign",
+    "from",
+    "full",
+    "grant",
+    "group",
+    "having",
+    "in",
+    "index",
+    "inner",
+    "insert",
+    "intersect",
+    "into",
+    "is",
+    "join",
+    "key",
+    "left",
+    "like",
+    "limit",
+    "natural",
+    "not",
+    "null",
+    "offset",
+    "on",
+    "or",
+    "order",
+    "outer",
+    "over",
+    "partition",
+    "primary",
+    "range",
+    "references",
+    "revoke",
+    "right",
+    "rows",
+    "schema",
+    "select",
+    "session_user",
+    "set",
+    "some",
+    "table",
+    "then",
+    "to",
+    "true",
+    "trigger",
+    "union",
+    "unique",
+    "update",
+    "user",
+    "using",
+    "values",
+    "view",
+    "when",
+    "where",
+    "window",
+    "with",
+];
+
+/// Rust keywords, including those reserved for future use, as of the 2024 edition.
+const RUST_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Rust keywords that can't be used as raw identifiers.
+const RUST_NON_RAW_KEYWORDS: &[&str] = &["crate", "self", "super"];
+
+/// Bucket name prefixes reserved by AWS.
+const S3_RESERVED_PREFIXES: &[&str] = &["xn--", "sthree-", "amzn-s3-demo-"];
+
+/// Bucket name suffixes reserved by AWS.
+const S3_RESERVED_SUFFIXES: &[&str] = &["-s3alias", "--ol-s3", ".mrap", "--x-s3"];
+
+/// Returns `true` if `name` looks like an IPv4 address, e.g. `192.168.5.4`.
+fn is_ip_address(name: &str) -> bool {
+    let parts: Vec<&str> = name.split('.').collect();
+    parts.len() == 4
+        && parts
+            .iter()
+            .all(|p| !p.is_empty() && p.len() <= 3 && p.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// Adds the prefix and suffix that `affixes` returns for `slug`, first
+/// shortening the slug so that the result is at most `max_len` bytes long.
+fn escape(
+    slugifier: &Slugifier,
+    slug: &mut String,
+    max_len: Option<usize>,
+    affixes: impl Fn(&str) -> (&'static str, &'static str),
+) {
+    loop {
+        let (prefix, suffix) = affixes(slug);
+        let added = prefix.len() + suffix.len();
+        if let Some(max_len) = max_len
+            && slug.len() + added > max_len
+            && max_len > added
+        {
+            // The shortened slug may need another escape, like "as" cut from "asc".
+            slugifier.truncate_to(slug, max_len - added);
+            continue;
+        }
+        slug.insert_str(0, prefix);
+        slug.push_str(suffix);
+        return;
+    }
+}
+
+impl fmt::Display for Preset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Preset {
+    type Err = Error;
+
+    /// Looks up a built-in preset by its [`name`](Preset::name), ignoring ASCII case.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Preset::ALL
+            .iter()
+            .copied()
+            .find(|preset| preset.name().eq_ignore_ascii_case(name))
+            .ok_or_else(|| Error::UnknownPreset {
+                name: name.to_string(),
+            })
+    }
+}
+
+impl Preset {
+    /// All presets, in the order they are documented.
+    pub const ALL: &'static [Preset] = &[
+        Preset::DnsLabel,
+        Preset::K8sName,
+        Preset::K8sLabelValue,
+        Preset::DockerRepository,
+        Preset::DockerTag,
+        Preset::S3Bucket,
+        Preset::S3ObjectKey,
+        Preset::NpmPackage,
+        Preset::CratesIo,
+        Preset::RustIdent,
+        Preset::Sql(SqlDialect::Ansi),
+        Preset::Sql(SqlDialect::Postgres),
+        Preset::Sql(SqlDialect::MySql),
+        Preset::Sql(SqlDialect::Sqlite),
+        Preset::Sql(SqlDialect::SqlServer),
+        Preset::Sql(SqlDialect::Oracle),
+        Preset::EnvVar,
+        Preset::HtmlId,
+        Preset::GitHubAnchor,
+        Preset::GitLabAnchor,
+        Preset::PandocAnchor,
+        Preset::MdBookAnchor,
+        Preset::DocusaurusAnchor,
+        Preset::WordPress,
+        Preset::PythonSlugify,
+        Preset::NpmSlugify,
+        Preset::NpmSlugifyStrict,
+        Preset::SpeakingUrl,
+        Preset::HugoUrlize,
+        Preset::Jekyll(JekyllMode::Raw),
+        Preset::Jekyll(JekyllMode::Default),
+        Preset::Jekyll(JekyllMode::Pretty),
+        Preset::Jekyll(JekyllMode::Ascii),
+        Preset::Jekyll(JekyllMode::Latin),
+        Preset::TwitterHandle,
+        Preset::GitHubUsername,
+        Preset::InstagramHandle,
+        Preset::EmailLocalPart,
+    ];
+
+    /// Returns the preset's name, as accepted by [`Slugifier::from_preset_name`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::{Preset, SqlDialect};
+    /// assert_eq!(Preset::GitHubAnchor.name(), "github-anchor");
+    /// assert_eq!(Preset::Sql(SqlDialect::Postgres).name(), "sql-postgres");
+    /// assert_eq!("github-anchor".parse(), Ok(Preset::GitHubAnchor));
+    /// ```
+    pub fn name(self) -> &'static str {
+        match self {
+            Preset::DnsLabel => "dns-label",
+            Preset::K8sName => "k8s-name",
+            Preset::K8sLabelValue => "k8s-label-value",
+            Preset::DockerRepository => "docker-repository",
+            Preset::DockerTag => "docker-tag",
+            Preset::S3Bucket => "s3-bucket",
+            Preset::S3ObjectKey => "s3-object-key",
+            Preset::NpmPackage => "npm-package",
+            Preset::CratesIo => "crates-io",
+            Preset::RustIdent => "rust-ident",
+            Preset::Sql(SqlDialect::Ansi) => "sql",
+            Preset::Sql(SqlDialect::Postgres) => "sql-postgres",
+            Preset::Sql(SqlDialect::MySql) => "sql-mysql",
+            Preset::Sql(SqlDialect::Sqlite) => "sql-sqlite",
+            Preset::Sql(SqlDialect::SqlServer) => "sql-sqlserver",
+            Preset::Sql(SqlDialect::Oracle) => "sql-oracle",
+            Preset::EnvVar => "env-var",
+            Preset::HtmlId => "html-id",
+            Preset::GitHubAnchor => "github-anchor",
+            Preset::GitLabAnchor => "gitlab-anchor",
+            Preset::PandocAnchor => "pandoc-anchor",
+            Preset::MdBookAnchor => "mdbook-anchor",
+            Preset::DocusaurusAnchor => "docusaurus-anchor",
+            Preset::WordPress => "wordpress",
+            Preset::PythonSlugify => "python-slugify",
+            Preset::NpmSlugify => "npm-slugify",
+            Preset::NpmSlugifyStrict => "npm-slugify-strict",
+            Preset::SpeakingUrl => "speakingurl",
+            Preset::HugoUrlize => "hugo-urlize",
+            Preset::Jekyll(JekyllMode::Raw) => "jekyll-raw",
+            Preset::Jekyll(JekyllMode::Default) => "jekyll",
+            Preset::Jekyll(JekyllMode::Pretty) => "jekyll-pretty",
+            Preset::Jekyll(JekyllMode::Ascii) => "jekyll-ascii",
+            Preset::Jekyll(JekyllMode::Latin) => "jekyll-latin",
+            Preset::TwitterHandle => "twitter-handle",
+            Preset::GitHubUsername => "github-username",
+            Preset::InstagramHandle => "instagram-handle",
+            Preset::EmailLocalPart => "email-local-part",
+        }
+    }
+
+    /// Applies the preset's options to `slugifier`.
+    pub(crate) fn configure(self, slugifier: Slugifier) -> Slugifier {
+        match self {
+            Preset::DnsLabel => slugifier.separator("-").to_lowercase(true).truncate(63),
+            Preset::K8sName => slugifier
+                .separator("-")
+                .to_lowercase(true)
+                .allowed_chars(".")
+                .truncate(253),
+            Preset::K8sLabelValue => slugifier
+                .separator("-")
+                .to_lowercase(false)
+                .allowed_chars("._")
+                .truncate(63),
+            Preset::DockerRepository => slugifier
+                .separator("-")
+                .to_lowercase(true)
+                .allowed_chars("._/")
+                .truncate(255),
+            Preset::DockerTag => slugifier
+                .separator("-")
+                .to_lowercase(false)
+                .allowed_chars("._")
+                .truncate(128),
+            Preset::S3Bucket => slugifier
+                .separator("-")
+                .to_lowercase(true)
+                .allowed_chars(".")
+                .truncate(63),
+            Preset::S3ObjectKey => slugifier
+                .separator("-")
+                .to_lowercase(false)
+                .allowed_chars("/_.")
+                .truncate(1024),
+            Preset::NpmPackage => slugifier
+                .separator("-")
+                .to_lowercase(true)
+                .allowed_chars("._~")
+                .truncate(214),
+            Preset::CratesIo => slugifier
+                .separator("-")
+                .to_lowercase(true)
+                .allowed_chars("_")
+                .truncate(64),
+            Preset::RustIdent => slugifier.separator("_").to_lowercase(true),
+            Preset::EnvVar => slugifier.separator("_").to_lowercase(false),
+            Preset::HtmlId => slugifier
+                .separator("-")
+                .to_lowercase(true)
+                .allowed_chars("_"),
+            Preset::GitHubAnchor
+            | Preset::GitLabAnchor
+            | Preset::PandocAnchor
+            | Preset::MdBookAnchor
+            | Preset::DocusaurusAnchor
+            | Preset::WordPress => slugifier.separator("-").to_lowercase(true),
+            Preset::NpmSlugify | Preset::NpmSlugifyStrict => {
+                slugifier.separator("-").to_lowercase(false)
+            }
+            Preset::TwitterHandle => slugifier.separator("_").to_lowercase(true).truncate(15),
+            Preset::GitHubUsername => slugifier.separator("-").to_lowercase(true).truncate(39),
+            Preset::InstagramHandle => slugifier
+                .separator(".")
+                .to_lowercase(true)
+                .allowed_chars("_")
+                .truncate(30),
+            Preset::EmailLocalPart => slugifier
+                .separator(".")
+                .to_lowercase(true)
+                .allowed_chars("-_")
+                .remove_chars("'")
+                .truncate(64),
+            Preset::HugoUrlize | Preset::Jekyll(_) => slugifier.separator("-").to_lowercase(true),
+            Preset::SpeakingUrl => slugifier.separator("-").to_lowercase(true).lang(Some("en")),
+            Preset::PythonSlugify => slugifier
+                .separator("-")
+                .to_lowercase(true)
+                .word_boundary(false)
+                .save_order(false),
+            Preset::Sql(dialect) => {
+                let slugifier = slugifier.separator("_").to_lowercase(true);
+                match dialect.max_identifier_len() {
+                    Some(max) => slugifier.truncate(max),
+                    None => slugifier,
+                }
+            }
+        }
+    }
+
+    /// Whether the preset produces `c` beyond the characters configured on the slugifier.
+    pub(crate) fn allows_char(self, c: char) -> bool {
+        match self {
+            Preset::NpmPackage => matches!(c, '@' | '/'),
+            Preset::RustIdent => c == '#',
+            Preset::GitHubAnchor | Preset::GitLabAnchor | Preset::DocusaurusAnchor => {
+                c == '_' || (c.is_alphanumeric() && !c.is_uppercase()) || is_combining_mark(c)
+            }
+            Preset::MdBookAnchor => c == '_' || (c.is_alphanumeric() && !c.is_ascii_uppercase()),
+            Preset::WordPress => matches!(c, '_' | '%'),
+            Preset::NpmSlugify => "*_+~.()'\"!:@".contains(c),
+            Preset::HugoUrlize => "./_#+~@%".contains(c),
+            Preset::Jekyll(mode) => {
+                let is_word_char =
+                    (c.is_alphanumeric() && !c.is_uppercase()) || is_combining_mark(c);
+                match mode {
+                    JekyllMode::Raw => !c.is_whitespace() && !c.is_uppercase(),
+                    JekyllMode::Default => is_word_char,
+                    JekyllMode::Pretty => is_word_char || "._~!$&'()+,;=@".contains(c),
+                    _ => false,
+                }
+            }
+            Preset::PandocAnchor => {
+                matches!(c, '_' | '.') || (c.is_alphanumeric() && !c.is_uppercase())
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the preset replaces the generic pipeline for some input, see `slugify`.
+    pub(crate) fn has_custom_slugify(self) -> bool {
+        matches!(
+            self,
+            Preset::NpmPackage
+                | Preset::RustIdent
+                | Preset::GitHubAnchor
+                | Preset::GitLabAnchor
+                | Preset::PandocAnchor
+                | Preset::MdBookAnchor
+                | Preset::DocusaurusAnchor
+                | Preset::WordPress
+                | Preset::PythonSlugify
+                | Preset::NpmSlugify
+                | Preset::NpmSlugifyStrict
+                | Preset::HugoUrlize
+                | Preset::Jekyll(_)
+        )
+    }
+
+    /// Slugifies input that the preset handles differently from the generic
+    /// pipeline, returning `None` for input that needs no special treatment.
+    pub(crate) fn slugify(self, slugifier: &Slugifier, text: &str) -> Option<String> {
+        match self {
+            Preset::NpmPackage => {
+                let (scope, name) = text.trim().strip_prefix('@')?.split_once('/')?;
+                let mut scope = slugifier.slugify(scope);
+                let mut name = slugifier.slugify(name);
+                // The length limit covers the whole name, so the scope leaves
+                // room for `@`, `/`, and at least one character of the name.
+                if let Some(max) = slugifier.truncate {
+                    slugifier.truncate_to(&mut scope, max.saturating_sub(3));
+                }
+                // Without a name, the scope alone doesn't make a package name.
+                if scope.is_empty() || name.is_empty() {
+                    return Some(name);
+                }
+                if let Some(max) = slugifier.truncate {
+                    slugifier.truncate_to(&mut name, max - scope.len() - 2);
+                }
+                Some(format!("@{scope}/{name}"))
+            }
+            Preset::WordPress => {
+                let mut slug = compat::wordpress_title(text);
+                compat::truncate_percent_encoded(slugifier, &mut slug);
+                Some(slug)
+            }
+            Preset::PythonSlugify => Some(compat::python_slugify(slugifier, text)),
+            Preset::NpmSlugify => Some(compat::npm_slugify(slugifier, text, false)),
+            Preset::HugoUrlize => Some(compat::hugo_urlize(slugifier, text)),
+            Preset::Jekyll(mode) => Some(compat::jekyll_slugify(slugifier, text, mode)),
+            Preset::NpmSlugifyStrict => Some(compat::npm_slugify(slugifier, text, true)),
+            Preset::GitHubAnchor
+            | Preset::GitLabAnchor
+            | Preset::PandocAnchor
+            | Preset::MdBookAnchor
+            | Preset::DocusaurusAnchor => {
+                let mut slug = match self {
+                    Preset::GitLabAnchor => anchor::gitlab_anchor(text),
+                    Preset::PandocAnchor => anchor::pandoc_anchor(text),
+                    Preset::MdBookAnchor => anchor::mdbook_anchor(text),
+                    _ => anchor::github_anchor(text),
+                };
+                slugifier.apply_truncation(&mut slug);
+                Some(slug)
+            }
+            Preset::RustIdent => {
+                // Raw identifiers are already valid and must not be re-escaped.
+                let keyword = text.strip_prefix("r#")?;
+                (RUST_KEYWORDS.contains(&keyword) && !RUST_NON_RAW_KEYWORDS.contains(&keyword))
+                    .then(|| text.to_string())
+            }
+            _ => None,
+        }
+    }
+
+    /// Applies the preset's rules to a slug produced by the generic pipeline.
+    pub(crate) fn finish(self, slugifier: &Slugifier, slug: &mut String) {
+        match self {
+            Preset::S3Bucket => {
+                // Stripping can uncover another reserved prefix or suffix, like
+                // the first "-s3alias" of "logs-s3alias-s3alias".
+                loop {
+                    let mut name = slug.as_str();
+                    for prefix in S3_RESERVED_PREFIXES {
+                        name = name.strip_prefix(prefix).unwrap_or(name);
+                    }
+                    for suffix in S3_RESERVED_SUFFIXES {
+                        name = name.strip_suffix(suffix).unwrap_or(name);
+                    }
+                    let name = name.trim_matches(['-', '.']);
+                    if name.len() == slug.len() {
+                        break;
+                    }
+                    *slug = name.to_string();
+                }
+                if is_ip_address(slug) {
+                    *slug = slug.replace('.', "-");
+                }
+                // Names that are too short are padded rather than left invalid.
+                if !slug.is_empty() {
+                    while slug.len() < 3 {
+                        slug.push('0');
+                    }
+                }
+            }
+            Preset::CratesIo => {
+                let start = slug
+                    .find(|c: char| c.is_ascii_alphabetic())
+                    .unwrap_or(slug.len());
+                slug.drain(..start);
+            }
+            Preset::RustIdent => escape(slugifier, slug, slugifier.truncate, |ident| {
+                if ident.starts_with(|c: char| c.is_ascii_digit()) {
+                    ("_", "")
+                } else if RUST_NON_RAW_KEYWORDS.contains(&ident) {
+                    ("", "_")
+                } else if RUST_KEYWORDS.contains(&ident) {
+                    ("r#", "")
+                } else {
+                    ("", "")
+                }
+            }),
+            Preset::EnvVar => {
+                slug.make_ascii_uppercase();
+                escape(slugifier, slug, slugifier.truncate, |name| {
+                    if name.starts_with(|c: char| c.is_ascii_digit()) {
+                        ("VAR_", "")
+                    } else {
+                        ("", "")
+                    }
+                });
+            }
+            Preset::HtmlId => escape(slugifier, slug, slugifier.truncate, |id| {
+                if id.starts_with(|c: char| c.is_ascii_digit()) {
+                    ("_", "")
+                } else {
+                    ("", "")
+                }
+            }),
+            Preset::Sql(dialect) => {
+                let max_len = match (slugifier.truncate, dialect.max_identifier_len()) {
+                    (Some(truncate), Some(max)) => Some(truncate.min(max)),
+                    (truncate, max) => truncate.or(max),
+                };
+                escape(slugifier, slug, max_len, |ident| {
+                    if ident.starts_with(|c: char| c.is_ascii_digit()) {
+                        (dialect.digit_prefix(), "")
+                    } else if SQL_RESERVED_WORDS.contains(&ident) {
+                        ("", "_")
+                    } else {
+                        ("", "")
+                    }
+                });
+            }
+            _ => {}
+        }
+    }
+
+    /// Checks the preset's rules that the generic validation doesn't cover.
+    pub(crate) fn check(self, slug: &str) -> Result<(), Error> {
+        match self {
+            Preset::S3Bucket => {
+                if slug.len() < 3 {
+                    return Err(Error::TooShort {
+                        len: slug.len(),
+                        min: 3,
+                    });
+                }
+                if is_ip_address(slug)
+                    || S3_RESERVED_PREFIXES.iter().any(|p| slug.starts_with(p))
+                    || S3_RESERVED_SUFFIXES.iter().any(|s| slug.ends_with(s))
+                {
+                    return Err(Error::Reserved {
+                        slug: slug.to_string(),
+                    });
+                }
+                Ok(())
+            }
+            Preset::NpmPackage => {
+                let is_scoped_name = |scoped: &str| {
+                    scoped
+                        .split_once('/')
+                        .is_some_and(|(scope, name)| !scope.is_empty() && !name.is_empty())
+                };
+                match slug.strip_prefix('@') {
+                    Some(scoped) if !is_scoped_name(scoped) => Err(Error::NotNormalized),
+                    _ => Ok(()),
+                }
+            }
+            Preset::TwitterHandle if slug.len() < 5 => Err(Error::TooShort {
+                len: slug.len(),
+                min: 5,
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dns_label() {
+        let slugifier = Slugifier::new().preset(Preset::DnsLabel);
+        assert_eq!(
+            slugifier.slugify("  Tenant #42 — Ünïcode "),
+            "tenant-42-unicode"
+        );
+
+        let long = "word ".repeat(20);
+        let label = slugifier.slugify(&long);
+        assert!(label.len() <= 63);
+        assert!(!label.ends_with('-'));
+    }
+
+    #[test]
+    fn test_dns_label_validation() {
+        let slugifier = Slugifier::new().preset(Preset::DnsLabel);
+        assert_eq!(slugifier.validate("my-tenant"), Ok(()));
+        assert_eq!(slugifier.validate(""), Err(Error::Empty));
+        assert_eq!(
+            slugifier.validate("my_tenant"),
+            Err(Error::InvalidChar { ch: '_', index: 2 })
+        );
+        assert_eq!(
+            slugifier.validate(&"a".repeat(64)),
+            Err(Error::TooLong { len: 64, max: 63 })
+        );
+        assert_eq!(slugifier.validate("tenant-"), Err(Error::NotNormalized));
+    }
+
+    #[test]
+    fn test_k8s_name() {
+        let slugifier = Slugifier::new().preset(Preset::K8sName);
+        assert_eq!(
+            slugifier.slugify("My App v1.2 (Staging)"),
+            "my-app-v1.2-staging"
+        );
+        assert_eq!(slugifier.slugify(".internal.svc."), "internal.svc");
+        assert_eq!(slugifier.slugify(&"a".repeat(300)).len(), 253);
+    }
+
+    #[test]
+    fn test_k8s_label_value() {
+        let slugifier = Slugifier::new().preset(Preset::K8sLabelValue);
+        assert_eq!(
+            slugifier.slugify("Release_2024.Q1 Final"),
+            "Release_2024.Q1-Final"
+        );
+        assert_eq!(slugifier.slugify("_private_"), "private");
+
+        let value = slugifier.slugify(&"ab_".repeat(30));
+        assert!(value.len() <= 63);
+        assert!(value.ends_with(|c: char| c.is_ascii_alphanumeric()));
+    }
+
+    #[test]
+    fn test_docker_repository() {
+        let slugifier = Slugifier::new().preset(Preset::DockerRepository);
+        assert_eq!(slugifier.slugify("MyTeam/Web App"), "myteam/web-app");
+        assert_eq!(slugifier.slugify("/team//api_server/"), "team-api_server");
+    }
+
+    #[test]
+    fn test_docker_tag() {
+        let slugifier = Slugifier::new().preset(Preset::DockerTag);
+        assert_eq!(
+            slugifier.slugify("feature/JIRA-123_login"),
+            "feature-JIRA-123_login"
+        );
+        assert_eq!(slugifier.slugify("-v1.2.0-rc.1"), "v1.2.0-rc.1");
+        assert!(slugifier.slugify(&"commit title ".repeat(20)).len() <= 128);
+    }
+
+    #[test]
+    fn test_s3_bucket() {
+        let slugifier = Slugifier::new().preset(Preset::S3Bucket);
+        assert_eq!(
+            slugifier.slugify("Acme Corp. Backups.."),
+            "acme-corp-backups"
+        );
+        assert_eq!(slugifier.slugify("logs..example.com"), "logs-example.com");
+        assert_eq!(slugifier.slugify("192.168.0.1"), "192-168-0-1");
+        assert_eq!(slugifier.slugify("sthree-media"), "media");
+        assert_eq!(slugifier.slugify("my bucket-s3alias-s3alias"), "my-bucket");
+        assert_eq!(slugifier.slugify("sthree-sthree-logs.mrap"), "logs");
+        assert_eq!(slugifier.slugify("ab"), "ab0");
+        assert_eq!(slugifier.slugify("..a.."), "a00");
+        assert_eq!(slugifier.slugify("?!"), "");
+        for name in ["my-bucket", "ab0", "a00"] {
+            assert_eq!(slugifier.validate(name), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_s3_bucket_validation() {
+        let slugifier = Slugifier::new().preset(Preset::S3Bucket);
+        assert_eq!(slugifier.validate("my.bucket"), Ok(()));
+        assert_eq!(
+            slugifier.validate("ab"),
+            Err(Error::TooShort { len: 2, min: 3 })
+        );
+        assert_eq!(
+            slugifier.validate("10.0.0.1"),
+            Err(Error::Reserved {
+                slug: "10.0.0.1".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_s3_object_key() {
+        let slugifier = Slugifier::new().preset(Preset::S3ObjectKey);
+        assert_eq!(
+            slugifier.slugify("/uploads/My Photo #1.JPG"),
+            "uploads/My-Photo-1.JPG"
+        );
+        assert_eq!(slugifier.slugify("a//b?.txt"), "a-b-txt");
+    }
+
+    #[test]
+    fn test_npm_package() {
+        let slugifier = Slugifier::new().preset(Preset::NpmPackage);
+        assert_eq!(slugifier.slugify("My Cool Lib.js"), "my-cool-lib.js");
+        assert_eq!(slugifier.slugify("_private .thing"), "private-thing");
+        assert_eq!(slugifier.slugify("@My Org/Fancy UI"), "@my-org/fancy-ui");
+        assert_eq!(slugifier.slugify("@/name"), "name");
+        assert_eq!(slugifier.validate("@my-org/fancy-ui"), Ok(()));
+        assert_eq!(slugifier.slugify("@My Org/"), "");
+        assert_eq!(slugifier.slugify("@My Org/!?"), "");
+        assert_eq!(slugifier.validate("@my-org/"), Err(Error::NotNormalized));
+        assert_eq!(slugifier.validate("@/name"), Err(Error::NotNormalized));
+
+        let name = slugifier.slugify(&format!("@{}/{}", "a".repeat(150), "b".repeat(150)));
+        assert_eq!(name.len(), 214);
+        assert!(name.starts_with(&format!("@{}/b", "a".repeat(150))));
+        assert_eq!(slugifier.validate(&name), Ok(()));
+
+        let name = slugifier.slugify(&format!("@{}/{}", "a".repeat(300), "b".repeat(10)));
+        assert_eq!(name, format!("@{}/b", "a".repeat(211)));
+    }
+
+    #[test]
+    fn test_crates_io() {
+        let slugifier = Slugifier::new().preset(Preset::CratesIo);
+        assert_eq!(slugifier.slugify("Serde JSON_ext!"), "serde-json_ext");
+        assert_eq!(slugifier.slugify("3D Engine"), "d-engine");
+        assert_eq!(slugifier.slugify("2048 game"), "game");
+        assert!(slugifier.slugify(&"long name ".repeat(10)).len() <= 64);
+    }
+
+    #[test]
+    fn test_rust_ident() {
+        let slugifier = Slugifier::new().preset(Preset::RustIdent);
+        assert_eq!(slugifier.slugify("User Name"), "user_name");
+        assert_eq!(slugifier.slugify("2nd Place"), "_2nd_place");
+        assert_eq!(slugifier.slugify("Type"), "r#type");
+        assert_eq!(slugifier.slugify("self"), "self_");
+        assert_eq!(slugifier.slugify("r#match"), "r#match");
+
+        let slugifier = slugifier.truncate(6);
+        assert_eq!(slugifier.slugify("12345678"), "_12345");
+        assert_eq!(slugifier.slugify("Type"), "r#type");
+        assert_eq!(slugifier.slugify("Typeof"), "r#type");
+        assert_eq!(slugifier.slugify("Super"), "super_");
+        assert_eq!(slugifier.validate("_12345"), Ok(()));
+        assert_eq!(slugifier.truncate(5).slugify("Super"), "supe");
+    }
+
+    #[test]
+    fn test_rust_ident_validation() {
+        let slugifier = Slugifier::new().preset(Preset::RustIdent);
+        assert_eq!(slugifier.validate("r#type"), Ok(()));
+        assert_eq!(slugifier.validate("_9lives"), Ok(()));
+        assert_eq!(slugifier.validate("type"), Err(Error::NotNormalized));
+    }
+
+    #[test]
+    fn test_env_var() {
+        let slugifier = Slugifier::new().preset(Preset::EnvVar);
+        assert_eq!(slugifier.slugify("Database URL"), "DATABASE_URL");
+        assert_eq!(slugifier.slugify("api-key (prod)"), "API_KEY_PROD");
+        assert_eq!(slugifier.slugify("2FA secret"), "VAR_2FA_SECRET");
+        assert_eq!(slugifier.clone().truncate(6).slugify("12345678"), "VAR_12");
+        assert_eq!(slugifier.clone().truncate(6).validate("VAR_12"), Ok(()));
+        assert_eq!(slugifier.validate("DATABASE_URL"), Ok(()));
+        assert_eq!(
+            slugifier.validate("database_url"),
+            Err(Error::NotNormalized)
+        );
+    }
+
+    #[test]
+    fn test_html_id() {
+        let slugifier = Slugifier::new().preset(Preset::HtmlId);
+        assert_eq!(slugifier.slugify("2024 Report"), "_2024-report");
+        assert_eq!(slugifier.slugify("--main_nav--"), "main_nav");
+        assert_eq!(slugifier.validate("_2024-report"), Ok(()));
+
+        let slugifier = slugifier.truncate(6);
+        assert_eq!(slugifier.slugify("2024 Report"), "_2024");
+        assert_eq!(slugifier.slugify("123456789"), "_12345");
+        assert_eq!(slugifier.validate("_12345"), Ok(()));
+    }
+
+    #[test]
+    fn test_github_anchor() {
+        let slugifier = Slugifier::new().preset(Preset::GitHubAnchor);
+        assert_eq!(slugifier.slugify("Why use `rslug`?"), "why-use-rslug");
+        assert_eq!(slugifier.slugify("C++ & C#"), "c--c");
+        assert_eq!(slugifier.validate("über_uns"), Ok(()));
+
+        // "İ" lowercases to "i" followed by a combining dot above.
+        let anchor = slugifier.slugify("İstanbul");
+        assert_eq!(anchor, "i\u{307}stanbul");
+        assert_eq!(slugifier.validate(&anchor), Ok(()));
+    }
+
+    #[test]
+    fn test_gitlab_and_pandoc_anchors() {
+        let gitlab = Slugifier::new().preset(Preset::GitLabAnchor);
+        assert_eq!(gitlab.slugify("C++ & C#"), "c-c");
+        assert_eq!(gitlab.slugify("404"), "anchor-404");
+
+        let pandoc = Slugifier::new().preset(Preset::PandocAnchor);
+        assert_eq!(pandoc.slugify("2.1 Release v2.1"), "release-v2.1");
+        assert_eq!(pandoc.validate("release-v2.1"), Ok(()));
+    }
+
+    #[test]
+    fn test_mdbook_and_docusaurus_anchors() {
+        let mdbook = Slugifier::new().preset(Preset::MdBookAnchor);
+        assert_eq!(mdbook.slugify("The `Option` Enum"), "the-option-enum");
+
+        let docusaurus = Slugifier::new().preset(Preset::DocusaurusAnchor);
+        let github = Slugifier::new().preset(Preset::GitHubAnchor);
+        for heading in ["Getting Started!", "API & SDK", "Déjà vu  twice"] {
+            assert_eq!(docusaurus.slugify(heading), github.slugify(heading));
+        }
+    }
+
+    #[test]
+    fn test_wordpress() {
+        let slugifier = Slugifier::new().preset(Preset::WordPress);
+        assert_eq!(
+            slugifier.slugify("My 1st Post: Ünïcode!"),
+            "my-1st-post-unicode"
+        );
+        assert_eq!(slugifier.validate("hello_world-2"), Ok(()));
+
+        let slugifier = slugifier.truncate(8);
+        assert_eq!(
+            slugifier.slugify("Hello Wonderful World of Things"),
+            "hello"
+        );
+        let slugifier = slugifier.truncate(12);
+        assert_eq!(slugifier.slugify("日本 post"), "%e6%97%a5");
+    }
+
+    #[test]
+    fn test_python_slugify() {
+        let slugifier = Slugifier::new().preset(Preset::PythonSlugify).truncate(9);
+        assert_eq!(slugifier.slugify("jaja---lol-méméméoo--a"), "jaja-lol");
+        let slugifier = slugifier.word_boundary(true).truncate(15);
+        assert_eq!(slugifier.slugify("jaja---lol-méméméoo--a"), "jaja-lol-a");
+        let slugifier = slugifier.save_order(true);
+        assert_eq!(slugifier.slugify("jaja---lol-méméméoo--a"), "jaja-lol");
+        assert_eq!(slugifier.validate("jaja-lol"), Ok(()));
+    }
+
+    #[test]
+    fn test_npm_slugify() {
+        let slugifier = Slugifier::new().preset(Preset::NpmSlugify);
+        assert_eq!(slugifier.slugify("I ♥ Rust!"), "I-love-Rust!");
+        assert_eq!(slugifier.validate("I-love-Rust!"), Ok(()));
+
+        let slugifier = slugifier.to_lowercase(true).remove_chars("!");
+        assert_eq!(slugifier.slugify("I ♥ Rust, Go!"), "i-love-rust,-go");
+
+        let slugifier = Slugifier::new().preset(Preset::NpmSlugifyStrict);
+        assert_eq!(slugifier.slugify("I ♥ Rust (2024)!"), "I-love-Rust-2024");
+
+        let slugifier = slugifier.truncate(10);
+        assert_eq!(slugifier.slugify("I ♥ Rust (2024)!"), "I-love");
+        let slugifier = Slugifier::new().preset(Preset::NpmSlugify).truncate(12);
+        assert_eq!(slugifier.slugify("I ♥ Rust (2024)!"), "I-love-Rust");
+    }
+
+    #[test]
+    fn test_speakingurl() {
+        let slugifier = Slugifier::new().preset(Preset::SpeakingUrl);
+        assert_eq!(
+            slugifier.slugify("Schöner Titel läßt grüßen!? Bel été !"),
+            "schoner-titel-lasst-grussen-bel-ete"
+        );
+        assert_eq!(slugifier.slugify("I ♥ Rust & Go"), "i-love-rust-and-go");
+
+        let slugifier = slugifier.lang(Some("de")).replace("Rust", "rostig");
+        assert_eq!(slugifier.slugify("Schöner Titel"), "schoener-titel");
+        assert_eq!(slugifier.slugify("I ♥ Rust & Go"), "i-liebe-rostig-und-go");
+    }
+
+    #[test]
+    fn test_hugo_and_jekyll() {
+        let slugifier = Slugifier::new().preset(Preset::HugoUrlize);
+        assert_eq!(slugifier.slugify("Über uns: Team"), "%C3%BCber-uns-team");
+        assert_eq!(slugifier.validate("c#-and-c++"), Ok(()));
+        let slug = slugifier.slugify("Café Éclair");
+        assert_eq!(slug, "caf%C3%A9-%C3%A9clair");
+        assert_eq!(slugifier.validate(&slug), Ok(()));
+        assert_eq!(
+            slugifier.validate("cafE"),
+            Err(Error::InvalidChar { ch: 'E', index: 3 })
+        );
+
+        let slugifier = Slugifier::new().preset(Preset::Jekyll(JekyllMode::Pretty));
+        assert_eq!(slugifier.slugify("What's new in 2.0?"), "what's-new-in-2.0");
+        assert_eq!(slugifier.validate("what's-new-in-2.0"), Ok(()));
+        let slug = slugifier.slugify("Ökologie İstanbul");
+        assert_eq!(slug, "ökologie-i\u{307}stanbul");
+        assert_eq!(slugifier.validate(&slug), Ok(()));
+
+        let slugifier = slugifier.to_lowercase(false);
+        assert_eq!(slugifier.slugify("What's new in 2.0?"), "What's-new-in-2.0");
+    }
+
+    #[test]
+    fn test_preset_names_round_trip() {
+        for &preset in Preset::ALL {
+            assert_eq!(preset.name().parse(), Ok(preset));
+            assert_eq!(preset.to_string(), preset.name());
+        }
+        assert_eq!("GitHub-Anchor".parse(), Ok(Preset::GitHubAnchor));
+        assert_eq!(
+            "github".parse::<Preset>(),
+            Err(Error::UnknownPreset {
+                name: "github".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_social_handles() {
+        let twitter = Slugifier::new().preset(Preset::TwitterHandle);
+        assert_eq!(twitter.slugify("José Pérez-García Jr."), "jose_perez");
+        assert_eq!(twitter.validate("jose_perez"), Ok(()));
+        assert_eq!(
+            twitter.validate("jo"),
+            Err(Error::TooShort { len: 2, min: 5 })
+        );
+        assert_eq!(twitter.slugify("Al"), "al");
+
+        let github = Slugifier::new().preset(Preset::GitHubUsername);
+        assert_eq!(github.slugify("--Octo Cat--"), "octo-cat");
+        assert!(github.validate("octo--cat").is_err());
+
+        let instagram = Slugifier::new().preset(Preset::InstagramHandle);
+        assert_eq!(
+            instagram.slugify("Jane  Doe_Official."),
+            "jane.doe_official"
+        );
+        assert!(instagram.validate("jane..doe").is_err());
+        assert!(instagram.validate(".jane").is_err());
+    }
+
+    #[test]
+    fn test_email_local_part() {
+        let slugifier = Slugifier::new().preset(Preset::EmailLocalPart);
+        assert_eq!(slugifier.slugify("José Pérez Jr."), "jose.perez.jr");
+        assert_eq!(slugifier.slugify("Mary-Jane O'Neil"), "mary-jane.oneil");
+        assert_eq!(slugifier.validate("mary-jane.oneil"), Ok(()));
+        assert!(slugifier.validate("jose..perez").is_err());
+    }
+
+    #[test]
+    fn test_sql_identifier() {
+        let slugifier = Slugifier::new().preset(Preset::Sql(SqlDialect::Postgres));
+        assert_eq!(slugifier.slugify("Customer E-mail"), "customer_e_mail");
+        assert_eq!(slugifier.slugify("Order"), "order_");
+        assert_eq!(slugifier.slugify("2024 Revenue ($)"), "_2024_revenue");
+        assert!(slugifier.slugify(&"column ".repeat(20)).len() <= 63);
+
+        let oracle = Slugifier::new().preset(Preset::Sql(SqlDialect::Oracle));
+        assert_eq!(oracle.slugify("1st Quarter"), "x_1st_quarter");
+    }
+
+    #[test]
+    fn test_sql_identifier_prefix_respects_length_limit() {
+        let slugifier = Slugifier::new().preset(Preset::Sql(SqlDialect::MySql));
+        let identifier = slugifier.slugify(&"9".repeat(100));
+        assert_eq!(identifier.len(), 64);
+        assert!(identifier.starts_with('_'));
+    }
+
+    #[test]
+    fn test_sql_identifier_escape_respects_truncation() {
+        let slugifier = Slugifier::new()
+            .preset(Preset::Sql(SqlDialect::Postgres))
+            .truncate(6);
+        assert_eq!(slugifier.slugify("12345678"), "_12345");
+        assert_eq!(slugifier.slugify("Select"), "selec");
+        assert_eq!(slugifier.validate("_12345"), Ok(()));
+
+        let oracle = Slugifier::new()
+            .preset(Preset::Sql(SqlDialect::Oracle))
+            .truncate(6);
+        assert_eq!(oracle.slugify("12345678"), "x_1234");
+
+        // A truncation longer than the dialect allows is capped.
+        let slugifier = Slugifier::new()
+            .preset(Preset::Sql(SqlDialect::Postgres))
+            .truncate(100);
+        assert_eq!(slugifier.slugify(&"9".repeat(100)).len(), 63);
+    }
+}
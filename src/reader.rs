@@ -0,0 +1,114 @@
+//! Slugification of text read incrementally from a reader.
+
+use std::io::{self, BufRead};
+
+use crate::{SlugBuilder, Slugifier, symbols};
+
+impl Slugifier {
+    /// Generates a single slug from all text read from `reader`.
+    ///
+    /// The input is processed a line at a time, so only the longest line and
+    /// the slug itself are held in memory. Invalid UTF-8 is replaced with
+    /// U+FFFD, which separates words. Presets that replace the slug algorithm,
+    /// like the heading anchor and compatibility presets, need the whole input
+    /// at once and read it into memory first, and so does reporting losses to
+    /// the callback set with [`on_loss`](Slugifier::on_loss).
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from reading `reader`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// let input = "A Title\nspanning Lines\n".as_bytes();
+    /// let slug = Slugifier::new().slugify_reader(input).unwrap();
+    /// assert_eq!(slug, "a-title-spanning-lines");
+    /// ```
+    pub fn slugify_reader<R: BufRead>(&self, mut reader: R) -> io::Result<String> {
+        if self.on_loss.is_some()
+            || self
+                .preset
+                .is_some_and(|preset| preset.has_custom_slugify())
+        {
+            let mut text = Vec::new();
+            reader.read_to_end(&mut text)?;
+            return Ok(self.slugify(&String::from_utf8_lossy(&text)));
+        }
+
+        let mut builder = SlugBuilder::new(self);
+        let mut line = Vec::new();
+        while reader.read_until(b'\n', &mut line)? > 0 {
+            let text = String::from_utf8_lossy(&line);
+            let text = symbols::apply(&text, self);
+            builder.push_str(&text);
+            line.clear();
+        }
+
+        Ok(builder.finish())
+    }
+
+    /// Lazily slugifies each line read from `reader`.
+    ///
+    /// Lines end with `\n` or `\r\n`, and invalid UTF-8 is replaced with
+    /// U+FFFD, which separates words.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// let input = "First Post\r\nSecond Post\n".as_bytes();
+    /// let slugs: Vec<String> = Slugifier::new()
+    ///     .slugify_lines(input)
+    ///     .collect::<Result<_, _>>()
+    ///     .unwrap();
+    /// assert_eq!(slugs, ["first-post", "second-post"]);
+    /// ```
+    pub fn slugify_lines<'a, R: BufRead + 'a>(
+        &'a self,
+        reader: R,
+    ) -> impl Iterator<Item = io::Result<String>> + 'a {
+        reader.split(b'\n').map(|line| {
+            let mut line = line?;
+            if line.last() == Some(&b'\r') {
+                line.pop();
+            }
+            Ok(self.slugify(&String::from_utf8_lossy(&line)))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Preset;
+
+    #[test]
+    fn test_slugify_reader_matches_slugify() {
+        let slugifier = Slugifier::new().truncate(30).lang(Some("en"));
+        let text = "Ünïcode & friends:\n  more\u{200B}text\r\n\nend";
+        assert_eq!(
+            slugifier.slugify_reader(text.as_bytes()).unwrap(),
+            slugifier.slugify(text)
+        );
+    }
+
+    #[test]
+    fn test_slugify_reader_invalid_utf8() {
+        let input: &[u8] = b"caf\xE9 au lait";
+        assert_eq!(
+            Slugifier::new().slugify_reader(input).unwrap(),
+            "caf-au-lait"
+        );
+    }
+
+    #[test]
+    fn test_slugify_reader_with_custom_preset_algorithm() {
+        let slugifier = Slugifier::new().preset(Preset::GitHubAnchor);
+        assert_eq!(
+            slugifier.slugify_reader("What's New?".as_bytes()).unwrap(),
+            "whats-new"
+        );
+    }
+}
@@ -0,0 +1,224 @@
+//! A [`UniquenessStore`] backed by Redis, for allocating unique slugs across
+//! the processes of a cluster.
+
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+use crate::UniquenessStore;
+
+/// A [`UniquenessStore`] that reserves slugs as Redis keys.
+///
+/// A slug is reserved with a single `SET key 1 NX` command, which only sets
+/// the key if it doesn't exist, so concurrent allocations from any number of
+/// processes never win the same slug. With a [`ttl`](RedisStore::ttl), the
+/// reservation expires, which suits slugs that are only held until they are
+/// saved elsewhere, like drafts.
+///
+/// The store speaks the Redis protocol over any stream, so a TLS stream or one
+/// that was already authenticated can be used with [`RedisStore::new`].
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use rslug::{RedisStore, Slugifier};
+///
+/// let mut store = RedisStore::connect("127.0.0.1:6379")?
+///     .key_prefix("blog:slug:")
+///     .ttl(Duration::from_secs(3600));
+/// let slug = Slugifier::new().slugify_unique("Hello World", &mut store)?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct RedisStore<S: Read + Write = TcpStream> {
+    stream: BufReader<S>,
+    key_prefix: String,
+    ttl: Option<Duration>,
+}
+
+impl RedisStore {
+    /// Connects to the Redis server at `addr`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of connecting to the server.
+    pub fn connect<A: ToSocketAddrs>(addr: A) -> io::Result<Self> {
+        Ok(Self::new(TcpStream::connect(addr)?))
+    }
+}
+
+impl<S: Read + Write> RedisStore<S> {
+    /// Creates a store that sends its commands over `stream`, which must be
+    /// connected to a Redis server.
+    pub fn new(stream: S) -> Self {
+        Self {
+            stream: BufReader::new(stream),
+            key_prefix: String::new(),
+            ttl: None,
+        }
+    }
+
+    /// Sets the prefix of the keys that slugs are reserved as, which
+    /// namespaces them among the other keys of the server.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The string slice to prepend to each slug.
+    pub fn key_prefix(mut self, prefix: &str) -> Self {
+        self.key_prefix = prefix.to_string();
+        self
+    }
+
+    /// Sets how long a reservation lasts, after which the slug is free again.
+    ///
+    /// By default, reservations never expire. The time is rounded down to
+    /// milliseconds, and is at least one millisecond.
+    ///
+    /// # Arguments
+    ///
+    /// * `ttl` - The time a reservation lasts.
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Returns the stream the store sends its commands over.
+    pub fn into_inner(self) -> S {
+        self.stream.into_inner()
+    }
+
+    /// Sends `args` as a command and returns the first line of the reply.
+    fn command(&mut self, args: &[&[u8]]) -> io::Result<String> {
+        let mut command = format!("*{}\r\n", args.len()).into_bytes();
+        for arg in args {
+            command.extend_from_slice(format!("${}\r\n", arg.len()).as_bytes());
+            command.extend_from_slice(arg);
+            command.extend_from_slice(b"\r\n");
+        }
+        let stream = self.stream.get_mut();
+        stream.write_all(&command)?;
+        stream.flush()?;
+
+        let mut reply = String::new();
+        if self.stream.read_line(&mut reply)? == 0 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        Ok(reply.trim_end_matches(['\r', '\n']).to_string())
+    }
+}
+
+impl<S: Read + Write> UniquenessStore for RedisStore<S> {
+    type Error = io::Error;
+
+    fn reserve(&mut self, slug: &str) -> Result<bool, Self::Error> {
+        let key = format!("{}{slug}", self.key_prefix);
+        let ttl = self
+            .ttl
+            .map(|ttl| ttl.as_millis().max(1).to_string())
+            .unwrap_or_default();
+        let mut args: Vec<&[u8]> = vec![b"SET", key.as_bytes(), b"1", b"NX"];
+        if self.ttl.is_some() {
+            args.extend([&b"PX"[..], ttl.as_bytes()]);
+        }
+
+        // `SET` with `NX` replies `OK` if it set the key, and null if the key
+        // exists, which is `$-1` in RESP2 and `_` in RESP3.
+        let reply = self.command(&args)?;
+        match reply.as_str() {
+            "+OK" => Ok(true),
+            "$-1" | "_" => Ok(false),
+            _ => match reply.strip_prefix('-') {
+                Some(error) => Err(io::Error::other(error.to_string())),
+                None => Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("unexpected reply {reply:?}"),
+                )),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::Slugifier;
+
+    /// A stream that replies with canned replies and records the commands.
+    struct FakeServer {
+        replies: Cursor<&'static [u8]>,
+        commands: Vec<u8>,
+    }
+
+    impl FakeServer {
+        fn new(replies: &'static [u8]) -> Self {
+            Self {
+                replies: Cursor::new(replies),
+                commands: Vec::new(),
+            }
+        }
+    }
+
+    impl Read for FakeServer {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.replies.read(buf)
+        }
+    }
+
+    impl Write for FakeServer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.commands.write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_reserve_sends_set_nx() {
+        let server = FakeServer::new(b"$-1\r\n_\r\n+OK\r\n");
+        let mut store = RedisStore::new(server)
+            .key_prefix("s:")
+            .ttl(Duration::from_secs(60));
+        let slug = Slugifier::new().slugify_unique("Post", &mut store);
+        assert_eq!(slug.unwrap(), "post-3");
+
+        let commands = String::from_utf8(store.into_inner().commands).unwrap();
+        assert!(commands.starts_with(
+            "*6\r\n$3\r\nSET\r\n$6\r\ns:post\r\n$1\r\n1\r\n$2\r\nNX\r\n$2\r\nPX\r\n$5\r\n60000\r\n"
+        ));
+        assert!(
+            commands
+                .ends_with("$8\r\ns:post-3\r\n$1\r\n1\r\n$2\r\nNX\r\n$2\r\nPX\r\n$5\r\n60000\r\n")
+        );
+    }
+
+    #[test]
+    fn test_reserve_without_ttl() {
+        let mut store = RedisStore::new(FakeServer::new(b"+OK\r\n"));
+        assert!(store.reserve("post").unwrap());
+        let commands = store.into_inner().commands;
+        assert_eq!(
+            commands,
+            b"*4\r\n$3\r\nSET\r\n$4\r\npost\r\n$1\r\n1\r\n$2\r\nNX\r\n"
+        );
+    }
+
+    #[test]
+    fn test_reserve_errors() {
+        let mut store = RedisStore::new(FakeServer::new(b"-NOAUTH Authentication required.\r\n"));
+        let error = store.reserve("post").unwrap_err();
+        assert_eq!(error.to_string(), "NOAUTH Authentication required.");
+
+        let mut store = RedisStore::new(FakeServer::new(b":1\r\n"));
+        let error = store.reserve("post").unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+        let mut store = RedisStore::new(FakeServer::new(b""));
+        let error = store.reserve("post").unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+    }
+}
@@ -0,0 +1,93 @@
+//! A process-wide registry of named slugifier configurations.
+
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+
+use crate::{Error, Preset, Slugifier};
+
+/// Configurations registered with [`Slugifier::register_preset`], by name.
+fn registry() -> &'static RwLock<HashMap<String, Slugifier>> {
+    static REGISTRY: OnceLock<RwLock<HashMap<String, Slugifier>>> = OnceLock::new();
+    REGISTRY.get_or_init(Default::default)
+}
+
+impl Slugifier {
+    /// Creates a slugifier from the name of a preset, e.g. one read from a
+    /// configuration file.
+    ///
+    /// Configurations registered with [`register_preset`](Slugifier::register_preset)
+    /// are looked up first, then the built-in presets by their
+    /// [`name`](Preset::name), ignoring ASCII case.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::UnknownPreset`] if no preset has the given name.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// let slugifier = Slugifier::from_preset_name("github-anchor").unwrap();
+    /// assert_eq!(slugifier.slugify("What's New?"), "whats-new");
+    /// assert!(Slugifier::from_preset_name("no-such-preset").is_err());
+    /// ```
+    pub fn from_preset_name(name: &str) -> Result<Slugifier, Error> {
+        let registered = registry()
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get(name)
+            .cloned();
+        match registered {
+            Some(slugifier) => Ok(slugifier),
+            None => Ok(Slugifier::new().preset(name.parse::<Preset>()?)),
+        }
+    }
+
+    /// Registers a configuration under `name`, so that `from_preset_name`
+    /// returns it. Registering a name again replaces the previous configuration,
+    /// and registered names take precedence over built-in presets.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name to register the configuration under.
+    /// * `slugifier` - The configuration to register.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::{Preset, Slugifier};
+    /// Slugifier::register_preset("blog", Slugifier::new().preset(Preset::GitHubAnchor).truncate(8));
+    /// let slugifier = Slugifier::from_preset_name("blog").unwrap();
+    /// assert_eq!(slugifier.slugify("Release notes"), "release");
+    /// ```
+    pub fn register_preset(name: &str, slugifier: Slugifier) {
+        registry()
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert(name.to_string(), slugifier);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_preset_name() {
+        let slugifier = Slugifier::from_preset_name("DNS-Label").unwrap();
+        assert_eq!(slugifier.slugify("ACME Corp."), "acme-corp");
+        assert_eq!(
+            Slugifier::from_preset_name("nope").unwrap_err(),
+            Error::UnknownPreset {
+                name: "nope".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_register_preset() {
+        Slugifier::register_preset("snake-case", Slugifier::new().separator("_"));
+        let slugifier = Slugifier::from_preset_name("snake-case").unwrap();
+        assert_eq!(slugifier.slugify("Hello World"), "hello_world");
+    }
+}
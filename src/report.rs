@@ -0,0 +1,168 @@
+//! Diagnostics about what slugification changed or lost.
+
+use crate::builder::{self, SlugBuilder};
+use crate::symbols::Terms;
+use crate::{Slugifier, chars, translit};
+
+/// What happened to a text while it was slugified, returned by
+/// [`Slugifier::slugify_report`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub struct SlugifyReport {
+    /// The characters that left nothing in the slug, like punctuation and
+    /// symbols without a transliteration. Whitespace, invisible formatting
+    /// characters, and characters of the separator aren't counted.
+    pub dropped_chars: usize,
+    /// The non-ASCII characters that were transliterated into the slug.
+    pub transliterated_chars: usize,
+    /// The custom replacements and language symbols that were applied.
+    pub replacements: usize,
+    /// The stop words removed from the slug.
+    pub stop_words_removed: usize,
+    /// The segments removed past `max_segments`.
+    pub segments_removed: usize,
+    /// The length the slug had before it was truncated, if it was.
+    pub truncated_from: Option<usize>,
+    /// Whether the slug differs from the input.
+    pub changed: bool,
+}
+
+impl SlugifyReport {
+    /// Returns `true` if any part of the input was lost: dropped characters,
+    /// stop words, segments, or truncation.
+    pub fn is_lossy(&self) -> bool {
+        self.dropped_chars > 0
+            || self.stop_words_removed > 0
+            || self.segments_removed > 0
+            || self.truncated_from.is_some()
+    }
+}
+
+impl Slugifier {
+    /// Generates a slug like `slugify`, along with a report of what was
+    /// changed or lost on the way.
+    ///
+    /// Presets that replace the slug algorithm, like the heading anchor and
+    /// compatibility presets, report the characters and replacements of the
+    /// input, but not stop words or truncation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    ///
+    /// let slugifier = Slugifier::new().truncate(12);
+    /// let (slug, report) = slugifier.slugify_report("Café: déjà vu!");
+    /// assert_eq!(slug, "cafe-deja-vu");
+    /// assert_eq!(report.dropped_chars, 2);
+    /// assert_eq!(report.transliterated_chars, 3);
+    /// assert!(report.is_lossy());
+    /// ```
+    pub fn slugify_report(&self, text: &str) -> (String, SlugifyReport) {
+        let (replaced, replacements) = Terms::new(self).apply_counting(text);
+        let mut report = SlugifyReport {
+            replacements,
+            ..SlugifyReport::default()
+        };
+
+        for c in replaced.chars() {
+            if c.is_whitespace() || chars::is_invisible(c) {
+                continue;
+            }
+            let mut buffer = [0; 4];
+            let ascii = if c.is_ascii() {
+                &*c.encode_utf8(&mut buffer)
+            } else {
+                translit::transliterate_char(c)
+            };
+            // Characters matching the separator are kept as the separator.
+            let is_kept = ascii.chars().any(|c| {
+                (c.is_ascii_alphanumeric()
+                    || self.allowed_chars.contains(c)
+                    || self.separator.contains(c))
+                    && !self.remove_chars.contains(c)
+            });
+            if !is_kept {
+                report.dropped_chars += 1;
+            } else if !c.is_ascii() {
+                report.transliterated_chars += 1;
+            }
+        }
+
+        let slug = match self
+            .preset
+            .and_then(|preset| preset.slugify(self, &replaced))
+        {
+            Some(slug) => slug,
+            None => {
+                let mut builder = SlugBuilder::new(self);
+                builder.push_str(&replaced);
+                // Writing to a `String` never fails.
+                let slug = builder.into_sink().unwrap_or_default();
+
+                let (mut slug, removed) = builder::remove_stop_words(self, slug);
+                report.stop_words_removed = removed;
+                report.segments_removed = builder::limit_segments(self, &mut slug);
+
+                let len = slug.len();
+                self.apply_truncation(&mut slug);
+                if slug.len() < len {
+                    report.truncated_from = Some(len);
+                }
+
+                if let Some(preset) = self.preset {
+                    preset.finish(self, &mut slug);
+                }
+                slug
+            }
+        };
+
+        report.changed = slug != text;
+        (slug, report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Preset;
+
+    #[test]
+    fn test_report_matches_slugify() {
+        let slugifiers = [
+            Slugifier::new(),
+            Slugifier::new().stop_words(&["the"]).truncate(10),
+            Slugifier::new().lang(Some("en")),
+            Slugifier::new().preset(Preset::GitHubAnchor),
+        ];
+        for slugifier in &slugifiers {
+            for text in ["The Quick & the Dead", "Ünïcode 🎉!", "", "plain"] {
+                let (slug, _) = slugifier.slugify_report(text);
+                assert_eq!(slug, slugifier.slugify(text), "{text:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_report_counts() {
+        let slugifier = Slugifier::new()
+            .lang(Some("en"))
+            .stop_words(&["the"])
+            .truncate(14);
+        let (slug, report) = slugifier.slugify_report("The Rock & Roll Hall of Fame?");
+        assert_eq!(slug, "rock-and-roll");
+        assert_eq!(report.replacements, 1);
+        assert_eq!(report.stop_words_removed, 1);
+        assert_eq!(report.truncated_from, Some(26));
+        assert_eq!(report.dropped_chars, 1);
+        assert!(report.changed);
+    }
+
+    #[test]
+    fn test_lossless_report() {
+        let (slug, report) = Slugifier::new().slugify_report("hello-world");
+        assert_eq!(slug, "hello-world");
+        assert_eq!(report, SlugifyReport::default());
+        assert!(!report.is_lossy());
+    }
+}
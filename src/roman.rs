@@ -0,0 +1,104 @@
+//! Recognizing roman numerals, like the "IV" of "Part IV".
+
+/// How standalone roman numerals are slugified, set with
+/// [`Slugifier::roman_numerals`](crate::Slugifier::roman_numerals).
+///
+/// A roman numeral is a standalone word of at least two uppercase letters
+/// that is a valid numeral up to 3999, like "II", "IV", or "MCMXCIV". Single
+/// letters are left alone, since "I", "C", or "X" are more often a pronoun or
+/// an initial. Acronyms that happen to be numerals, like "CD", are numerals
+/// too.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum RomanNumerals {
+    /// Treat roman numerals like any other word.
+    #[default]
+    Ignore,
+    /// Keep roman numerals uppercase in lowercased slugs.
+    PreserveCase,
+    /// Replace roman numerals with arabic digits.
+    Arabic,
+}
+
+/// Returns the value of `word` if it is a roman numeral of at least two
+/// letters, written the standard way.
+pub(crate) fn parse(word: &[u8]) -> Option<u16> {
+    if word.len() < 2 {
+        return None;
+    }
+
+    let mut value: i32 = 0;
+    let mut previous = 0;
+    for &c in word.iter().rev() {
+        let digit = match c {
+            b'I' => 1,
+            b'V' => 5,
+            b'X' => 10,
+            b'L' => 50,
+            b'C' => 100,
+            b'D' => 500,
+            b'M' => 1000,
+            _ => return None,
+        };
+        if digit < previous {
+            value -= digit;
+        } else {
+            value += digit;
+            previous = digit;
+        }
+    }
+
+    // Forms like "IIII" or "IC" add up to a value too, so only accept the
+    // numeral if it is how that value is written.
+    let value = u16::try_from(value).ok().filter(|value| *value <= 3999)?;
+    (format(value).as_bytes() == word).then_some(value)
+}
+
+/// Writes `value` as a roman numeral.
+fn format(mut value: u16) -> String {
+    const NUMERALS: [(u16, &str); 13] = [
+        (1000, "M"),
+        (900, "CM"),
+        (500, "D"),
+        (400, "CD"),
+        (100, "C"),
+        (90, "XC"),
+        (50, "L"),
+        (40, "XL"),
+        (10, "X"),
+        (9, "IX"),
+        (5, "V"),
+        (4, "IV"),
+        (1, "I"),
+    ];
+
+    let mut numeral = String::new();
+    for (digit, letters) in NUMERALS {
+        while value >= digit {
+            numeral.push_str(letters);
+            value -= digit;
+        }
+    }
+    numeral
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse(b"II"), Some(2));
+        assert_eq!(parse(b"IV"), Some(4));
+        assert_eq!(parse(b"XLII"), Some(42));
+        assert_eq!(parse(b"MCMXCIV"), Some(1994));
+        assert_eq!(parse(b"MMMCMXCIX"), Some(3999));
+    }
+
+    #[test]
+    fn test_parse_rejects_non_numerals() {
+        for word in [&b"I"[..], b"X", b"IIII", b"IC", b"VV", b"iv", b"MIXED", b""] {
+            assert_eq!(parse(word), None, "{:?}", String::from_utf8_lossy(word));
+        }
+    }
+}
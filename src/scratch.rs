@@ -0,0 +1,16 @@
+//! Scratch directories for the tests that touch the filesystem.
+
+use std::fs;
+use std::path::PathBuf;
+
+/// Creates an empty scratch directory unique to the calling test.
+///
+/// The path is canonical, which makes it a verbatim path on Windows, so tests
+/// can create names with trailing dots or spaces that Windows would otherwise
+/// drop.
+pub(crate) fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("rslug-{}-{name}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::canonicalize(dir).unwrap()
+}
@@ -0,0 +1,97 @@
+//! Lightweight script detection used to spot suspicious mixed-script input.
+//!
+//! This is not a full implementation of the Unicode `Script` property. It only
+//! distinguishes the scripts that commonly appear in slugs, which is enough to
+//! catch homograph tricks like a Cyrillic `а` hidden inside a Latin word.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Script {
+    Latin,
+    Greek,
+    Cyrillic,
+    Armenian,
+    Georgian,
+    Hebrew,
+    Arabic,
+    Devanagari,
+    Thai,
+    /// Han, Kana, and Hangul are grouped together, since Japanese and Korean
+    /// text legitimately mixes them within a single word.
+    Cjk,
+}
+
+/// Returns the script of a letter, or `None` for digits, marks, punctuation,
+/// and letters from scripts this module doesn't track.
+fn script_of(c: char) -> Option<Script> {
+    if !c.is_alphabetic() {
+        return None;
+    }
+
+    let script = match c as u32 {
+        0x0041..=0x024F | 0x1E00..=0x1EFF | 0x2C60..=0x2C7F | 0xA720..=0xA7FF | 0xAB30..=0xAB6F => {
+            Script::Latin
+        }
+        0xFF21..=0xFF3A | 0xFF41..=0xFF5A => Script::Latin,
+        0x0370..=0x03FF | 0x1F00..=0x1FFF => Script::Greek,
+        0x0400..=0x052F | 0x1C80..=0x1C8F | 0x2DE0..=0x2DFF | 0xA640..=0xA69F => Script::Cyrillic,
+        0x0530..=0x058F => Script::Armenian,
+        0x10A0..=0x10FF | 0x2D00..=0x2D2F => Script::Georgian,
+        0x0590..=0x05FF => Script::Hebrew,
+        0x0600..=0x06FF | 0x0750..=0x077F | 0x08A0..=0x08FF | 0xFB50..=0xFDFF | 0xFE70..=0xFEFF => {
+            Script::Arabic
+        }
+        0x0900..=0x097F => Script::Devanagari,
+        0x0E00..=0x0E7F => Script::Thai,
+        0x1100..=0x11FF
+        | 0x3040..=0x30FF
+        | 0x3130..=0x318F
+        | 0x3400..=0x4DBF
+        | 0x4E00..=0x9FFF
+        | 0xAC00..=0xD7AF
+        | 0xF900..=0xFAFF
+        | 0xFF66..=0xFF9F
+        | 0x20000..=0x2FFFF => Script::Cjk,
+        _ => return None,
+    };
+
+    Some(script)
+}
+
+/// Returns the first word of `text` that contains letters from more than one script.
+///
+/// Words are runs of alphanumeric characters and combining marks.
+pub(crate) fn find_mixed_script_word(text: &str) -> Option<&str> {
+    let is_word_char = |c: char| c.is_alphanumeric() || ('\u{0300}'..='\u{036F}').contains(&c);
+
+    text.split(|c: char| !is_word_char(c)).find(|word| {
+        let mut scripts = word.chars().filter_map(script_of);
+        match scripts.next() {
+            Some(first) => scripts.any(|s| s != first),
+            None => false,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_script_words() {
+        assert_eq!(find_mixed_script_word("Hello World"), None);
+        assert_eq!(find_mixed_script_word("Привет мир"), None);
+        assert_eq!(find_mixed_script_word("東京タワー"), None);
+        assert_eq!(find_mixed_script_word("Café 2024"), None);
+    }
+
+    #[test]
+    fn test_mixed_script_word() {
+        // The `а` in "pаypal" is CYRILLIC SMALL LETTER A.
+        assert_eq!(find_mixed_script_word("login to pаypal"), Some("pаypal"));
+    }
+
+    #[test]
+    fn test_scripts_in_separate_words() {
+        assert_eq!(find_mixed_script_word("Rust и Python"), None);
+    }
+}
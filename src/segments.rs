@@ -0,0 +1,113 @@
+//! Lazily splitting text into the words of its slug.
+
+use std::borrow::Cow;
+
+use crate::builder::WORD_BREAK;
+use crate::{SlugBuilder, Slugifier, symbols};
+
+impl Slugifier {
+    /// Lazily yields the words of the slug of `text`, without joining them.
+    ///
+    /// Words are produced one whitespace-separated piece of the input at a
+    /// time, and are borrowed from `text` when they need no changes. Terms
+    /// like replacements, dates, and digit groups can span whitespace, so with
+    /// any of them the input is split at once. Stop words are skipped, but
+    /// truncation and presets apply to the whole slug and are ignored here.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// let slugifier = Slugifier::new();
+    /// let words: Vec<_> = slugifier.slug_segments("Hello, wide World!").collect();
+    /// assert_eq!(words, ["hello", "wide", "world"]);
+    /// assert_eq!(slugifier.slug_segments("C'est la vie").count(), 4);
+    /// ```
+    pub fn slug_segments<'a>(&'a self, text: &'a str) -> impl Iterator<Item = Cow<'a, str>> + 'a {
+        let has_terms = self.has_terms();
+        text.split(move |c: char| !has_terms && c.is_whitespace())
+            .filter(|piece| !piece.is_empty())
+            .flat_map(move |piece| self.piece_segments(piece))
+            .filter(move |word| !self.stop_words.iter().any(|s| s.eq_ignore_ascii_case(word)))
+    }
+
+    /// Returns the words of the slug of one whitespace-separated piece.
+    fn piece_segments<'a>(&self, piece: &'a str) -> Vec<Cow<'a, str>> {
+        let is_word = !self.has_terms()
+            && !self.converts_words()
+            && piece.bytes().all(|c| {
+                c.is_ascii_alphanumeric()
+                    && !(self.to_lowercase && c.is_ascii_uppercase())
+                    && !self.remove_chars.contains(c as char)
+            });
+        if is_word {
+            return vec![Cow::Borrowed(piece)];
+        }
+
+        let piece = symbols::apply(piece, self);
+        let mut builder = SlugBuilder::new(self).with_separator(WORD_BREAK);
+        builder.push_str(&piece);
+        let Ok(words) = builder.into_sink() else {
+            return Vec::new();
+        };
+
+        words
+            .split(WORD_BREAK)
+            .filter(|word| !word.is_empty())
+            .map(|word| Cow::Owned(word.to_string()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{NumberMode, OrdinalMode, RomanNumerals};
+
+    #[test]
+    fn test_segments_join_to_slug() {
+        let slugifier = Slugifier::new().allowed_chars(".");
+        let text = "  Ünïcode v1.2 -- release   notes & more ";
+        let words: Vec<_> = slugifier.slug_segments(text).collect();
+        assert_eq!(words.join("-"), slugifier.slugify(text));
+    }
+
+    #[test]
+    fn test_segments_with_conversions_join_to_slug() {
+        let slugifiers = [
+            Slugifier::new().numbers(NumberMode::Words),
+            Slugifier::new().numbers(NumberMode::Remove),
+            Slugifier::new().ordinals(OrdinalMode::Digits),
+            Slugifier::new().roman_numerals(RomanNumerals::Arabic),
+            Slugifier::new().join_digit_groups(true),
+            Slugifier::new().dates(Some("%Y-%m-%d")),
+            Slugifier::new().replace("new york", "nyc"),
+        ];
+        for slugifier in &slugifiers {
+            for text in [
+                "Top 10 tips",
+                "x 1st IV",
+                "2 000 km",
+                "New York, 1 May 2024",
+            ] {
+                let words: Vec<_> = slugifier.slug_segments(text).collect();
+                assert_eq!(words.join("-"), slugifier.slugify(text), "{text}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_unchanged_words_are_borrowed() {
+        let slugifier = Slugifier::new();
+        let words: Vec<_> = slugifier.slug_segments("plain Words").collect();
+        assert!(matches!(words[0], Cow::Borrowed("plain")));
+        assert!(matches!(words[1], Cow::Owned(_)));
+    }
+
+    #[test]
+    fn test_segments_skip_stop_words() {
+        let slugifier = Slugifier::new().stop_words(&["the", "of"]);
+        let words: Vec<_> = slugifier.slug_segments("The Art of War").collect();
+        assert_eq!(words, ["art", "war"]);
+    }
+}
@@ -0,0 +1,113 @@
+//! Writing slugs directly into `fmt::Write` and `io::Write` sinks.
+
+use std::{fmt, io};
+
+use crate::{SlugBuilder, Slugifier, symbols};
+
+impl Slugifier {
+    /// Generates a slug like `slugify`, appending it to `out`.
+    ///
+    /// Unless the slug has to be complete before it can be written, because
+    /// stop words, truncation, or a preset are configured, it is streamed into
+    /// `out` as it is built, without allocating a string for it.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from writing to `out`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// let mut url = String::from("https://example.com/posts/");
+    /// Slugifier::new().slugify_to("Hello, World!", &mut url).unwrap();
+    /// assert_eq!(url, "https://example.com/posts/hello-world");
+    /// ```
+    pub fn slugify_to<W: fmt::Write>(&self, text: &str, out: &mut W) -> fmt::Result {
+        if self.preset.is_some()
+            || self.truncate.is_some()
+            || !self.stop_words.is_empty()
+            || self.max_segments.is_some()
+            || self.on_loss.is_some()
+        {
+            return out.write_str(&self.slugify(text));
+        }
+
+        let text = symbols::apply(text, self);
+        let mut builder = SlugBuilder::with_sink(self, out);
+        builder.push_str(&text);
+        builder.into_sink().map(|_| ())
+    }
+
+    /// Generates a slug like `slugify`, writing it to `out`.
+    ///
+    /// This is `slugify_to` for byte sinks like files, sockets, and response
+    /// bodies.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from writing to `out`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// let mut body = Vec::new();
+    /// Slugifier::new().slugify_write("Hello, World!", &mut body).unwrap();
+    /// assert_eq!(body, b"hello-world");
+    /// ```
+    pub fn slugify_write<W: io::Write>(&self, text: &str, out: &mut W) -> io::Result<()> {
+        let mut adapter = IoAdapter { out, error: None };
+        match self.slugify_to(text, &mut adapter) {
+            Ok(()) => Ok(()),
+            Err(fmt::Error) => Err(adapter
+                .error
+                .unwrap_or_else(|| io::Error::other("formatter error"))),
+        }
+    }
+}
+
+/// Forwards `fmt::Write` to an `io::Write`, keeping the error `fmt::Error` can't carry.
+struct IoAdapter<'a, W> {
+    out: &'a mut W,
+    error: Option<io::Error>,
+}
+
+impl<W: io::Write> fmt::Write for IoAdapter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.out.write_all(s.as_bytes()).map_err(|err| {
+            self.error = Some(err);
+            fmt::Error
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slugify_to_matches_slugify() {
+        let slugifiers = [
+            Slugifier::new(),
+            Slugifier::new().separator("_").allowed_chars("."),
+            Slugifier::new().truncate(8),
+            Slugifier::new().stop_words(&["the"]),
+        ];
+        for slugifier in &slugifiers {
+            let text = "The Quick brown fox v1.2 — Ünïcödé";
+            let mut out = String::from("prefix/");
+            slugifier.slugify_to(text, &mut out).unwrap();
+            assert_eq!(out, format!("prefix/{}", slugifier.slugify(text)));
+        }
+    }
+
+    #[test]
+    fn test_slugify_write_reports_io_errors() {
+        let mut full = [0u8; 4];
+        let err = Slugifier::new()
+            .slugify_write("Hello World", &mut full.as_mut_slice())
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+    }
+}
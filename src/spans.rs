@@ -0,0 +1,209 @@
+//! Mapping the words of a slug back to the text they came from.
+
+use std::ops::Range;
+
+use crate::builder::WORD_BREAK;
+use crate::symbols::Terms;
+use crate::thai;
+use crate::{NumberMode, OrdinalMode, RomanNumerals, SlugBuilder, Slugifier};
+
+/// A word of a slug, and the part of the input it came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlugSpan {
+    /// The byte range of the word in the slug.
+    pub output: Range<usize>,
+    /// The byte range of the input the word came from, from its first
+    /// character to its last. Characters dropped inside a word, like the
+    /// apostrophe of "don't", fall inside this range.
+    pub input: Range<usize>,
+}
+
+impl Slugifier {
+    /// Generates a slug like `slugify`, along with the part of `text` each
+    /// word of the slug came from, so editors can highlight what produced
+    /// which part of a slug, and what was lost.
+    ///
+    /// A word made by a replacement maps to the term it replaced. Words cut
+    /// short by truncation keep their whole input range. Presets that
+    /// replace the slug algorithm, like the heading anchor and compatibility
+    /// presets, and the `roman_numerals` modes return no spans.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::{SlugSpan, Slugifier};
+    ///
+    /// let (slug, spans) = Slugifier::new().slugify_spans("Hello, Wörld!");
+    /// assert_eq!(slug, "hello-world");
+    /// assert_eq!(
+    ///     spans[1],
+    ///     SlugSpan {
+    ///         output: 6..11,
+    ///         input: 7..13,
+    ///     }
+    /// );
+    /// ```
+    pub fn slugify_spans(&self, text: &str) -> (String, Vec<SlugSpan>) {
+        if self.preset.is_some()
+            || self.roman_numerals != RomanNumerals::Ignore
+            || self.numbers != NumberMode::Keep
+            || self.ordinals != OrdinalMode::Keep
+        {
+            return (self.slugify(text), Vec::new());
+        }
+
+        let (replaced, sources) = Terms::new(self).apply_mapped(text);
+
+        // Words are built with a stand-in separator, so their bytes can be
+        // told apart from the separator's.
+        let mut builder = SlugBuilder::new(self).with_separator(WORD_BREAK);
+        let mut owners: Vec<Range<usize>> = Vec::with_capacity(replaced.len());
+        let mut chars = replaced.char_indices().zip(&sources).peekable();
+        while let Some(((i, c), source)) = chars.next() {
+            // Thai and Lao are romanized a run at a time, as their letters
+            // don't map to the romanization one by one.
+            let run = thai::run_len(&replaced[i..]).max(c.len_utf8());
+            let mut source = source.clone();
+            while let Some(&((j, _), last)) = chars.peek()
+                && j < i + run
+            {
+                source.end = last.end;
+                chars.next();
+            }
+            builder.push_str(&replaced[i..i + run]);
+            owners.resize(builder.written_len(), source);
+        }
+        // Writing to a `String` never fails.
+        let built = builder.into_sink().unwrap_or_default();
+        owners.resize(built.len(), text.len()..text.len());
+
+        let is_stop_word = |word: &str| {
+            !self.separator.is_empty()
+                && self.stop_words.iter().any(|s| s.eq_ignore_ascii_case(word))
+        };
+        let mut slug = String::with_capacity(built.len());
+        let mut spans = Vec::new();
+        // Separators that aren't collapsed or trimmed leave empty segments,
+        // which count toward `max_segments` as they do in `slugify`.
+        let mut segments = 0;
+        let mut start = 0;
+        for word in built.split(WORD_BREAK) {
+            let end = start + word.len();
+            let is_over_limit =
+                !self.separator.is_empty() && self.max_segments.is_some_and(|max| segments >= max);
+            if !is_stop_word(word) && !is_over_limit {
+                if segments > 0 {
+                    slug.push_str(&self.separator);
+                }
+                segments += 1;
+                if !word.is_empty() {
+                    spans.push(SlugSpan {
+                        output: slug.len()..slug.len() + word.len(),
+                        input: owners[start].start..owners[end - 1].end,
+                    });
+                }
+                slug.push_str(word);
+            }
+            start = end + WORD_BREAK.len();
+        }
+
+        self.apply_truncation(&mut slug);
+        spans.retain_mut(|span| {
+            span.output.end = span.output.end.min(slug.len());
+            span.output.start < slug.len()
+        });
+
+        (slug, spans)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spans_match_slugify() {
+        let slugifiers = [
+            Slugifier::new(),
+            Slugifier::new().separator("_").allowed_chars("."),
+            Slugifier::new().stop_words(&["the", "of"]).truncate(12),
+            Slugifier::new().max_segments(2),
+            Slugifier::new().truncate(10),
+            Slugifier::new().unicode_words(true),
+            Slugifier::new().separator("").lang(Some("en")),
+            Slugifier::new().collapse_separators(false),
+            Slugifier::new().trim_separators(false),
+            Slugifier::new()
+                .collapse_separators(false)
+                .trim_separators(false)
+                .stop_words(&["of"])
+                .max_segments(4),
+        ];
+        let inputs = [
+            "The Lord of the Rings",
+            "Tom & Jerry v1.2",
+            "Don’t l'art",
+            "Ünïcode 🎉!",
+            "already_a-slug",
+            "--a--b--",
+            "ภาษาไทย ok",
+            "ສະບາຍດີ, ไทย!",
+            " Tom  of Jerry! ",
+            "",
+        ];
+        for slugifier in &slugifiers {
+            for text in inputs {
+                let (slug, spans) = slugifier.slugify_spans(text);
+                assert_eq!(slug, slugifier.slugify(text), "{text:?}");
+                for span in &spans {
+                    assert!(span.output.end <= slug.len());
+                    assert!(text.is_char_boundary(span.input.start));
+                    assert!(text.is_char_boundary(span.input.end));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_spans_point_at_words() {
+        let text = "The Quick  Brown Fox";
+        let (slug, spans) = Slugifier::new().stop_words(&["the"]).slugify_spans(text);
+        assert_eq!(slug, "quick-brown-fox");
+        let words: Vec<(&str, &str)> = spans
+            .iter()
+            .map(|span| (&slug[span.output.clone()], &text[span.input.clone()]))
+            .collect();
+        assert_eq!(
+            words,
+            [("quick", "Quick"), ("brown", "Brown"), ("fox", "Fox")]
+        );
+    }
+
+    #[test]
+    fn test_spans_of_thai() {
+        let text = "ภาษาไทย ok";
+        let (slug, spans) = Slugifier::new().slugify_spans(text);
+        assert_eq!(slug, "phasathai-ok");
+        assert_eq!(spans.len(), 2);
+        assert_eq!(&text[spans[0].input.clone()], "ภาษาไทย");
+
+        let (slug, _) = Slugifier::new()
+            .max_segments(1)
+            .slugify_spans("กรุงเทพมหานคร ok");
+        assert_eq!(slug, "krungthepmahanakhon");
+    }
+
+    #[test]
+    fn test_spans_of_replacements_and_truncation() {
+        let text = "Rock & Roll";
+        let (slug, spans) = Slugifier::new()
+            .lang(Some("en"))
+            .truncate(7)
+            .word_boundary(false)
+            .slugify_spans(text);
+        assert_eq!(slug, "rock-an");
+        assert_eq!(spans.len(), 2);
+        assert_eq!(spans[1].output, 5..7);
+        assert_eq!(&text[spans[1].input.clone()], "&");
+    }
+}
@@ -0,0 +1,140 @@
+//! Suffixes for pages, languages, and variants of a slug, like the "page-2"
+//! of "rust-tips-page-2".
+
+use crate::Slugifier;
+
+/// A suffix appended to a slug with [`Slugifier::with_suffix`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Suffix<'a> {
+    /// A page number, appended as "page" and the number, like "page-2".
+    Page(usize),
+    /// A language code, like "en" or "pt-BR".
+    Lang(&'a str),
+    /// A variant tag, like "amp" or "print".
+    Variant(&'a str),
+}
+
+impl Slugifier {
+    /// Appends `suffix` to `slug`, joined by the separator.
+    ///
+    /// If `truncate` is set, the slug and suffix together fit in it: the slug
+    /// is truncated like `truncate` says to make room, and the suffix is never
+    /// cut. The language code and variant tag are slugified with the
+    /// separator and case of this slugifier, and nothing is appended if they
+    /// are empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `slug` - The slug to append to.
+    /// * `suffix` - The suffix to append.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::{Slugifier, Suffix};
+    ///
+    /// let slugifier = Slugifier::new().truncate(24);
+    /// let slug = slugifier.slugify("Ten Tips for Writing Rust");
+    /// assert_eq!(slug, "ten-tips-for-writing");
+    /// assert_eq!(slugifier.with_suffix(&slug, Suffix::Page(2)), "ten-tips-for-page-2");
+    /// assert_eq!(slugifier.with_suffix(&slug, Suffix::Lang("pt_BR")), "ten-tips-for-pt-br");
+    /// ```
+    pub fn with_suffix(&self, slug: &str, suffix: Suffix<'_>) -> String {
+        let suffix = match suffix {
+            Suffix::Page(page) => format!("page{}{page}", self.separator),
+            Suffix::Lang(tag) | Suffix::Variant(tag) => Slugifier::new()
+                .separator(&self.separator)
+                .to_lowercase(self.to_lowercase)
+                .slugify(tag),
+        };
+        if suffix.is_empty() {
+            return slug.to_string();
+        }
+
+        let mut base = slug.to_string();
+        if let Some(max) = self.truncate {
+            let budget = max.saturating_sub(self.separator.len() + suffix.len());
+            self.truncate_to(&mut base, budget);
+        }
+        if base.is_empty() {
+            return suffix;
+        }
+        base.push_str(&self.separator);
+        base.push_str(&suffix);
+        base
+    }
+}
+
+/// Fills the counter into a suffix template, replacing `{n}`, or appending it
+/// if the template has no `{n}`, so every counter gives a different suffix.
+pub(crate) fn fill_counter(template: &str, n: &str) -> String {
+    if template.contains("{n}") {
+        template.replace("{n}", n)
+    } else {
+        format!("{template}{n}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suffixes() {
+        let slugifier = Slugifier::new();
+        assert_eq!(
+            slugifier.with_suffix("rust-tips", Suffix::Page(3)),
+            "rust-tips-page-3"
+        );
+        assert_eq!(
+            slugifier.with_suffix("rust-tips", Suffix::Variant("AMP")),
+            "rust-tips-amp"
+        );
+        assert_eq!(
+            slugifier.with_suffix("rust-tips", Suffix::Lang("!")),
+            "rust-tips"
+        );
+        assert_eq!(slugifier.with_suffix("", Suffix::Lang("de")), "de");
+
+        let slugifier = Slugifier::new().separator("_").to_lowercase(false);
+        assert_eq!(
+            slugifier.with_suffix("Rust", Suffix::Lang("en-US")),
+            "Rust_en_US"
+        );
+    }
+
+    #[test]
+    fn test_suffix_fits_truncation() {
+        let slugifier = Slugifier::new().truncate(12);
+        assert_eq!(
+            slugifier.with_suffix("rust-tips-and-tricks", Suffix::Page(10)),
+            "rust-page-10"
+        );
+        assert_eq!(
+            slugifier.with_suffix("rust", Suffix::Page(10)),
+            "rust-page-10"
+        );
+        assert_eq!(
+            slugifier.with_suffix("rust-tips", Suffix::Variant("printable")),
+            "ru-printable"
+        );
+
+        let slugifier = Slugifier::new().truncate(12).word_boundary(false);
+        assert_eq!(
+            slugifier.with_suffix("rustacean", Suffix::Lang("en")),
+            "rustacean-en"
+        );
+        assert_eq!(
+            slugifier.with_suffix("rustaceans", Suffix::Lang("en")),
+            "rustacean-en"
+        );
+    }
+
+    #[test]
+    fn test_fill_counter() {
+        assert_eq!(fill_counter("-{n}", "2"), "-2");
+        assert_eq!(fill_counter("({n}/{n})", "2"), "(2/2)");
+        assert_eq!(fill_counter("-dup", "2"), "-dup2");
+    }
+}
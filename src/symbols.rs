@@ -0,0 +1,913 @@
+//! Per-language symbol dictionaries and custom replacements applied before slugification.
+
+use std::borrow::Cow;
+use std::ops::Range;
+
+use unicode_normalization::UnicodeNormalization;
+
+#[cfg(feature = "chinese-conversion")]
+use crate::chinese;
+use crate::{Slugifier, chars, dates, numbers};
+
+/// The words a language uses for symbols, and its letters that are
+/// transliterated differently from the language-neutral default.
+#[derive(Debug)]
+pub(crate) struct Language {
+    pub(crate) code: &'static str,
+    symbols: &'static [(&'static str, &'static str)],
+    /// The words for currency symbols, which fall back to English.
+    currencies: &'static [(&'static str, &'static str)],
+    /// The words for units and math symbols, which fall back to English.
+    operators: &'static [(&'static str, &'static str)],
+    letters: &'static [(char, &'static str)],
+    /// Punctuation that is part of words, like the middle dot of Catalan
+    /// "col·lecció", dropped before a letter instead of separating words.
+    in_word: &'static [char],
+}
+
+const LANGUAGES: &[Language] = &[
+    Language {
+        code: "en",
+        symbols: &[
+            ("&", "and"),
+            ("|", "or"),
+            ("<", "less than"),
+            (">", "greater than"),
+            ("∆", "delta"),
+            ("∞", "infinity"),
+            ("♥", "love"),
+            ("∑", "sum"),
+            ("¤", "currency"),
+        ],
+        currencies: &[
+            ("$", "dollar"),
+            ("€", "euro"),
+            ("£", "pound"),
+            ("¥", "yen"),
+            ("₹", "rupee"),
+            ("₩", "won"),
+            ("₽", "ruble"),
+            ("₺", "lira"),
+            ("₿", "bitcoin"),
+            ("¢", "cent"),
+            ("₴", "hryvnia"),
+            ("₪", "shekel"),
+            ("₫", "dong"),
+            ("฿", "baht"),
+            ("₱", "peso"),
+        ],
+        operators: &[
+            ("°", "degrees"),
+            ("%", "percent"),
+            ("‰", "per mille"),
+            ("+", "plus"),
+            ("=", "equals"),
+            ("±", "plus minus"),
+            ("×", "times"),
+            ("÷", "divided by"),
+            ("−", "minus"),
+            ("≠", "not equal"),
+            ("≈", "approximately"),
+            ("≤", "less or equal"),
+            ("≥", "greater or equal"),
+            ("√", "square root"),
+            ("π", "pi"),
+        ],
+        letters: &[],
+        in_word: &[],
+    },
+    Language {
+        code: "de",
+        symbols: &[
+            ("&", "und"),
+            ("|", "oder"),
+            ("<", "kleiner als"),
+            (">", "groesser als"),
+            ("∆", "delta"),
+            ("∞", "unendlich"),
+            ("♥", "Liebe"),
+            ("∑", "Summe"),
+            ("¤", "Waehrung"),
+        ],
+        currencies: &[
+            ("$", "Dollar"),
+            ("€", "Euro"),
+            ("£", "Pfund"),
+            ("¥", "Yen"),
+            ("₹", "Rupie"),
+            ("₽", "Rubel"),
+            ("₺", "Lira"),
+            ("¢", "Cent"),
+        ],
+        operators: &[
+            ("°", "Grad"),
+            ("%", "Prozent"),
+            ("‰", "Promille"),
+            ("+", "plus"),
+            ("=", "gleich"),
+            ("×", "mal"),
+            ("÷", "geteilt durch"),
+            ("−", "minus"),
+            ("≈", "ungefaehr"),
+        ],
+        letters: &[
+            ('Ä', "AE"),
+            ('ä', "ae"),
+            ('Ö', "OE"),
+            ('ö', "oe"),
+            ('Ü', "UE"),
+            ('ü', "ue"),
+        ],
+        in_word: &[],
+    },
+    Language {
+        code: "fr",
+        symbols: &[
+            ("&", "et"),
+            ("|", "ou"),
+            ("<", "moins que"),
+            (">", "superieure a"),
+            ("∆", "delta"),
+            ("∞", "infiniment"),
+            ("♥", "Amour"),
+            ("∑", "somme des"),
+            ("¤", "monnaie"),
+        ],
+        currencies: &[
+            ("$", "dollar"),
+            ("€", "euro"),
+            ("£", "livre"),
+            ("¥", "yen"),
+            ("₹", "roupie"),
+            ("₽", "rouble"),
+            ("¢", "centime"),
+        ],
+        operators: &[
+            ("°", "degres"),
+            ("%", "pour cent"),
+            ("‰", "pour mille"),
+            ("+", "plus"),
+            ("=", "egal"),
+            ("×", "fois"),
+            ("÷", "divise par"),
+            ("−", "moins"),
+            ("≈", "environ"),
+        ],
+        letters: &[],
+        in_word: &[],
+    },
+    Language {
+        code: "es",
+        symbols: &[
+            ("&", "y"),
+            ("|", "u"),
+            ("<", "menos que"),
+            (">", "mas que"),
+            ("∆", "delta"),
+            ("∞", "infinito"),
+            ("♥", "amor"),
+            ("∑", "suma de los"),
+            ("¤", "moneda"),
+        ],
+        currencies: &[
+            ("$", "dolar"),
+            ("€", "euro"),
+            ("£", "libra"),
+            ("¥", "yen"),
+            ("₹", "rupia"),
+            ("₽", "rublo"),
+            ("₺", "lira"),
+            ("¢", "centavo"),
+        ],
+        operators: &[
+            ("°", "grados"),
+            ("%", "por ciento"),
+            ("‰", "por mil"),
+            ("+", "mas"),
+            ("=", "igual"),
+            ("×", "por"),
+            ("÷", "dividido por"),
+            ("−", "menos"),
+            ("≈", "aproximadamente"),
+        ],
+        letters: &[],
+        in_word: &[],
+    },
+    Language {
+        code: "it",
+        symbols: &[
+            ("&", "e"),
+            ("|", "o"),
+            ("<", "minore di"),
+            (">", "maggiore di"),
+            ("∆", "delta"),
+            ("∞", "infinito"),
+            ("♥", "amore"),
+            ("∑", "somma"),
+            ("¤", "moneta"),
+        ],
+        currencies: &[
+            ("$", "dollaro"),
+            ("€", "euro"),
+            ("£", "sterlina"),
+            ("¥", "yen"),
+            ("₹", "rupia"),
+            ("₽", "rublo"),
+            ("₺", "lira"),
+            ("¢", "centesimo"),
+        ],
+        operators: &[
+            ("°", "gradi"),
+            ("%", "per cento"),
+            ("‰", "per mille"),
+            ("+", "piu"),
+            ("=", "uguale"),
+            ("×", "per"),
+            ("÷", "diviso"),
+            ("−", "meno"),
+            ("≈", "circa"),
+        ],
+        letters: &[],
+        in_word: &[],
+    },
+    Language {
+        code: "pt",
+        symbols: &[
+            ("&", "e"),
+            ("|", "ou"),
+            ("<", "menor que"),
+            (">", "maior que"),
+            ("∆", "delta"),
+            ("∞", "infinito"),
+            ("♥", "amor"),
+            ("∑", "soma"),
+            ("¤", "moeda"),
+        ],
+        currencies: &[
+            ("$", "dolar"),
+            ("€", "euro"),
+            ("£", "libra"),
+            ("¥", "iene"),
+            ("₹", "rupia"),
+            ("₽", "rublo"),
+            ("₺", "lira"),
+            ("¢", "centavo"),
+        ],
+        operators: &[
+            ("°", "graus"),
+            ("%", "por cento"),
+            ("‰", "por mil"),
+            ("+", "mais"),
+            ("=", "igual"),
+            ("×", "vezes"),
+            ("÷", "dividido por"),
+            ("−", "menos"),
+            ("≈", "aproximadamente"),
+        ],
+        letters: &[],
+        in_word: &[],
+    },
+    Language {
+        code: "nl",
+        symbols: &[
+            ("&", "en"),
+            ("|", "of"),
+            ("<", "kleiner dan"),
+            (">", "groter dan"),
+            ("∆", "delta"),
+            ("∞", "oneindig"),
+            ("♥", "liefde"),
+            ("∑", "som"),
+            ("¤", "valuta"),
+        ],
+        currencies: &[
+            ("$", "dollar"),
+            ("€", "euro"),
+            ("£", "pond"),
+            ("¥", "yen"),
+            ("₹", "roepie"),
+            ("₽", "roebel"),
+            ("₺", "lira"),
+            ("¢", "cent"),
+        ],
+        operators: &[
+            ("°", "graden"),
+            ("%", "procent"),
+            ("‰", "promille"),
+            ("+", "plus"),
+            ("=", "is"),
+            ("×", "keer"),
+            ("÷", "gedeeld door"),
+            ("−", "min"),
+            ("≈", "ongeveer"),
+        ],
+        letters: &[],
+        in_word: &[],
+    },
+    Language {
+        code: "ca",
+        symbols: &[
+            ("&", "i"),
+            ("|", "o"),
+            ("<", "menor que"),
+            (">", "major que"),
+            ("∆", "delta"),
+            ("∞", "infinit"),
+            ("♥", "amor"),
+            ("∑", "suma"),
+            ("¤", "moneda"),
+        ],
+        currencies: &[("$", "dòlar"), ("€", "euro"), ("£", "lliura")],
+        operators: &[
+            ("°", "graus"),
+            ("%", "per cent"),
+            ("+", "més"),
+            ("=", "igual"),
+            ("−", "menys"),
+        ],
+        letters: &[],
+        // The punt volat of "l·l", and the hyphenation point typed for it.
+        in_word: &['·', '\u{2027}'],
+    },
+    Language {
+        code: "haw",
+        symbols: &[("&", "a me")],
+        currencies: &[("$", "kālā")],
+        operators: &[],
+        letters: &[],
+        // The ʻokina is a consonant, and is often typed as an apostrophe.
+        in_word: &['ʻ', '‘', '’', '\''],
+    },
+];
+
+/// Names of programming languages and frameworks that would otherwise lose
+/// the symbols that tell them apart, like "C", "C++", and "C#".
+const PROGRAMMING_TERMS: &[(&str, &str)] = &[
+    ("C++", "cpp"),
+    ("C#", "c sharp"),
+    ("F#", "f sharp"),
+    ("Q#", "q sharp"),
+    ("J#", "j sharp"),
+    ("G++", "gpp"),
+    (".NET", "dotnet"),
+    ("ASP.NET", "asp dotnet"),
+    ("VB.NET", "vb dotnet"),
+    ("Node.js", "nodejs"),
+    ("Vue.js", "vuejs"),
+    ("Next.js", "nextjs"),
+    ("Nuxt.js", "nuxtjs"),
+    ("Three.js", "threejs"),
+];
+
+/// The ISO 4217 codes of currency symbols.
+const CURRENCY_CODES: &[(&str, &str)] = &[
+    ("$", "USD"),
+    ("€", "EUR"),
+    ("£", "GBP"),
+    ("¥", "JPY"),
+    ("₹", "INR"),
+    ("₩", "KRW"),
+    ("₽", "RUB"),
+    ("₺", "TRY"),
+    ("₿", "BTC"),
+    ("₴", "UAH"),
+    ("₪", "ILS"),
+    ("₫", "VND"),
+    ("฿", "THB"),
+    ("₱", "PHP"),
+];
+
+/// How currency symbols are spelled out, set with
+/// [`Slugifier::currency_symbols`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CurrencyStyle {
+    /// The ISO 4217 code, like `usd` for `$`.
+    Code,
+    /// The name of the currency in the language set with `lang`, or in
+    /// English, like `dollar` for `$`.
+    Word,
+}
+
+/// Returns the language with the given code, falling back to English for
+/// codes without a dictionary.
+pub(crate) fn language(code: &str) -> &'static Language {
+    LANGUAGES
+        .iter()
+        .find(|language| language.code.eq_ignore_ascii_case(code))
+        .unwrap_or(&LANGUAGES[0])
+}
+
+/// Replaces the custom terms of `slugifier`, and the terms of its
+/// dictionaries and the letters of its language, in `text`.
+///
+/// Replaced terms and symbols become separate words. Matching ignores ASCII
+/// case, and a term starting or ending with an alphanumeric character only
+/// matches at a word boundary on that side, so "and" doesn't match in "band".
+/// Custom replacements take precedence over the dictionaries, and longer
+/// terms over shorter ones.
+pub(crate) fn apply<'a>(text: &'a str, slugifier: &Slugifier) -> Cow<'a, str> {
+    if !slugifier.has_terms() {
+        return Cow::Borrowed(text);
+    }
+
+    Terms::new(slugifier).apply(text)
+}
+
+impl Slugifier {
+    /// Returns `true` if the configuration has any terms or letters to
+    /// replace before slugification.
+    pub(crate) fn has_terms(&self) -> bool {
+        !self.replacements.is_empty()
+            || self.language.is_some()
+            || self.programming_terms
+            || self.verbalize_symbols
+            || self.compatibility_folding
+            || self.converts_chinese()
+            || self.date_format.is_some()
+            || self.join_digit_groups
+            || self.currency_style.is_some()
+    }
+}
+
+impl Slugifier {
+    /// Returns `true` if Chinese characters are converted to another script.
+    #[cfg(feature = "chinese-conversion")]
+    fn converts_chinese(&self) -> bool {
+        self.chinese_script.is_some()
+    }
+
+    /// Returns `true` if Chinese characters are converted to another script.
+    #[cfg(not(feature = "chinese-conversion"))]
+    fn converts_chinese(&self) -> bool {
+        false
+    }
+}
+
+/// The terms to replace, sorted by precedence, with a table of the bytes they
+/// can start with so most positions are skipped without comparing any term.
+#[derive(Debug, Clone)]
+pub(crate) struct Terms<'a> {
+    terms: Vec<Term<'a>>,
+    letters: &'static [(char, &'static str)],
+    in_word: &'static [char],
+    first_bytes: [bool; 256],
+    /// Whether compatibility characters are folded before terms are matched.
+    fold: bool,
+    /// The script Chinese characters are converted to before terms are
+    /// matched, if they are.
+    #[cfg(feature = "chinese-conversion")]
+    chinese_script: Option<crate::ChineseScript>,
+    /// The format dates are rewritten in, if they are.
+    date_format: Option<Cow<'a, str>>,
+    /// Whether the thousands separators of numbers are dropped.
+    join_digit_groups: bool,
+}
+
+/// A term and its replacement.
+#[derive(Debug, Clone)]
+struct Term<'a> {
+    from: Cow<'a, str>,
+    to: Cow<'a, str>,
+    /// Whether the term is a currency symbol, which is moved after the amount
+    /// it precedes, like "$5" to "5 usd".
+    is_currency: bool,
+}
+
+impl<'a> Term<'a> {
+    fn new(from: impl Into<Cow<'a, str>>, to: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            from: from.into(),
+            to: to.into(),
+            is_currency: false,
+        }
+    }
+}
+
+impl<'a> Terms<'a> {
+    pub(crate) fn new(slugifier: &'a Slugifier) -> Self {
+        let language = slugifier.language;
+        let mut terms: Vec<Term> = slugifier
+            .replacements
+            .iter()
+            .filter(|(from, _)| !from.is_empty())
+            .map(|(from, to)| Term::new(from.as_str(), to.as_str()))
+            .collect();
+        let dictionaries = [
+            language.map(|language| language.symbols),
+            slugifier.programming_terms.then_some(PROGRAMMING_TERMS),
+        ];
+        terms.extend(
+            dictionaries
+                .into_iter()
+                .flatten()
+                .flatten()
+                .map(|&(from, to)| Term::new(from, to)),
+        );
+        if slugifier.verbalize_symbols {
+            let words = language.map_or(&[][..], |language| language.operators);
+            terms.extend(
+                LANGUAGES[0]
+                    .operators
+                    .iter()
+                    .map(|&(symbol, english)| Term::new(symbol, localize(words, symbol, english))),
+            );
+        }
+        if let Some(style) = slugifier.currency_style {
+            let words = language.map_or(&[][..], |language| language.currencies);
+            terms.extend(
+                LANGUAGES[0]
+                    .currencies
+                    .iter()
+                    .filter_map(|&(symbol, english)| {
+                        let to = match style {
+                            CurrencyStyle::Code => {
+                                CURRENCY_CODES.iter().find(|(s, _)| *s == symbol)?.1
+                            }
+                            CurrencyStyle::Word => localize(words, symbol, english),
+                        };
+                        Some(Term {
+                            is_currency: true,
+                            ..Term::new(symbol, to)
+                        })
+                    }),
+            );
+        }
+        terms.sort_by_key(|term| std::cmp::Reverse(term.from.len()));
+
+        let mut first_bytes = [false; 256];
+        for term in &terms {
+            let first = term.from.as_bytes()[0];
+            first_bytes[first.to_ascii_lowercase() as usize] = true;
+            first_bytes[first.to_ascii_uppercase() as usize] = true;
+        }
+
+        Self {
+            terms,
+            letters: language.map_or(&[], |language| language.letters),
+            in_word: language.map_or(&[], |language| language.in_word),
+            first_bytes,
+            fold: slugifier.compatibility_folding,
+            #[cfg(feature = "chinese-conversion")]
+            chinese_script: slugifier.chinese_script,
+            date_format: slugifier.date_format.as_deref().map(Cow::Borrowed),
+            join_digit_groups: slugifier.join_digit_groups,
+        }
+    }
+
+    /// Returns terms that own their text, so they can outlive the configuration.
+    pub(crate) fn into_owned(self) -> Terms<'static> {
+        Terms {
+            terms: self
+                .terms
+                .into_iter()
+                .map(|term| Term {
+                    from: Cow::Owned(term.from.into_owned()),
+                    to: Cow::Owned(term.to.into_owned()),
+                    is_currency: term.is_currency,
+                })
+                .collect(),
+            letters: self.letters,
+            in_word: self.in_word,
+            first_bytes: self.first_bytes,
+            fold: self.fold,
+            #[cfg(feature = "chinese-conversion")]
+            chinese_script: self.chinese_script,
+            date_format: self
+                .date_format
+                .map(|format| Cow::Owned(format.into_owned())),
+            join_digit_groups: self.join_digit_groups,
+        }
+    }
+
+    /// Returns `true` if Chinese characters are converted to another script.
+    #[cfg(feature = "chinese-conversion")]
+    fn converts_chinese(&self) -> bool {
+        self.chinese_script.is_some()
+    }
+
+    /// Returns `true` if Chinese characters are converted to another script.
+    #[cfg(not(feature = "chinese-conversion"))]
+    fn converts_chinese(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+            && self.letters.is_empty()
+            && self.in_word.is_empty()
+            && !self.fold
+            && !self.converts_chinese()
+            && self.date_format.is_none()
+            && !self.join_digit_groups
+    }
+
+    /// Replaces the terms and letters in `text`.
+    pub(crate) fn apply<'t>(&self, text: &'t str) -> Cow<'t, str> {
+        self.apply_counting(text).0
+    }
+
+    /// Replaces the terms and letters in `text`, also returning the number of
+    /// terms replaced.
+    pub(crate) fn apply_counting<'t>(&self, text: &'t str) -> (Cow<'t, str>, usize) {
+        if self.is_empty() {
+            return (Cow::Borrowed(text), 0);
+        }
+
+        let folded = self.fold(text, |_| {});
+        let text = folded.as_deref().unwrap_or(text);
+        let mut replaced = String::with_capacity(text.len());
+        let count = self.replace(text, |piece, _| replaced.push_str(piece));
+        (Cow::Owned(replaced), count)
+    }
+
+    /// Replaces the terms and letters in `text`, also returning, for each
+    /// character of the result, the byte range of `text` it came from.
+    pub(crate) fn apply_mapped(&self, text: &str) -> (String, Vec<Range<usize>>) {
+        // The byte range of `text` each byte of the folded text came from.
+        let mut folded_sources = Vec::new();
+        let folded = self.fold(text, |source| folded_sources.push(source));
+
+        let mut replaced = String::with_capacity(text.len());
+        let mut sources = Vec::with_capacity(text.len());
+        self.replace(folded.as_deref().unwrap_or(text), |piece, source| {
+            let source = match folded_sources.get(source.start..source.end) {
+                Some([first, .., last]) => first.start..last.end,
+                Some([only]) => only.clone(),
+                _ => source,
+            };
+            replaced.push_str(piece);
+            sources.extend(piece.chars().map(|_| source.clone()));
+        });
+        (replaced, sources)
+    }
+
+    /// Prepares `text` for matching terms: no-break spaces become spaces and
+    /// invisible characters like soft hyphens are dropped, so that they don't
+    /// keep a term from matching, and compatibility characters, like
+    /// full-width and mathematical letters, are folded into their plain form
+    /// with NFKC a character at a time if configured. Returns `None` if there
+    /// is nothing to change, and otherwise calls `source` with the range of
+    /// `text` each byte of the prepared text came from.
+    fn fold(&self, text: &str, mut source: impl FnMut(Range<usize>)) -> Option<String> {
+        if text.is_ascii() {
+            return None;
+        }
+
+        let mut folded = String::with_capacity(text.len());
+        for (i, c) in text.char_indices() {
+            let start = folded.len();
+            if c.is_ascii() {
+                folded.push(c);
+            } else if chars::is_no_break_space(c) {
+                folded.push(' ');
+            } else if chars::is_invisible(c) {
+                continue;
+            } else if self.fold {
+                #[cfg(feature = "chinese-conversion")]
+                let c = chinese::convert(c, self.chinese_script);
+                folded.extend(std::iter::once(c).nfkc());
+            } else {
+                #[cfg(feature = "chinese-conversion")]
+                let c = chinese::convert(c, self.chinese_script);
+                folded.push(c);
+            }
+            for _ in start..folded.len() {
+                source(i..i + c.len_utf8());
+            }
+        }
+        Some(folded)
+    }
+
+    /// Calls `emit` with each piece of the replaced text and the byte range of
+    /// `text` it replaces, returning the number of terms replaced.
+    fn replace(&self, text: &str, mut emit: impl FnMut(&str, Range<usize>)) -> usize {
+        let is_word_char = |c: Option<char>| c.is_some_and(char::is_alphanumeric);
+        let matches_at = |i: usize, from: &str| {
+            let Some(candidate) = text.get(i..i + from.len()) else {
+                return false;
+            };
+            candidate.eq_ignore_ascii_case(from)
+                && !(is_word_char(from.chars().next())
+                    && is_word_char(text[..i].chars().next_back()))
+                && !(is_word_char(from.chars().next_back())
+                    && is_word_char(text[i + from.len()..].chars().next()))
+        };
+
+        let mut count = 0;
+        let mut i = 0;
+        while let Some(c) = text[i..].chars().next() {
+            if let Some(format) = &self.date_format
+                && c.is_ascii_alphanumeric()
+                && !is_word_char(text[..i].chars().next_back())
+                && let Some((date, len)) = dates::parse(&text[i..])
+            {
+                let source = i..i + len;
+                emit(" ", source.clone());
+                emit(&date.format(format), source.clone());
+                emit(" ", source);
+                i += len;
+                count += 1;
+                continue;
+            }
+
+            if self.join_digit_groups
+                && c.is_ascii_digit()
+                && !is_word_char(text[..i].chars().next_back())
+                && let Some(len) = numbers::digit_groups_len(&text[i..])
+            {
+                for (j, digit) in text[i..i + len].char_indices() {
+                    if digit.is_ascii_digit() {
+                        emit(&text[i + j..i + j + 1], i + j..i + j + 1);
+                    }
+                }
+                i += len;
+                continue;
+            }
+
+            if self.first_bytes[text.as_bytes()[i] as usize]
+                && let Some(term) = self.terms.iter().find(|term| matches_at(i, &term.from))
+            {
+                let source = i..i + term.from.len();
+                i += term.from.len();
+                if term.is_currency
+                    && let Some(amount) = amount_at(text, i)
+                {
+                    emit(" ", source.clone());
+                    emit(&text[amount.clone()], amount.clone());
+                    i = amount.end;
+                }
+                emit(" ", source.clone());
+                emit(&term.to, source.clone());
+                emit(" ", source);
+                count += 1;
+                continue;
+            }
+
+            let source = i..i + c.len_utf8();
+            if self.in_word.contains(&c)
+                && text[source.end..]
+                    .chars()
+                    .next()
+                    .is_some_and(char::is_alphabetic)
+            {
+                i = source.end;
+                continue;
+            }
+            match self.letters.iter().find(|(l, _)| *l == c) {
+                Some((_, to)) => emit(to, source),
+                None => emit(c.encode_utf8(&mut [0; 4]), source),
+            }
+            i += c.len_utf8();
+        }
+
+        count
+    }
+}
+
+/// Returns the word for `symbol` in a language's `words`, or `english`.
+fn localize(
+    words: &'static [(&'static str, &'static str)],
+    symbol: &str,
+    english: &'static str,
+) -> &'static str {
+    words
+        .iter()
+        .find(|(s, _)| *s == symbol)
+        .map_or(english, |&(_, word)| word)
+}
+
+/// Returns the range of the amount at `i` in `text`, like "5" or "1,299.99",
+/// after optional spaces.
+fn amount_at(text: &str, i: usize) -> Option<Range<usize>> {
+    let bytes = text.as_bytes();
+    let start = i + bytes[i..].iter().take_while(|&&c| c == b' ').count();
+    let mut end = start;
+    while let Some(&c) = bytes.get(end) {
+        let is_grouping = matches!(c, b'.' | b',')
+            && end > start
+            && bytes.get(end + 1).is_some_and(u8::is_ascii_digit);
+        if !c.is_ascii_digit() && !is_grouping {
+            break;
+        }
+        end += 1;
+    }
+    (end > start).then_some(start..end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_with(text: &str, slugifier: Slugifier) -> String {
+        apply(text, &slugifier).into_owned()
+    }
+
+    #[test]
+    fn test_symbols_become_words() {
+        let en = Slugifier::new().lang(Some("en"));
+        assert_eq!(apply_with("Tom&Jerry", en), "Tom and Jerry");
+        let fr = Slugifier::new().lang(Some("fr"));
+        assert_eq!(apply_with("a < b", fr), "a  moins que  b");
+        let de = Slugifier::new().lang(Some("de"));
+        assert_eq!(apply_with("Mädchen", de), "Maedchen");
+    }
+
+    #[test]
+    fn test_custom_replacements_match_whole_words() {
+        let slugifier = Slugifier::new().replace("js", "javascript");
+        assert_eq!(
+            apply_with("JS tips", slugifier.clone()),
+            " javascript  tips"
+        );
+        assert_eq!(apply_with("jsdoc", slugifier), "jsdoc");
+    }
+
+    #[test]
+    fn test_custom_replacements_take_precedence() {
+        let slugifier = Slugifier::new().replace("&", "n").lang(Some("en"));
+        assert_eq!(apply_with("rock&roll", slugifier), "rock n roll");
+    }
+
+    #[test]
+    fn test_currency_symbols() {
+        let codes = Slugifier::new().currency_symbols(Some(CurrencyStyle::Code));
+        assert_eq!(apply_with("$5 sale", codes.clone()), " 5 USD  sale");
+        assert_eq!(apply_with("€ 1,299.99", codes.clone()), " 1,299.99 EUR ");
+        assert_eq!(apply_with("5£", codes), "5 GBP ");
+
+        let words = Slugifier::new().currency_symbols(Some(CurrencyStyle::Word));
+        assert_eq!(apply_with("¥500", words.clone()), " 500 yen ");
+        assert_eq!(
+            apply_with("$.", words.clone().lang(Some("de"))),
+            " Dollar ."
+        );
+        assert_eq!(apply_with("₩", words.lang(Some("de"))), " won ");
+    }
+
+    #[test]
+    fn test_verbalized_symbols() {
+        let slugifier = Slugifier::new().verbalize_symbols(true);
+        assert_eq!(apply_with("100%", slugifier.clone()), "100 percent ");
+        assert_eq!(apply_with("a+b=c", slugifier.clone()), "a plus b equals c");
+        let de = slugifier.lang(Some("de"));
+        assert_eq!(apply_with("20° ±", de), "20 Grad   plus minus ");
+    }
+
+    #[test]
+    fn test_compatibility_folding() {
+        let slugifier = Slugifier::new()
+            .compatibility_folding(true)
+            .verbalize_symbols(true);
+        assert_eq!(apply_with("ＡＢＣ１２３", slugifier.clone()), "ABC123");
+        assert_eq!(
+            apply_with("𝐇𝐞𝐥𝐥𝐨 ５％", slugifier.clone()),
+            "Hello 5 percent "
+        );
+
+        let terms = Terms::new(&slugifier);
+        let (folded, sources) = terms.apply_mapped("ﬁx");
+        assert_eq!(folded, "fix");
+        assert_eq!(sources, [0..3, 0..3, 3..4]);
+    }
+
+    #[test]
+    fn test_no_break_spaces_and_soft_hyphens() {
+        let slugifier = Slugifier::new().replace("New York", "NYC");
+        assert_eq!(apply_with("New\u{a0}York", slugifier.clone()), " NYC ");
+        assert_eq!(apply_with("New\u{202f}Yo\u{ad}rk", slugifier), " NYC ");
+
+        let terms = Slugifier::new().lang(Some("en"));
+        let (replaced, sources) = Terms::new(&terms).apply_mapped("a\u{ad}b\u{a0}c");
+        assert_eq!(replaced, "ab c");
+        assert_eq!(sources, [0..1, 3..4, 4..6, 6..7]);
+    }
+
+    #[test]
+    fn test_in_word_punctuation() {
+        let catalan = Slugifier::new().lang(Some("ca"));
+        assert_eq!(apply_with("col·lecció · 5", catalan), "collecció · 5");
+        let hawaiian = Slugifier::new().lang(Some("haw"));
+        assert_eq!(
+            apply_with("ʻŌlelo Hawaiʻi, Hawai'i", hawaiian),
+            "Ōlelo Hawaii, Hawaii"
+        );
+    }
+
+    #[test]
+    fn test_programming_terms() {
+        let slugifier = Slugifier::new().programming_terms(true);
+        assert_eq!(apply_with("ASP.NET", slugifier.clone()), " asp dotnet ");
+        assert_eq!(
+            apply_with("c++ and C#", slugifier.clone()),
+            " cpp  and  c sharp "
+        );
+        assert_eq!(apply_with("Node.jsx", slugifier), "Node.jsx");
+    }
+
+    #[test]
+    fn test_unknown_language_falls_back_to_english() {
+        assert_eq!(language("xx").code, "en");
+        assert_eq!(language("DE").code, "de");
+    }
+}
@@ -0,0 +1,499 @@
+//! Romanization of Thai and Lao, whose vowels are written before, above, and
+//! below their consonants and whose words aren't separated by spaces, so they
+//! can't be transliterated a character at a time.
+//!
+//! Thai is romanized with the Royal Thai General System (RTGS), and Lao with
+//! the same vowels and the BGN/PCGN values of its consonants. Text is split
+//! into syllables by the rules of the spelling rather than a dictionary, so an
+//! unwritten vowel between syllables is sometimes missed, like the second "a"
+//! of "มหานคร" (mahanakhon). A few common words the rules get wrong, like the
+//! "นคร" of city names, are romanized whole instead. Syllables are joined
+//! without separators, like the words of the text.
+
+/// Returns `true` for the letters, marks, and digits of Thai and Lao, which
+/// are romanized together a run at a time.
+pub(crate) fn is_thai_or_lao(c: char) -> bool {
+    matches!(c, '\u{0E01}'..='\u{0E3A}' | '\u{0E40}'..='\u{0E5B}' | '\u{0E81}'..='\u{0EDF}')
+}
+
+/// Returns the length in bytes of the run of Thai or Lao at the start of
+/// `text`.
+pub(crate) fn run_len(text: &str) -> usize {
+    text.find(|c| !is_thai_or_lao(c)).unwrap_or(text.len())
+}
+
+/// Returns the romanization of `run`, a run of Thai or Lao text.
+///
+/// Punctuation like the abbreviation mark "ฯ" becomes a space.
+pub(crate) fn romanize(run: &str) -> String {
+    let letters = normalize(run);
+    let mut parser = Parser {
+        letters: &letters,
+        i: 0,
+        romanized: String::with_capacity(run.len() / 2),
+    };
+    let mut last = String::new();
+    while parser.i < letters.len() {
+        let syllable = parser.syllable();
+        if let Some(syllable) = syllable {
+            parser.romanized.push_str(&syllable);
+            last = syllable;
+        } else {
+            // The repetition mark repeats the word before it, which is
+            // approximated by its last syllable.
+            parser.romanized.push_str(&last);
+        }
+    }
+    parser.romanized
+}
+
+/// A letter of a run, with Lao letters mapped to the Thai letters they
+/// correspond to, so the rules of both scripts are written once.
+#[derive(Debug, Clone, Copy)]
+struct Letter {
+    /// The Thai letter, or the Lao letter if it has no Thai counterpart.
+    c: char,
+    /// Whether the letter is Lao, whose consonants are romanized differently.
+    lao: bool,
+}
+
+/// Maps the letters of `run` to Thai letters and drops the tone marks and the
+/// vowel shortener, which RTGS doesn't write.
+fn normalize(run: &str) -> Vec<Letter> {
+    let mut letters = Vec::with_capacity(run.len() / 3);
+    for c in run.chars() {
+        let lao = c >= '\u{0E80}';
+        let thai: &[char] = match c {
+            '\u{0E47}'..='\u{0E4B}' | '\u{0E3A}' | '\u{0EC8}'..='\u{0ECB}' => &[],
+            // ໜ and ໝ are ligatures of ຫ with ນ and ມ.
+            'ໜ' => &['ห', 'น'],
+            'ໝ' => &['ห', 'ม'],
+            // The subscript lo forms a cluster like ລ.
+            '\u{0EBC}' => &['ล'],
+            'ຊ' => &['ซ'],
+            '\u{0ECD}' => &['อ'],
+            // ົ and ຽ have no Thai counterpart and keep their Lao letters.
+            '\u{0EBB}' | '\u{0EBD}' => &[c],
+            _ if lao => &[char::from_u32(c as u32 - 0x80).unwrap_or(c)],
+            _ => &[c],
+        };
+        letters.extend(thai.iter().map(|&c| Letter { c, lao }));
+    }
+    letters
+}
+
+/// Thai words with unwritten vowels that the spelling rules miss, with their
+/// romanizations.
+const WORDS: &[(&str, &str)] = &[("นคร", "nakhon")];
+
+/// Returns `true` for the consonants, including อ, which also writes vowels.
+fn is_consonant(c: char) -> bool {
+    matches!(c, 'ก'..='ฮ') && !matches!(c, 'ฤ' | 'ฦ')
+}
+
+/// Returns `true` for the vowels written before their consonant.
+fn is_leading_vowel(c: char) -> bool {
+    matches!(c, 'เ' | 'แ' | 'โ' | 'ใ' | 'ไ')
+}
+
+/// Returns `true` for the vowels written above, below, or after their
+/// consonant, which show that the consonant before them starts a syllable.
+fn is_vowel_sign(c: char) -> bool {
+    matches!(
+        c,
+        'ะ' | 'ั' | 'า' | 'ำ' | 'ิ'..='ู' | 'ๅ' | '\u{0EBB}' | '\u{0EBD}'
+    )
+}
+
+/// Returns the romanization of a consonant at the start of a syllable.
+fn initial(letter: Letter) -> &'static str {
+    if letter.lao {
+        match letter.c {
+            'ซ' => return "x",
+            'ญ' => return "ny",
+            'ว' => return "v",
+            _ => {}
+        }
+    }
+    match letter.c {
+        'ก' => "k",
+        'ข' | 'ฃ' | 'ค' | 'ฅ' | 'ฆ' => "kh",
+        'ง' => "ng",
+        'จ' | 'ฉ' | 'ช' | 'ฌ' => "ch",
+        'ซ' | 'ศ' | 'ษ' | 'ส' => "s",
+        'ญ' | 'ย' => "y",
+        'ฎ' | 'ด' => "d",
+        'ฏ' | 'ต' => "t",
+        'ฐ' | 'ฑ' | 'ฒ' | 'ถ' | 'ท' | 'ธ' => "th",
+        'ณ' | 'น' => "n",
+        'บ' => "b",
+        'ป' => "p",
+        'ผ' | 'พ' | 'ภ' => "ph",
+        'ฝ' | 'ฟ' => "f",
+        'ม' => "m",
+        'ร' => "r",
+        'ล' | 'ฬ' => "l",
+        'ว' => "w",
+        'ห' | 'ฮ' => "h",
+        _ => "",
+    }
+}
+
+/// Returns the romanization of a consonant at the end of a syllable, which is
+/// empty for the consonants that never end one.
+fn final_sound(letter: Letter) -> &'static str {
+    match letter.c {
+        'ก' | 'ข' | 'ฃ' | 'ค' | 'ฅ' | 'ฆ' => "k",
+        'ง' => "ng",
+        'จ' | 'ช' | 'ซ' | 'ฎ' | 'ฏ' | 'ฐ' | 'ฑ' | 'ฒ' | 'ด' | 'ต' | 'ถ' | 'ท' | 'ธ' | 'ศ' | 'ษ'
+        | 'ส' => "t",
+        'ญ' if letter.lao => "i",
+        'ญ' | 'ณ' | 'น' | 'ร' | 'ล' | 'ฬ' => "n",
+        'บ' | 'ป' | 'พ' | 'ฟ' | 'ภ' => "p",
+        'ม' => "m",
+        'ย' => "i",
+        'ว' => "o",
+        _ => "",
+    }
+}
+
+/// Splits a run into syllables and romanizes them.
+struct Parser<'a> {
+    letters: &'a [Letter],
+    i: usize,
+    romanized: String,
+}
+
+impl Parser<'_> {
+    /// Returns the letter `k` positions ahead.
+    fn at(&self, k: usize) -> Option<char> {
+        self.letters.get(self.i + k).map(|letter| letter.c)
+    }
+
+    /// Returns `true` if the letter `k` positions ahead is a consonant
+    /// followed by its vowel, so it starts a syllable.
+    fn starts_syllable(&self, k: usize) -> bool {
+        // อ writes the vowel "o" unless a vowel sign follows it, which makes
+        // it the silent consonant starting the next syllable, or it marks the
+        // tone of a following ย.
+        let next = self.at(k + 1);
+        self.at(k).is_some_and(is_consonant)
+            && (next.is_some_and(is_vowel_sign)
+                || (next == Some('อ')
+                    && !self.at(k + 2).is_some_and(|c| is_vowel_sign(c) || c == 'ย')))
+    }
+
+    /// Returns the word of `WORDS` that comes next, if any.
+    fn word(&self) -> Option<(&'static str, &'static str)> {
+        WORDS.iter().copied().find(|(word, _)| {
+            word.chars().enumerate().all(|(k, c)| {
+                self.letters
+                    .get(self.i + k)
+                    .is_some_and(|letter| letter.c == c && !letter.lao)
+            })
+        })
+    }
+
+    /// Consumes `letters` if they come next.
+    fn take(&mut self, letters: &str) -> bool {
+        let matches = letters
+            .chars()
+            .enumerate()
+            .all(|(k, c)| self.at(k) == Some(c));
+        if matches {
+            self.i += letters.chars().count();
+        }
+        matches
+    }
+
+    /// Skips the consonants silenced by the cancellation mark, like the "ร์"
+    /// of "การ์ด" and the "ทร์" of "จันทร์".
+    fn skip_silent(&mut self) {
+        loop {
+            let consonant = |c: Option<char>| c.is_some_and(is_consonant);
+            if self.at(0) == Some('์') {
+                self.i += 1;
+            } else if consonant(self.at(0)) && self.at(1) == Some('์') {
+                self.i += 2;
+            } else if consonant(self.at(0)) && consonant(self.at(1)) && self.at(2) == Some('์') {
+                self.i += 3;
+            } else {
+                return;
+            }
+        }
+    }
+
+    /// Romanizes the next syllable, or returns `None` for the repetition
+    /// mark.
+    fn syllable(&mut self) -> Option<String> {
+        self.skip_silent();
+        let Some(c) = self.at(0) else {
+            return Some(String::new());
+        };
+        let syllable = match c {
+            '๐'..='๙' => {
+                self.i += 1;
+                char::from_digit(c as u32 - '๐' as u32, 10)
+                    .map(String::from)
+                    .unwrap_or_default()
+            }
+            'ๆ' => {
+                self.i += 1;
+                return None;
+            }
+            'ฤ' | 'ฦ' => {
+                self.i += 1;
+                self.take("ๅ");
+                if c == 'ฤ' { "rue" } else { "lue" }.to_string()
+            }
+            _ if is_leading_vowel(c) => {
+                self.i += 1;
+                if self.at(0).is_some_and(is_consonant) {
+                    self.consonant_syllable(Some(c))
+                } else {
+                    match c {
+                        'เ' => "e",
+                        'แ' => "ae",
+                        'โ' => "o",
+                        _ => "ai",
+                    }
+                    .to_string()
+                }
+            }
+            _ if let Some((word, romanized)) = self.word() => {
+                self.i += word.chars().count();
+                romanized.to_string()
+            }
+            _ if is_consonant(c) => self.consonant_syllable(None),
+            _ => {
+                self.i += 1;
+                " ".to_string()
+            }
+        };
+        Some(syllable)
+    }
+
+    /// Romanizes the syllable starting at the consonant at the current
+    /// position, after the leading vowel `lead`, if any.
+    fn consonant_syllable(&mut self, lead: Option<char>) -> String {
+        let mut syllable = self.onset(lead.is_some());
+        let (vowel, closed) = self.vowel(lead);
+        let coda = if closed {
+            self.coda(!vowel.is_empty())
+        } else {
+            ""
+        };
+        let vowel = match vowel {
+            "" if coda.is_empty() => "a",
+            "" => "o",
+            vowel => vowel,
+        };
+        syllable.push_str(vowel);
+        syllable.push_str(coda);
+        self.skip_silent();
+        syllable
+    }
+
+    /// Consumes and romanizes the consonants starting a syllable.
+    fn onset(&mut self, has_lead: bool) -> String {
+        let first = self.letters[self.i];
+        self.i += 1;
+        let second = self.at(0);
+
+        // ห before a sonorant, and อ before ย, only mark the tone of the
+        // sonorant.
+        let is_tone_marker = matches!(
+            (first.c, second),
+            ('ห', Some('ง' | 'ญ' | 'น' | 'ม' | 'ย' | 'ร' | 'ล' | 'ว')) | ('อ', Some('ย'))
+        );
+        if is_tone_marker && (has_lead || self.at(1).is_some()) {
+            let sonorant = self.letters[self.i];
+            self.i += 1;
+            return initial(sonorant).to_string();
+        }
+
+        let mut onset = initial(first).to_string();
+        let is_cluster = match (first.c, second) {
+            ('ก' | 'ข' | 'ค', Some('ว')) => {
+                self.at(1).is_some_and(is_vowel_sign)
+                    || (has_lead && self.at(1).is_some_and(is_consonant))
+            }
+            ('ก' | 'ข' | 'ค' | 'ต' | 'ป' | 'พ' | 'ผ' | 'บ' | 'ด' | 'ฟ', Some('ร' | 'ล')) => {
+                has_lead
+                    || self.at(1).is_some_and(is_vowel_sign)
+                    || (self.at(1) == Some('อ') && self.at(2).is_some_and(is_consonant))
+            }
+            _ => false,
+        };
+        if is_cluster {
+            let second = self.letters[self.i];
+            self.i += 1;
+            onset.push_str(if second.c == 'ว' {
+                "w"
+            } else {
+                initial(second)
+            });
+        }
+        onset
+    }
+
+    /// Consumes and romanizes the vowel of a syllable, returning it with
+    /// whether the syllable can end with a consonant. The vowel is empty if it
+    /// isn't written.
+    fn vowel(&mut self, lead: Option<char>) -> (&'static str, bool) {
+        let is_lao = self.letters[self.i - 1].lao;
+        let before_coda = |parser: &Self, k: usize| {
+            parser.at(k).is_some_and(is_consonant) && !parser.starts_syllable(k)
+        };
+        match lead {
+            None => {
+                if self.take("ัว") || self.take("\u{0EBB}ว") {
+                    ("ua", true)
+                } else if self.take("ั") || self.take("า") {
+                    ("a", true)
+                } else if self.take("ะ") {
+                    ("a", false)
+                } else if self.take("ำ") {
+                    ("am", false)
+                } else if self.take("ิ") || self.take("ี") {
+                    ("i", true)
+                } else if self.take("ือ") || self.take("ึ") || self.take("ื") {
+                    ("ue", true)
+                } else if self.take("ุ") || self.take("ู") {
+                    ("u", true)
+                } else if self.take("ฤ") {
+                    // After a consonant, ฤ writes "ri", like in "อังกฤษ".
+                    ("ri", true)
+                } else if self.take("\u{0EBB}") {
+                    ("o", true)
+                } else if self.take("\u{0EBD}") {
+                    ("ia", true)
+                } else if self.at(0) == Some('อ') && !self.at(1).is_some_and(is_vowel_sign) {
+                    self.i += 1;
+                    ("o", true)
+                } else if self.at(0) == Some('ว') && before_coda(self, 1) {
+                    self.i += 1;
+                    ("ua", true)
+                } else if self.take("รร") {
+                    if before_coda(self, 0) {
+                        ("a", true)
+                    } else {
+                        ("an", false)
+                    }
+                } else {
+                    ("", true)
+                }
+            }
+            Some('เ') => {
+                let vowel = if self.take("ีย") || self.take("\u{0EBD}") {
+                    ("ia", true)
+                } else if self.take("ือ") {
+                    ("uea", true)
+                } else if self.take("าะ") {
+                    ("o", false)
+                } else if self.take("า") || self.take("\u{0EBB}า") {
+                    ("ao", false)
+                } else if self.take("อ") || self.take("ิ") {
+                    ("oe", true)
+                } else if is_lao && !self.starts_syllable(0) && self.take("ญ") {
+                    ("ia", false)
+                } else if !self.starts_syllable(0) && self.take("ย") {
+                    ("oei", false)
+                } else {
+                    ("e", true)
+                };
+                if self.take("ะ") {
+                    (vowel.0, false)
+                } else {
+                    vowel
+                }
+            }
+            Some('แ') => ("ae", !self.take("ะ")),
+            Some('โ') => ("o", !self.take("ะ")),
+            _ => {
+                // The ย of "ไทย" is silent.
+                if !self.starts_syllable(0) {
+                    self.take("ย");
+                }
+                ("ai", false)
+            }
+        }
+    }
+
+    /// Consumes and romanizes the consonant ending a syllable, if the next
+    /// consonant ends it rather than starting the next one.
+    fn coda(&mut self, has_vowel: bool) -> &'static str {
+        self.skip_silent();
+        let Some(&letter) = self.letters.get(self.i) else {
+            return "";
+        };
+        let sound = final_sound(letter);
+        if sound.is_empty()
+            || !is_consonant(letter.c)
+            || self.starts_syllable(0)
+            || self.word().is_some()
+        {
+            return "";
+        }
+        // Without a written vowel, a consonant before the last consonant of
+        // the run starts the last syllable, like the first น of "ถนน".
+        if !has_vowel && self.at(1).is_some_and(is_consonant) && self.at(2).is_none() {
+            return "";
+        }
+        self.i += 1;
+        sound
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Slugifier;
+
+    #[test]
+    fn test_romanize_thai() {
+        let cases = [
+            ("ภาษาไทย", "phasathai"),
+            ("สวัสดี", "sawatdi"),
+            ("ประเทศไทย", "prathetthai"),
+            ("กรุงเทพ", "krungthep"),
+            ("เชียงใหม่", "chiangmai"),
+            ("ภูเก็ต", "phuket"),
+            ("ขอบคุณครับ", "khopkhunkhrap"),
+            ("ถนน", "thanon"),
+            ("มะม่วง", "mamuang"),
+            ("ข้าวผัด", "khaophat"),
+            ("ภาษาอังกฤษ", "phasaangkrit"),
+            ("ฤดูร้อน", "rueduron"),
+            ("เรื่อง", "rueang"),
+            ("อาหาร", "ahan"),
+            ("ทะเล", "thale"),
+            ("จันทร์", "chan"),
+            ("การ์ด", "kat"),
+            ("ปี ๒๕๖๗", "pi 2567"),
+            ("กรุงเทพมหานคร", "krungthepmahanakhon"),
+            ("นครปฐม", "nakhonpathom"),
+            ("สามคน", "samkhon"),
+        ];
+        for (thai, rtgs) in cases {
+            assert_eq!(romanize(thai), rtgs, "{thai}");
+        }
+    }
+
+    #[test]
+    fn test_romanize_lao() {
+        assert_eq!(romanize("ສະບາຍດີ"), "sabaidi");
+        assert_eq!(romanize("ວຽງຈັນ"), "viangchan");
+        assert_eq!(romanize("ຫຼວງພະບາງ"), "luangphabang");
+        assert_eq!(romanize("ລາວ"), "lao");
+        assert_eq!(romanize("ໜອງຄາຍ"), "nongkhai");
+    }
+
+    #[test]
+    fn test_slugify_romanizes_runs() {
+        let slugifier = Slugifier::new();
+        assert_eq!(slugifier.slugify("เที่ยวภูเก็ต 2024"), "thiaophuket-2024");
+        assert_eq!(slugifier.slugify("Bangkok (กรุงเทพ)"), "bangkok-krungthep");
+        assert_eq!(run_len("ไทยabc"), "ไทย".len());
+    }
+}
@@ -0,0 +1,114 @@
+//! Tracing events for the stages of slugification, behind the `tracing`
+//! feature.
+
+/// Emits a `TRACE` event for a pipeline stage, with the length of its input
+/// and its output.
+///
+/// Without the `tracing` feature, this compiles to nothing.
+#[cfg(feature = "tracing")]
+macro_rules! stage {
+    ($stage:literal, $before:expr, $output:expr) => {{
+        let output: &str = $output;
+        tracing::trace!(
+            stage = $stage,
+            before = $before,
+            after = output.len(),
+            output,
+        );
+    }};
+}
+
+#[cfg(not(feature = "tracing"))]
+macro_rules! stage {
+    ($stage:literal, $before:expr, $output:expr) => {{
+        let _ = (&$before, &$output);
+    }};
+}
+
+pub(crate) use stage;
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use std::fmt;
+    use std::sync::{Arc, Mutex};
+
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    use crate::Slugifier;
+
+    /// Collects the stage, `before`, and `after` fields of each event.
+    #[derive(Clone, Default)]
+    struct Stages(Arc<Mutex<Vec<(String, u64, u64)>>>);
+
+    #[derive(Default)]
+    struct StageVisitor(String, u64, u64);
+
+    impl Visit for StageVisitor {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            if field.name() == "stage" {
+                self.0 = value.to_string();
+            }
+        }
+
+        fn record_u64(&mut self, field: &Field, value: u64) {
+            match field.name() {
+                "before" => self.1 = value,
+                "after" => self.2 = value,
+                _ => {}
+            }
+        }
+
+        fn record_debug(&mut self, _: &Field, _: &dyn fmt::Debug) {}
+    }
+
+    impl Subscriber for Stages {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut visitor = StageVisitor::default();
+            event.record(&mut visitor);
+            self.0
+                .lock()
+                .unwrap()
+                .push((visitor.0, visitor.1, visitor.2));
+        }
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    #[test]
+    fn test_stages_are_traced() {
+        let stages = Stages::default();
+        let slugifier = Slugifier::new()
+            .lang(Some("en"))
+            .stop_words(&["the"])
+            .truncate(8);
+        let slug = tracing::subscriber::with_default(stages.clone(), || {
+            slugifier.slugify("The Rock & Roll")
+        });
+        assert_eq!(slug, "rock");
+
+        let stages = stages.0.lock().unwrap();
+        let names: Vec<&str> = stages.iter().map(|(stage, ..)| stage.as_str()).collect();
+        assert_eq!(
+            names,
+            ["replacement", "transliteration", "stop_words", "truncation"]
+        );
+        assert_eq!((stages[0].1, stages[0].2), (15, 19));
+        assert_eq!((stages[3].1, stages[3].2), (13, 4));
+    }
+}
@@ -0,0 +1,157 @@
+//! Transliteration to ASCII.
+//!
+//! With the default `transliteration` feature, every script is transliterated
+//! with `any_ascii`. Without it, the crate doesn't depend on `any_ascii`'s
+//! tables, and only Latin letters are folded to ASCII: accents are removed,
+//! letters like "ß" and "æ" are spelled out, and common punctuation like
+//! curly quotes and dashes is replaced. Other characters separate words.
+//!
+//! Either way, Thai and Lao are romanized a run at a time by the `thai`
+//! module, since their letters can't be transliterated one at a time.
+
+use crate::thai;
+
+/// Returns the ASCII transliteration of `c`.
+#[cfg(feature = "transliteration")]
+pub(crate) fn transliterate_char(c: char) -> &'static str {
+    any_ascii::any_ascii_char(c)
+}
+
+/// Returns `text` transliterated to ASCII.
+pub(crate) fn transliterate(text: &str) -> String {
+    let Some(start) = text.find(thai::is_thai_or_lao) else {
+        return transliterate_chars(text);
+    };
+
+    let mut ascii = transliterate_chars(&text[..start]);
+    let mut rest = &text[start..];
+    while !rest.is_empty() {
+        let run = thai::run_len(rest);
+        ascii.push_str(&thai::romanize(&rest[..run]));
+        let other = rest[run..]
+            .find(thai::is_thai_or_lao)
+            .unwrap_or(rest.len() - run);
+        ascii.push_str(&transliterate_chars(&rest[run..run + other]));
+        rest = &rest[run + other..];
+    }
+    ascii
+}
+
+/// Returns `text` transliterated to ASCII a character at a time.
+#[cfg(feature = "transliteration")]
+fn transliterate_chars(text: &str) -> String {
+    any_ascii::any_ascii(text)
+}
+
+/// Returns `text` transliterated to ASCII a character at a time.
+#[cfg(not(feature = "transliteration"))]
+fn transliterate_chars(text: &str) -> String {
+    let mut ascii = String::with_capacity(text.len());
+    for c in text.chars() {
+        if c.is_ascii() {
+            ascii.push(c);
+        } else {
+            ascii.push_str(transliterate_char(c));
+        }
+    }
+    ascii
+}
+
+/// Returns the ASCII folding of `c`, which is a space if it has none.
+#[cfg(not(feature = "transliteration"))]
+pub(crate) fn transliterate_char(c: char) -> &'static str {
+    if c.is_ascii() {
+        return ascii_str(c);
+    }
+
+    match c {
+        'ß' => "ss",
+        'ẞ' => "SS",
+        'æ' => "ae",
+        'Æ' => "AE",
+        'œ' => "oe",
+        'Œ' => "OE",
+        'ĳ' => "ij",
+        'Ĳ' => "IJ",
+        'ø' => "o",
+        'Ø' => "O",
+        'đ' | 'ð' => "d",
+        'Đ' | 'Ð' => "D",
+        'þ' => "th",
+        'Þ' => "Th",
+        'ł' | 'ŀ' => "l",
+        'Ł' | 'Ŀ' => "L",
+        'ħ' => "h",
+        'Ħ' => "H",
+        'ŧ' => "t",
+        'Ŧ' => "T",
+        'ı' => "i",
+        'ŋ' => "ng",
+        'Ŋ' => "NG",
+        'ſ' => "s",
+        'µ' => "u",
+        'ﬁ' => "fi",
+        'ﬂ' => "fl",
+        '¼' => "1/4",
+        '½' => "1/2",
+        '¾' => "3/4",
+        '©' => "(c)",
+        '®' => "(r)",
+        '×' => "x",
+        '÷' => "/",
+        '¡' => "!",
+        '¿' => "?",
+        '«' => "<<",
+        '»' => ">>",
+        '‘' | '’' | '‚' | '′' => "'",
+        '“' | '”' | '„' | '″' => "\"",
+        '‐' | '‑' | '‒' | '–' | '—' | '−' => "-",
+        '…' => "...",
+        _ => {
+            // Letters with accents, full-width forms, and superscripts fold to
+            // the ASCII character they decompose to.
+            let mut base = None;
+            unicode_normalization::char::decompose_compatible(c, |d| {
+                base.get_or_insert(d);
+            });
+            match base {
+                Some(base) if base.is_ascii() => ascii_str(base),
+                _ if unicode_normalization::char::is_combining_mark(c) => "",
+                _ => " ",
+            }
+        }
+    }
+}
+
+/// Returns `c`, an ASCII character, as a string.
+#[cfg(not(feature = "transliteration"))]
+fn ascii_str(c: char) -> &'static str {
+    const ASCII: &str = "\0\x01\x02\x03\x04\x05\x06\x07\x08\t\n\x0b\x0c\r\x0e\x0f\
+        \x10\x11\x12\x13\x14\x15\x16\x17\x18\x19\x1a\x1b\x1c\x1d\x1e\x1f \
+        !\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`\
+        abcdefghijklmnopqrstuvwxyz{|}~\x7f";
+    let i = c as usize;
+    &ASCII[i..i + 1]
+}
+
+#[cfg(all(test, not(feature = "transliteration")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latin_folding() {
+        assert_eq!(transliterate("Crème Brûlée"), "Creme Brulee");
+        assert_eq!(transliterate("Straße, Æsir, Łódź"), "Strasse, AEsir, Lodz");
+        assert_eq!(transliterate("“Ｆｕｌｌ” – x²"), "\"Full\" - x2");
+        assert_eq!(transliterate("e\u{301}"), "e");
+    }
+
+    #[test]
+    fn test_other_scripts_separate_words() {
+        assert_eq!(transliterate("a日本b"), "a  b");
+        assert_eq!(
+            crate::Slugifier::new().slugify("Rust 日本 Book"),
+            "rust-book"
+        );
+    }
+}
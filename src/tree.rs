@@ -0,0 +1,626 @@
+//! Sanitizing the names of every file in a directory tree, for backup and
+//! sync tools that copy files to a filesystem with stricter naming rules.
+
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use crate::{FsProfile, Slugifier};
+
+/// Options for [`sanitize_tree`].
+///
+/// Use the builder pattern to create an instance with custom settings.
+#[derive(Debug, Clone)]
+pub struct TreeOptions {
+    recursive: bool,
+    include_hidden: bool,
+    unique_suffix: String,
+}
+
+impl Default for TreeOptions {
+    /// Creates the default options, which walk subdirectories, skip hidden
+    /// entries, and resolve collisions with the ` ({n})` suffix.
+    fn default() -> Self {
+        Self {
+            recursive: true,
+            include_hidden: false,
+            unique_suffix: " ({n})".to_string(),
+        }
+    }
+}
+
+impl TreeOptions {
+    /// Creates the default options.
+    ///
+    /// This is an alias for `TreeOptions::default()`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether the entries of subdirectories are sanitized too, which is
+    /// the default.
+    ///
+    /// # Arguments
+    ///
+    /// * `recursive` - A boolean indicating if subdirectories are walked.
+    pub fn recursive(mut self, recursive: bool) -> Self {
+        self.recursive = recursive;
+        self
+    }
+
+    /// Sets whether hidden entries, whose names start with a dot, are
+    /// sanitized too. By default they, and the contents of hidden
+    /// directories, are left alone.
+    ///
+    /// # Arguments
+    ///
+    /// * `include_hidden` - A boolean indicating if hidden entries are sanitized.
+    pub fn include_hidden(mut self, include_hidden: bool) -> Self {
+        self.include_hidden = include_hidden;
+        self
+    }
+
+    /// Sets the suffix that makes a sanitized name unique when it collides
+    /// with another name in its directory, like
+    /// [`Slugifier::unique_suffix`], which appends the counter if the suffix
+    /// has no `{n}`.
+    ///
+    /// # Arguments
+    ///
+    /// * `suffix` - The suffix template, containing `{n}`.
+    pub fn unique_suffix(mut self, suffix: &str) -> Self {
+        self.unique_suffix = suffix.to_string();
+        self
+    }
+}
+
+/// A rename of a file or directory, planned by [`sanitize_tree`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rename {
+    /// The current path of the entry.
+    pub from: PathBuf,
+    /// The path the entry is renamed to, in the same directory.
+    pub to: PathBuf,
+}
+
+/// The renames that give every entry of a directory tree a valid name,
+/// returned by [`sanitize_tree`].
+///
+/// The renames are in the order they must be made: the entries of a
+/// directory come before the directory itself, so each path is still valid
+/// when its rename is made.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RenamePlan {
+    renames: Vec<Rename>,
+}
+
+impl RenamePlan {
+    /// Returns the planned renames, in the order they must be made.
+    pub fn renames(&self) -> &[Rename] {
+        &self.renames
+    }
+
+    /// Returns whether every name is valid already.
+    pub fn is_empty(&self) -> bool {
+        self.renames.is_empty()
+    }
+
+    /// Makes the planned renames.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error of renaming an entry, or an error of kind
+    /// [`AlreadyExists`](io::ErrorKind::AlreadyExists) if the target of a
+    /// rename was created after the plan was made. The renames before it stay
+    /// made.
+    pub fn apply(&self) -> io::Result<()> {
+        self.renames.iter().try_for_each(Rename::apply)
+    }
+
+    /// Makes the planned renames on several threads, reporting each one to
+    /// `progress`.
+    ///
+    /// The renames of the entries at one depth of the tree are made in
+    /// parallel, deepest first, so each path is still valid when its rename
+    /// is made. A rename that fails doesn't stop the others; its error is
+    /// reported to `progress` and collected in the returned report. Once
+    /// [`Progress::is_cancelled`] returns `true`, no further rename is
+    /// started.
+    ///
+    /// With the `rayon` feature, the renames run on the rayon thread pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `progress` - The receiver of the progress, which may be `&()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use rslug::{FsProfile, Progress, Rename, TreeOptions, sanitize_tree};
+    ///
+    /// struct Counter(AtomicUsize);
+    ///
+    /// impl Progress for Counter {
+    ///     fn renamed(&self, _rename: &Rename, _done: usize, _total: usize) {
+    ///         self.0.fetch_add(1, Ordering::Relaxed);
+    ///     }
+    /// }
+    ///
+    /// // A canonical path on Windows is a verbatim one, which keeps the
+    /// // trailing dots of the names.
+    /// let root = std::fs::canonicalize(std::env::temp_dir())?.join("rslug-apply-parallel-doc");
+    /// # let _ = std::fs::remove_dir_all(&root);
+    /// std::fs::create_dir_all(root.join("a."))?;
+    /// std::fs::write(root.join("a.").join("c.txt."), "")?;
+    ///
+    /// let plan = sanitize_tree(&root, FsProfile::Windows, &TreeOptions::new())?;
+    /// let counter = Counter(AtomicUsize::new(0));
+    /// let report = plan.apply_parallel(&counter);
+    /// assert!(report.is_complete());
+    /// assert_eq!(counter.0.into_inner(), 2);
+    /// assert!(root.join("a").join("c.txt").exists());
+    /// # std::fs::remove_dir_all(&root)?;
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn apply_parallel<P: Progress + ?Sized>(&self, progress: &P) -> ApplyReport {
+        let mut levels: Vec<Vec<&Rename>> = Vec::new();
+        for rename in &self.renames {
+            let depth = rename.from.components().count();
+            if levels.len() < depth {
+                levels.resize_with(depth, Vec::new);
+            }
+            levels[depth - 1].push(rename);
+        }
+
+        let state = ApplyState {
+            progress,
+            total: self.renames.len(),
+            done: AtomicUsize::new(0),
+            cancelled: AtomicBool::new(false),
+            errors: Mutex::new(Vec::new()),
+        };
+        for level in levels.iter().rev() {
+            run_level(level, &state);
+            if state.cancelled.load(Ordering::Relaxed) {
+                break;
+            }
+        }
+
+        let mut errors = state.errors.into_inner().unwrap_or_else(|e| e.into_inner());
+        errors.sort_by(|(a, _), (b, _)| a.from.cmp(&b.from));
+        ApplyReport {
+            renamed: state.done.into_inner() - errors.len(),
+            errors,
+            cancelled: state.cancelled.into_inner(),
+        }
+    }
+}
+
+impl Rename {
+    /// Makes the rename, unless its target exists.
+    fn apply(&self) -> io::Result<()> {
+        let Self { from, to } = self;
+        // On case-insensitive filesystems, a rename that only changes case
+        // finds the entry itself at the new path.
+        let is_case_change =
+            from.to_string_lossy().to_lowercase() == to.to_string_lossy().to_lowercase();
+        if to.exists() && !is_case_change {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} already exists", to.display()),
+            ));
+        }
+        fs::rename(from, to)
+    }
+}
+
+/// Receives the progress of [`RenamePlan::apply_parallel`], for example to
+/// drive a progress bar.
+///
+/// The methods are called from the threads making the renames, so they may be
+/// called concurrently. Every method does nothing by default, and `()`
+/// implements the trait for callers that don't need progress.
+pub trait Progress: Sync {
+    /// Called before `rename` is made.
+    fn started(&self, rename: &Rename) {
+        let _ = rename;
+    }
+
+    /// Called after `rename` was made, with the number of renames finished so
+    /// far, including failed ones, and the number of planned renames.
+    fn renamed(&self, rename: &Rename, done: usize, total: usize) {
+        let _ = (rename, done, total);
+    }
+
+    /// Called after `rename` failed with `error`, with the same counts as
+    /// [`renamed`](Progress::renamed).
+    fn failed(&self, rename: &Rename, error: &io::Error, done: usize, total: usize) {
+        let _ = (rename, error, done, total);
+    }
+
+    /// Returns whether the remaining renames should be skipped. It is checked
+    /// before each rename is started.
+    fn is_cancelled(&self) -> bool {
+        false
+    }
+}
+
+impl Progress for () {}
+
+/// The outcome of [`RenamePlan::apply_parallel`].
+#[derive(Debug)]
+pub struct ApplyReport {
+    /// The number of renames that were made.
+    pub renamed: usize,
+    /// The renames that failed, with their errors, in path order.
+    pub errors: Vec<(Rename, io::Error)>,
+    /// Whether the renames were cancelled before all of them were started.
+    pub cancelled: bool,
+}
+
+impl ApplyReport {
+    /// Returns whether every planned rename was made.
+    pub fn is_complete(&self) -> bool {
+        self.errors.is_empty() && !self.cancelled
+    }
+}
+
+/// The state shared by the threads of [`RenamePlan::apply_parallel`].
+struct ApplyState<'a, P: ?Sized> {
+    progress: &'a P,
+    total: usize,
+    done: AtomicUsize,
+    cancelled: AtomicBool,
+    errors: Mutex<Vec<(Rename, io::Error)>>,
+}
+
+impl<P: Progress + ?Sized> ApplyState<'_, P> {
+    /// Makes `rename` and reports it, unless the renames were cancelled.
+    fn run(&self, rename: &Rename) {
+        if self.cancelled.load(Ordering::Relaxed) || self.progress.is_cancelled() {
+            self.cancelled.store(true, Ordering::Relaxed);
+            return;
+        }
+        self.progress.started(rename);
+        let result = rename.apply();
+        let done = self.done.fetch_add(1, Ordering::Relaxed) + 1;
+        match result {
+            Ok(()) => self.progress.renamed(rename, done, self.total),
+            Err(error) => {
+                self.progress.failed(rename, &error, done, self.total);
+                let mut errors = self.errors.lock().unwrap_or_else(|e| e.into_inner());
+                errors.push((rename.clone(), error));
+            }
+        }
+    }
+}
+
+/// Makes the renames of one depth of the tree, which don't depend on each
+/// other, in parallel.
+#[cfg(feature = "rayon")]
+fn run_level<P: Progress + ?Sized>(level: &[&Rename], state: &ApplyState<'_, P>) {
+    use rayon::prelude::*;
+    level.par_iter().for_each(|rename| state.run(rename));
+}
+
+/// Makes the renames of one depth of the tree, which don't depend on each
+/// other, in parallel.
+#[cfg(not(feature = "rayon"))]
+fn run_level<P: Progress + ?Sized>(level: &[&Rename], state: &ApplyState<'_, P>) {
+    let threads = std::thread::available_parallelism()
+        .map_or(1, |n| n.get())
+        .min(level.len());
+    let next = AtomicUsize::new(0);
+    std::thread::scope(|scope| {
+        for _ in 0..threads {
+            scope.spawn(|| {
+                while let Some(rename) = level.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    state.run(rename);
+                }
+            });
+        }
+    });
+}
+
+impl IntoIterator for RenamePlan {
+    type Item = Rename;
+    type IntoIter = std::vec::IntoIter<Rename>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.renames.into_iter()
+    }
+}
+
+/// Plans the renames that give every entry under `root` a valid name on the
+/// filesystem of `profile`, without renaming anything.
+///
+/// Each name is sanitized like [`Slugifier::sanitize_filename`] with the
+/// profile's rules. If a sanitized name collides with another name in its
+/// directory, ignoring case on case-insensitive filesystems, the unique suffix
+/// is inserted before its extension. `root` itself is never renamed.
+///
+/// Inspect the plan, or make the renames with [`RenamePlan::apply`].
+///
+/// # Arguments
+///
+/// * `root` - The directory whose entries are sanitized.
+/// * `profile` - The filesystem the names must be valid on.
+/// * `options` - How the tree is walked and collisions are resolved.
+///
+/// # Errors
+///
+/// Returns the first error of reading a directory of the tree.
+///
+/// # Example
+///
+/// ```
+/// use rslug::{FsProfile, TreeOptions, sanitize_tree};
+///
+/// // A canonical path on Windows is a verbatim one, which keeps the trailing
+/// // dots and spaces of the names.
+/// let root = std::fs::canonicalize(std::env::temp_dir())?.join("rslug-sanitize-tree-doc");
+/// # let _ = std::fs::remove_dir_all(&root);
+/// std::fs::create_dir_all(root.join("Q1 plans..."))?;
+/// std::fs::write(root.join("Q1 plans...").join("draft.txt "), "")?;
+///
+/// let plan = sanitize_tree(&root, FsProfile::Windows, &TreeOptions::new())?;
+/// let renames: Vec<_> = plan.renames().iter().map(|rename| &rename.to).collect();
+/// assert_eq!(renames, [&root.join("Q1 plans...").join("draft.txt"), &root.join("Q1 plans")]);
+///
+/// plan.apply()?;
+/// assert!(root.join("Q1 plans").join("draft.txt").exists());
+/// # std::fs::remove_dir_all(&root)?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn sanitize_tree(
+    root: &Path,
+    profile: FsProfile,
+    options: &TreeOptions,
+) -> io::Result<RenamePlan> {
+    let slugifier = Slugifier::new()
+        .fs_profile(profile)
+        .unique_suffix(&options.unique_suffix)
+        .case_insensitive_collisions(profile.is_case_insensitive());
+    let mut plan = RenamePlan::default();
+    plan_dir(&slugifier, root, options, &mut plan.renames)?;
+    Ok(plan)
+}
+
+/// Appends the renames of the entries of `dir` to `renames`.
+fn plan_dir(
+    slugifier: &Slugifier,
+    dir: &Path,
+    options: &TreeOptions,
+    renames: &mut Vec<Rename>,
+) -> io::Result<()> {
+    let mut entries = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        entries.push((entry.file_name(), entry.file_type()?.is_dir()));
+    }
+    entries.sort();
+
+    let fold = |name: &str| slugifier.collision_key(name);
+    let mut taken: HashSet<String> = entries
+        .iter()
+        .map(|(name, _)| fold(&name.to_string_lossy()))
+        .collect();
+
+    for (file_name, is_dir) in &entries {
+        let name = &*file_name.to_string_lossy();
+        if name.starts_with('.') && !options.include_hidden {
+            continue;
+        }
+        if *is_dir && options.recursive {
+            plan_dir(slugifier, &dir.join(file_name), options, renames)?;
+        }
+
+        let new_name = slugifier.sanitize_filename(name);
+        // Names that aren't valid UTF-8 are renamed even if their lossy
+        // version is valid, since that version is what they are renamed to.
+        let is_utf8 = file_name.to_str().is_some();
+        if new_name.is_empty() || (new_name == name && is_utf8) {
+            continue;
+        }
+
+        let is_free =
+            |candidate: &str| fold(candidate) == fold(name) || !taken.contains(&fold(candidate));
+        let new_name = if is_free(&new_name) {
+            new_name
+        } else {
+            (1..)
+                .map(|n| slugifier.suffixed_filename(&new_name, n))
+                .find(|candidate| is_free(candidate))
+                .expect("an unbounded counter always finds a free name")
+        };
+
+        taken.insert(fold(&new_name));
+        renames.push(Rename {
+            from: dir.join(file_name),
+            to: dir.join(new_name),
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scratch::scratch_dir;
+
+    #[test]
+    fn test_sanitize_tree_resolves_collisions() {
+        let dir = scratch_dir("collisions");
+        for name in ["report.pdf.", "report.pdf", "Report.PDF ", ".hidden."] {
+            fs::write(dir.join(name), "").unwrap();
+        }
+
+        let plan = sanitize_tree(&dir, FsProfile::Windows, &TreeOptions::new()).unwrap();
+        assert_eq!(
+            plan.renames(),
+            [
+                Rename {
+                    from: dir.join("Report.PDF "),
+                    to: dir.join("Report (1).PDF"),
+                },
+                Rename {
+                    from: dir.join("report.pdf."),
+                    to: dir.join("report (2).pdf"),
+                },
+            ]
+        );
+
+        // Posix keeps trailing dots and tells case apart, so only the space
+        // is dropped.
+        let posix = sanitize_tree(&dir, FsProfile::Posix, &TreeOptions::new()).unwrap();
+        assert_eq!(
+            posix.renames(),
+            [Rename {
+                from: dir.join("Report.PDF "),
+                to: dir.join("Report.PDF"),
+            }]
+        );
+
+        let options = TreeOptions::new()
+            .include_hidden(true)
+            .unique_suffix("_{n}");
+        let plan = sanitize_tree(&dir, FsProfile::Windows, &options).unwrap();
+        assert_eq!(plan.renames()[0].to, dir.join(".hidden"));
+        assert_eq!(plan.renames()[1].to, dir.join("Report_1.PDF"));
+
+        let options = TreeOptions::new().unique_suffix("_copy");
+        let plan = sanitize_tree(&dir, FsProfile::Windows, &options).unwrap();
+        assert_eq!(plan.renames()[0].to, dir.join("Report_copy1.PDF"));
+        assert_eq!(plan.renames()[1].to, dir.join("report_copy2.pdf"));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_sanitize_tree_renames_contents_first() {
+        let dir = scratch_dir("nested");
+        fs::create_dir_all(dir.join("a.").join("c ")).unwrap();
+        fs::write(dir.join("a.").join("c ").join("e."), "").unwrap();
+
+        let flat = TreeOptions::new().recursive(false);
+        let plan = sanitize_tree(&dir, FsProfile::Windows, &flat).unwrap();
+        assert_eq!(plan.renames().len(), 1);
+
+        let plan = sanitize_tree(&dir, FsProfile::Windows, &TreeOptions::new()).unwrap();
+        let targets: Vec<_> = plan.clone().into_iter().map(|rename| rename.to).collect();
+        assert_eq!(
+            targets,
+            [
+                dir.join("a.").join("c ").join("e"),
+                dir.join("a.").join("c"),
+                dir.join("a"),
+            ]
+        );
+
+        plan.apply().unwrap();
+        assert!(dir.join("a").join("c").join("e").exists());
+        assert!(
+            sanitize_tree(&dir, FsProfile::Windows, &TreeOptions::new())
+                .unwrap()
+                .is_empty()
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    /// A progress receiver that records the events and cancels after a given
+    /// number of renames.
+    struct Recorder {
+        events: Mutex<Vec<String>>,
+        cancel_after: usize,
+    }
+
+    impl Progress for Recorder {
+        fn renamed(&self, rename: &Rename, done: usize, total: usize) {
+            let name = rename.to.file_name().unwrap().to_string_lossy();
+            self.events
+                .lock()
+                .unwrap()
+                .push(format!("{name} {done}/{total}"));
+        }
+
+        fn failed(&self, rename: &Rename, _error: &io::Error, done: usize, total: usize) {
+            let name = rename.from.file_name().unwrap().to_string_lossy();
+            self.events
+                .lock()
+                .unwrap()
+                .push(format!("failed {name} {done}/{total}"));
+        }
+
+        fn is_cancelled(&self) -> bool {
+            self.events.lock().unwrap().len() >= self.cancel_after
+        }
+    }
+
+    #[test]
+    fn test_apply_parallel_reports_progress() {
+        let dir = scratch_dir("parallel");
+        fs::create_dir_all(dir.join("a.").join("c ")).unwrap();
+        for name in ["e.", "g ", "i.."] {
+            fs::write(dir.join("a.").join("c ").join(name), "").unwrap();
+        }
+
+        let plan = sanitize_tree(&dir, FsProfile::Windows, &TreeOptions::new()).unwrap();
+        let recorder = Recorder {
+            events: Mutex::new(Vec::new()),
+            cancel_after: usize::MAX,
+        };
+        let report = plan.apply_parallel(&recorder);
+        assert!(report.is_complete());
+        assert_eq!(report.renamed, 5);
+
+        // The files are renamed in any order, but before their directories.
+        let mut events = recorder.events.into_inner().unwrap();
+        assert_eq!(events[3..], ["c 4/5", "a 5/5"]);
+        events.truncate(3);
+        events.iter_mut().for_each(|event| event.truncate(1));
+        events.sort();
+        assert_eq!(events, ["e", "g", "i"]);
+        assert!(dir.join("a").join("c").join("i").exists());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_apply_parallel_errors_and_cancellation() {
+        let dir = scratch_dir("parallel-errors");
+        fs::create_dir_all(dir.join("a.")).unwrap();
+        fs::write(dir.join("a.").join("c."), "").unwrap();
+        fs::write(dir.join("e."), "").unwrap();
+
+        let plan = sanitize_tree(&dir, FsProfile::Windows, &TreeOptions::new()).unwrap();
+        fs::write(dir.join("e"), "").unwrap();
+        let report = plan.apply_parallel(&());
+        assert_eq!(report.renamed, 2);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].0.from, dir.join("e."));
+        assert_eq!(report.errors[0].1.kind(), io::ErrorKind::AlreadyExists);
+        assert!(!report.is_complete());
+
+        let dir2 = scratch_dir("parallel-cancel");
+        fs::create_dir_all(dir2.join("a.")).unwrap();
+        fs::write(dir2.join("a.").join("c."), "").unwrap();
+        let plan = sanitize_tree(&dir2, FsProfile::Windows, &TreeOptions::new()).unwrap();
+        let recorder = Recorder {
+            events: Mutex::new(Vec::new()),
+            cancel_after: 1,
+        };
+        let report = plan.apply_parallel(&recorder);
+        assert!(report.cancelled);
+        assert_eq!(report.renamed, 1);
+        assert!(dir2.join("a.").join("c").exists());
+
+        fs::remove_dir_all(dir).unwrap();
+        fs::remove_dir_all(dir2).unwrap();
+    }
+}
@@ -0,0 +1,293 @@
+//! Unique slug allocation against a store of slugs that are already taken.
+
+use std::collections::{HashMap, HashSet};
+use std::convert::Infallible;
+
+use crate::{Slugifier, chars};
+
+/// A store that records which slugs are taken.
+///
+/// Implement this for a database table or a shared cache to allocate unique
+/// slugs with [`Slugifier::slugify_unique`]. Reserving must be atomic, i.e.
+/// checking whether a slug is free and taking it must be a single operation,
+/// so that concurrent allocations can't both win the same slug.
+pub trait UniquenessStore {
+    /// The error returned when the store can't be accessed.
+    type Error;
+
+    /// Takes `slug` if it is free, returning whether it was.
+    fn reserve(&mut self, slug: &str) -> Result<bool, Self::Error>;
+
+    /// Takes `slug` for a slug generated from `original` if it is free,
+    /// returning whether it was.
+    ///
+    /// Stores that record where their slugs came from implement this. By
+    /// default, the original is ignored and the slug is reserved with
+    /// [`reserve`](UniquenessStore::reserve).
+    fn reserve_for(&mut self, slug: &str, original: &str) -> Result<bool, Self::Error> {
+        let _ = original;
+        self.reserve(slug)
+    }
+}
+
+impl<S: UniquenessStore + ?Sized> UniquenessStore for &mut S {
+    type Error = S::Error;
+
+    fn reserve(&mut self, slug: &str) -> Result<bool, Self::Error> {
+        (**self).reserve(slug)
+    }
+
+    fn reserve_for(&mut self, slug: &str, original: &str) -> Result<bool, Self::Error> {
+        (**self).reserve_for(slug, original)
+    }
+}
+
+/// An in-memory [`UniquenessStore`].
+///
+/// # Example
+///
+/// ```
+/// use rslug::{SlugRegistry, Slugifier};
+///
+/// let slugifier = Slugifier::new();
+/// let mut registry = SlugRegistry::new();
+/// assert_eq!(slugifier.slugify_unique("Hello World", &mut registry), Ok("hello-world".to_string()));
+/// assert_eq!(slugifier.slugify_unique("Hello, World!", &mut registry), Ok("hello-world-2".to_string()));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SlugRegistry {
+    taken: HashSet<String>,
+    originals: Option<HashMap<String, String>>,
+}
+
+impl SlugRegistry {
+    /// Creates an empty registry.
+    ///
+    /// This is an alias for `SlugRegistry::default()`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty registry that also records the text each slug was
+    /// generated from, for auditing with [`original_of`](SlugRegistry::original_of).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::{SlugRegistry, Slugifier};
+    ///
+    /// let slugifier = Slugifier::new();
+    /// let mut registry = SlugRegistry::with_originals();
+    /// slugifier.slugify_unique("Hello World", &mut registry).unwrap();
+    /// slugifier.slugify_unique("Hello, World!", &mut registry).unwrap();
+    /// assert_eq!(registry.original_of("hello-world-2"), Some("Hello, World!"));
+    /// ```
+    pub fn with_originals() -> Self {
+        Self {
+            taken: HashSet::new(),
+            originals: Some(HashMap::new()),
+        }
+    }
+
+    /// Returns the text `slug` was generated from, if the registry records
+    /// originals and `slug` was reserved with one.
+    pub fn original_of(&self, slug: &str) -> Option<&str> {
+        self.originals.as_ref()?.get(slug).map(String::as_str)
+    }
+
+    /// Returns whether `slug` is taken.
+    pub fn contains(&self, slug: &str) -> bool {
+        self.taken.contains(slug)
+    }
+
+    /// Frees `slug`, returning whether it was taken.
+    pub fn release(&mut self, slug: &str) -> bool {
+        if let Some(originals) = &mut self.originals {
+            originals.remove(slug);
+        }
+        self.taken.remove(slug)
+    }
+
+    /// Returns the number of taken slugs.
+    pub fn len(&self) -> usize {
+        self.taken.len()
+    }
+
+    /// Returns whether no slug is taken.
+    pub fn is_empty(&self) -> bool {
+        self.taken.is_empty()
+    }
+}
+
+impl UniquenessStore for SlugRegistry {
+    type Error = Infallible;
+
+    fn reserve(&mut self, slug: &str) -> Result<bool, Self::Error> {
+        Ok(self.taken.insert(slug.to_string()))
+    }
+
+    fn reserve_for(&mut self, slug: &str, original: &str) -> Result<bool, Self::Error> {
+        let is_free = self.taken.insert(slug.to_string());
+        if is_free && let Some(originals) = &mut self.originals {
+            originals.insert(slug.to_string(), original.to_string());
+        }
+        Ok(is_free)
+    }
+}
+
+impl Slugifier {
+    /// Generates a slug that is unique in `store`, and reserves it.
+    ///
+    /// If the slug is taken, the separator and an increasing counter starting
+    /// at 2 are appended, as in `hello-world-2`. If `truncate` is set, the slug
+    /// is shortened to make room for the counter.
+    ///
+    /// # Errors
+    ///
+    /// Returns the store's error if it can't be accessed.
+    pub fn slugify_unique<S: UniquenessStore>(
+        &self,
+        text: &str,
+        store: &mut S,
+    ) -> Result<String, S::Error> {
+        self.slugify_unique_with(text, store, |n| format!("{}{n}", self.separator))
+    }
+
+    /// Generates a slug that is unique in `store` using a custom suffix, and
+    /// reserves it.
+    ///
+    /// If the slug is taken, `suffix` is called with an increasing counter
+    /// starting at 2, and its result is appended to the slug. If `truncate` is
+    /// set, the slug is shortened to make room for the suffix.
+    ///
+    /// # Errors
+    ///
+    /// Returns the store's error if it can't be accessed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::{Preset, SlugRegistry, Slugifier};
+    ///
+    /// let slugifier = Slugifier::new().preset(Preset::EmailLocalPart);
+    /// let mut registry = SlugRegistry::new();
+    /// let suffix = |n: usize| n.to_string();
+    /// let first = slugifier.slugify_unique_with("José Pérez", &mut registry, suffix);
+    /// let second = slugifier.slugify_unique_with("Jose Perez", &mut registry, suffix);
+    /// assert_eq!(first, Ok("jose.perez".to_string()));
+    /// assert_eq!(second, Ok("jose.perez2".to_string()));
+    /// ```
+    pub fn slugify_unique_with<S, F>(
+        &self,
+        text: &str,
+        store: &mut S,
+        mut suffix: F,
+    ) -> Result<String, S::Error>
+    where
+        S: UniquenessStore,
+        F: FnMut(usize) -> String,
+    {
+        let slug = self.slugify(text);
+        if store.reserve_for(&slug, text)? {
+            return Ok(slug);
+        }
+
+        for n in 2.. {
+            let suffix = suffix(n);
+            let mut base = slug.as_str();
+            if let Some(max) = self.truncate
+                && base.len() + suffix.len() > max
+            {
+                let end = chars::floor_grapheme_boundary(base, max.saturating_sub(suffix.len()));
+                base = base[..end].trim_end_matches(|c| self.separator.contains(c));
+            }
+
+            let candidate = format!("{base}{suffix}");
+            if store.reserve_for(&candidate, text)? {
+                return Ok(candidate);
+            }
+        }
+
+        unreachable!("ran out of suffix counters")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slugify_unique() {
+        let slugifier = Slugifier::new();
+        let mut registry = SlugRegistry::new();
+        for expected in ["post", "post-2", "post-3"] {
+            assert_eq!(
+                slugifier.slugify_unique("Post", &mut registry),
+                Ok(expected.to_string())
+            );
+        }
+        assert_eq!(registry.len(), 3);
+
+        assert!(registry.release("post-2"));
+        assert_eq!(
+            slugifier.slugify_unique("Post", &mut registry),
+            Ok("post-2".to_string())
+        );
+    }
+
+    #[test]
+    fn test_slugify_unique_respects_truncation() {
+        let slugifier = Slugifier::new().truncate(10);
+        let mut registry = SlugRegistry::new();
+        let first = slugifier.slugify_unique("release notes", &mut registry);
+        let second = slugifier.slugify_unique("release notes", &mut registry);
+        assert_eq!(first, Ok("release".to_string()));
+        assert_eq!(second, Ok("release-2".to_string()));
+
+        let slugifier = Slugifier::new().truncate(8);
+        let mut registry = SlugRegistry::new();
+        slugifier.slugify_unique("abcdefgh", &mut registry).unwrap();
+        assert_eq!(
+            slugifier.slugify_unique("abcdefgh", &mut registry),
+            Ok("abcdef-2".to_string())
+        );
+    }
+
+    #[test]
+    fn test_registry_records_originals() {
+        let slugifier = Slugifier::new();
+        let mut registry = SlugRegistry::with_originals();
+        slugifier
+            .slugify_unique("Café Menu", &mut registry)
+            .unwrap();
+        slugifier
+            .slugify_unique("Cafe menu", &mut registry)
+            .unwrap();
+        assert_eq!(registry.original_of("cafe-menu"), Some("Café Menu"));
+        assert_eq!(registry.original_of("cafe-menu-2"), Some("Cafe menu"));
+        assert_eq!(registry.original_of("tea"), None);
+
+        assert!(registry.release("cafe-menu"));
+        assert_eq!(registry.original_of("cafe-menu"), None);
+        assert!(registry.reserve("manual").unwrap());
+        assert_eq!(registry.original_of("manual"), None);
+
+        let mut plain = SlugRegistry::new();
+        slugifier.slugify_unique("Café Menu", &mut plain).unwrap();
+        assert_eq!(plain.original_of("cafe-menu"), None);
+    }
+
+    #[test]
+    fn test_slugify_unique_propagates_store_errors() {
+        struct Offline;
+        impl UniquenessStore for Offline {
+            type Error = &'static str;
+            fn reserve(&mut self, _: &str) -> Result<bool, Self::Error> {
+                Err("store is offline")
+            }
+        }
+
+        let result = Slugifier::new().slugify_unique("Post", &mut Offline);
+        assert_eq!(result, Err("store is offline"));
+    }
+}
@@ -0,0 +1,161 @@
+//! Slugification of the path of a URL, and encoding of query parameters.
+
+use crate::{Slugifier, encoding, filename};
+
+/// Returns `true` for the characters RFC 3986 allows in a query without
+/// encoding, minus those with a special meaning in `key=value&...` pairs.
+fn is_query_char(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b"-._~!$'()*,:@/?".contains(&b)
+}
+
+/// Encodes `key` for use as the key of a query parameter.
+///
+/// Letters, digits, and the RFC 3986 characters that are safe in a query are
+/// kept. Everything else is percent-encoded, including `&`, `=`, `+`, `;`,
+/// `#`, and spaces, which query parsers would interpret.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(rslug::encode_query_key("filter[tags]"), "filter%5Btags%5D");
+/// assert_eq!(rslug::encode_query_key("a=b&c"), "a%3Db%26c");
+/// ```
+pub fn encode_query_key(key: &str) -> String {
+    encoding::percent_encode(key, is_query_char)
+}
+
+/// Encodes `value` for use as the value of a query parameter.
+///
+/// Like [`encode_query_key`], except that `=` is kept, since parsers split a
+/// parameter at its first `=` only.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(rslug::encode_query_value("C++ & Rust"), "C%2B%2B%20%26%20Rust");
+/// assert_eq!(rslug::encode_query_value("a=b/c?d"), "a=b/c?d");
+/// assert_eq!(rslug::encode_query_value("Grüße"), "Gr%C3%BC%C3%9Fe");
+/// ```
+pub fn encode_query_value(value: &str) -> String {
+    encoding::percent_encode(value, |b| b == b'=' || is_query_char(b))
+}
+
+impl Slugifier {
+    /// Slugifies the path segments of a URL, leaving the rest of it intact.
+    ///
+    /// The scheme, host, query, and fragment are kept as they are. Each path
+    /// segment is percent-decoded and slugified on its own, and segments that
+    /// end up empty are dropped. A short alphanumeric extension on the last
+    /// segment, like `.html`, is kept. Without a `scheme://` prefix, everything
+    /// before the query is treated as a path.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The absolute or relative URL to clean up.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rslug::Slugifier;
+    /// let slugifier = Slugifier::new();
+    /// assert_eq!(
+    ///     slugifier.slugify_url("https://Example.com/Blog/My%20First%20Post!?ref=Home#Top"),
+    ///     "https://Example.com/blog/my-first-post?ref=Home#Top"
+    /// );
+    /// assert_eq!(slugifier.slugify_url("/Docs/Getting Started.HTML"), "/docs/getting-started.html");
+    /// ```
+    pub fn slugify_url(&self, url: &str) -> String {
+        let (before_query, query) = url.split_at(url.find(['?', '#']).unwrap_or(url.len()));
+
+        let (origin, path) = match before_query.find("://") {
+            Some(scheme_end) => {
+                let authority_start = scheme_end + "://".len();
+                let path_start = before_query[authority_start..]
+                    .find('/')
+                    .map_or(before_query.len(), |i| authority_start + i);
+                before_query.split_at(path_start)
+            }
+            None => ("", before_query),
+        };
+
+        let segments: Vec<&str> = path.split('/').collect();
+        let mut slugified: Vec<String> = Vec::with_capacity(segments.len());
+        for (i, segment) in segments.iter().enumerate() {
+            let segment = encoding::percent_decode(segment);
+            let (stem, extension) = filename::split_extension(&segment);
+            let is_extension = i == segments.len() - 1
+                && (2..=6).contains(&extension.len())
+                && extension[1..].bytes().all(|b| b.is_ascii_alphanumeric());
+
+            let slug = if is_extension {
+                let mut slug = self.slugify(stem);
+                if !slug.is_empty() {
+                    slug.push('.');
+                    if self.to_lowercase {
+                        slug.push_str(&extension[1..].to_ascii_lowercase());
+                    } else {
+                        slug.push_str(&extension[1..]);
+                    }
+                }
+                slug
+            } else {
+                self.slugify(&segment)
+            };
+
+            // Keep the leading and trailing slashes, but drop other empty segments.
+            if !slug.is_empty() || i == 0 || i == segments.len() - 1 {
+                slugified.push(slug);
+            }
+        }
+
+        format!("{origin}{}{query}", slugified.join("/"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slugify_url_keeps_origin_and_query() {
+        let slugifier = Slugifier::new();
+        assert_eq!(
+            slugifier.slugify_url("https://example.com"),
+            "https://example.com"
+        );
+        assert_eq!(
+            slugifier.slugify_url("https://example.com/"),
+            "https://example.com/"
+        );
+        assert_eq!(
+            slugifier.slugify_url("http://user@host:8080/A B/?q=Ünïcode&x=1"),
+            "http://user@host:8080/a-b/?q=Ünïcode&x=1"
+        );
+    }
+
+    #[test]
+    fn test_slugify_url_segments() {
+        let slugifier = Slugifier::new();
+        assert_eq!(
+            slugifier.slugify_url("/Café/!!/Über uns/"),
+            "/cafe/uber-uns/"
+        );
+        assert_eq!(
+            slugifier.slugify_url("relative/Path Name"),
+            "relative/path-name"
+        );
+        assert_eq!(
+            slugifier.slugify_url("/v2.0/Release Notes.md"),
+            "/v2-0/release-notes.md"
+        );
+    }
+
+    #[test]
+    fn test_encode_query() {
+        assert_eq!(encode_query_key("page size"), "page%20size");
+        assert_eq!(encode_query_key("x=1"), "x%3D1");
+        assert_eq!(encode_query_value("x=1"), "x=1");
+        assert_eq!(encode_query_value("50%;#top"), "50%25%3B%23top");
+        assert_eq!(encode_query_value("~user_name-1.0"), "~user_name-1.0");
+    }
+}